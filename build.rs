@@ -3,6 +3,8 @@ use std::path::PathBuf;
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let proto_root = PathBuf::from("proto/temporal-api");
     let proto_file = proto_root.join("temporal/api/workflowservice/v1/service.proto");
+    // Only used for the read-only Nexus endpoint listing
+    let operator_proto_file = proto_root.join("temporal/api/operatorservice/v1/service.proto");
 
     // Configure tonic-build
     tonic_build::configure()
@@ -10,7 +12,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .build_client(true)
         .out_dir("src/generated") // Output generated code to src/generated
         .compile_protos(
-            &[proto_file],
+            &[proto_file, operator_proto_file],
             &[proto_root], // Include path for imports
         )?;
 
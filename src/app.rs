@@ -1,23 +1,77 @@
-use crate::config::Config;
+use crate::cli::Cli;
+use crate::config::{Config, ConnectionProfile, QuickSignal, TlsConfig};
 use crate::events::{Event, EventHandler};
+use crate::fuzzy::fuzzy_score;
+use crate::history::{build_rows, event_references, group_activities, is_workflow_task_event, ActivityGroup, HistoryRow};
+use crate::keymap::{self, Action};
 use crate::generated::temporal::api::{
-    history::v1::HistoryEvent,
-    workflowservice::v1::DescribeNamespaceResponse,
-    workflow::v1::WorkflowExecutionInfo,
+    enums::v1::{
+        ArchivalState, BatchOperationState, BatchOperationType, EventType, NamespaceState, ResetReapplyType, WorkflowExecutionStatus,
+    },
+    history::v1::{history_event, HistoryEvent, WorkflowExecutionStartedEventAttributes},
+    nexus::v1::Endpoint,
+    update::v1::outcome,
+    workflowservice::v1::{DescribeBatchOperationResponse, DescribeNamespaceResponse, GetSystemInfoResponse},
+    workflow::v1::{PendingWorkflowTaskInfo, ResetPointInfo, WorkflowExecutionInfo},
+};
+use crate::temporal::{
+    error::{describe_error, order_by_rejected, AppError, ErrorKind},
+    api::TemporalApi,
+    payload::{decode_debug_bytes, format_payloads, payload_lines},
+    TemporalClient,
 };
-use crate::temporal::TemporalClient;
 use crate::ui;
 use anyhow::Result;
-use crossterm::event::KeyCode;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use crossterm::{
+    event::{DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture, KeyCode},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
 use ratatui::{backend::Backend, widgets::TableState, Terminal};
-use tokio::sync::mpsc;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, oneshot, Semaphore};
+
+/// Identical workflow refreshes dispatched closer together than this are dropped
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Workflows fetched per page of the list
+pub const WORKFLOW_PAGE_SIZE: i32 = 50;
+
+/// Number of recently viewed workflow details kept in memory
+const DETAIL_CACHE_CAPACITY: usize = 10;
+
+/// Longest a follow-mode long-poll may block before it is dropped and re-issued,
+/// which bounds how long a stopped follow keeps a request open
+const FOLLOW_POLL_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Span over which follow mode reports how fast events are arriving
+pub const FOLLOW_RATE_WINDOW: Duration = Duration::from_secs(10);
+
+/// Columns the unwrapped event modal moves per Left/Right
+const EVENT_DETAIL_HSCROLL_STEP: u16 = 8;
+
+/// Cached workflow details younger than this are shown immediately on reopen
+const DETAIL_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Workflow type suggestions shown under the search bar at once
+pub const MAX_TYPE_SUGGESTIONS: usize = 5;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Screen {
     Workflows,
     Namespaces,
+    Batches,
+    Nexus,
+    AllNamespaces,
     WorkflowDetail,
     Help,
+    Setup,
 }
 
 /// Commands that can be sent to the async task handler
@@ -27,11 +81,129 @@ pub enum AppCommand {
     LoadNextPage(String, Vec<u8>), // query, page_token
     LoadPreviousPage(String), // query - will start fresh and rebuild
     ViewWorkflowDetail(String, String), // workflow_id, run_id
+    CountWorkflowsByStatus(String), // query
+    ListArchivedWorkflows(String, Vec<u8>), // query, page_token (empty for the first page)
+    CheckWorkflowProgress(String, String), // workflow_id, run_id
+    CheckTaskQueueBacklog(String), // task queue
     RefreshNamespaces,
+    RefreshBatchOperations,
+    RefreshNexusEndpoints,
+    RefreshAggregate(Vec<String>, String), // namespaces, query
+    FollowWorkflow(String, String, i64, Arc<AtomicBool>), // workflow_id, run_id, last shown event ID, stop flag
+    LoadRunInput(String, String), // workflow_id, run_id; for the run diff
     SwitchNamespace(String),
     TerminateWorkflow(String, String, String), // workflow_id, run_id, reason
     CancelWorkflow(String, String),             // workflow_id, run_id
-    SignalWorkflow(String, String, String),     // workflow_id, run_id, signal_name
+    SignalWorkflow(String, String, String, String), // workflow_id, run_id, signal_name, JSON args
+    UpdateWorkflow(String, String, String, String), // workflow_id, run_id, update_name, json_args
+    RestartWorkflow(String, String, bool),      // workflow_id, run_id, terminate_current
+    ResetWorkflow(String, String, i64, String, i32), // workflow_id, run_id, workflow task finish event ID, reason, ResetReapplyType
+    RegisterNamespace(String, String, Option<u32>), // name, description, retention_days
+    UpdateNamespace(String, String, Option<u32>),   // name, description, retention_days
+    Reconnect(Box<ConnectionProfile>, String), // profile, namespace to stay in
+}
+
+impl AppCommand {
+    /// Whether this command only fetches data for display (and can be cancelled)
+    pub fn is_load(&self) -> bool {
+        matches!(
+            self,
+            AppCommand::RefreshWorkflows(..)
+                | AppCommand::LoadNextPage(..)
+                | AppCommand::LoadPreviousPage(..)
+                | AppCommand::CountWorkflowsByStatus(..)
+                | AppCommand::CheckWorkflowProgress(..)
+                | AppCommand::CheckTaskQueueBacklog(..)
+                | AppCommand::ViewWorkflowDetail(..)
+                | AppCommand::RefreshNamespaces
+                | AppCommand::RefreshBatchOperations
+                | AppCommand::RefreshNexusEndpoints
+                | AppCommand::RefreshAggregate(..)
+                | AppCommand::ListArchivedWorkflows(..)
+        )
+    }
+
+    /// Loads in the same lane run one after another. Everything that replaces the workflow
+    /// list shares a lane; other loads get one per kind.
+    pub fn lane(&self) -> std::mem::Discriminant<AppCommand> {
+        match self {
+            AppCommand::LoadNextPage(..) | AppCommand::LoadPreviousPage(..) | AppCommand::ListArchivedWorkflows(..) => {
+                std::mem::discriminant(&AppCommand::RefreshWorkflows(String::new()))
+            }
+            _ => std::mem::discriminant(self),
+        }
+    }
+
+    /// Name of the operation if this command modifies server state (blocked in read-only mode)
+    pub fn mutation_name(&self) -> Option<&'static str> {
+        match self {
+            AppCommand::TerminateWorkflow(..) => Some("terminate"),
+            AppCommand::CancelWorkflow(..) => Some("cancel"),
+            AppCommand::SignalWorkflow(..) => Some("signal"),
+            AppCommand::UpdateWorkflow(..) => Some("update"),
+            AppCommand::RestartWorkflow(..) => Some("restart"),
+            AppCommand::ResetWorkflow(..) => Some("reset"),
+            AppCommand::RegisterNamespace(..) => Some("namespace registration"),
+            AppCommand::UpdateNamespace(..) => Some("namespace update"),
+            _ => None,
+        }
+    }
+
+    /// The `temporal` CLI command that performs the same workflow operation, for audit logs and scripts
+    pub fn cli_command(&self, namespace: &str) -> Option<String> {
+        let target = |workflow_id: &str, run_id: &str| {
+            let mut target = format!(
+                "--namespace {} --workflow-id {}",
+                shell_quote(namespace),
+                shell_quote(workflow_id)
+            );
+            if !run_id.is_empty() {
+                target.push_str(&format!(" --run-id {}", shell_quote(run_id)));
+            }
+            target
+        };
+        match self {
+            AppCommand::TerminateWorkflow(workflow_id, run_id, reason) => Some(format!(
+                "temporal workflow terminate {} --reason {}",
+                target(workflow_id, run_id),
+                shell_quote(reason)
+            )),
+            AppCommand::CancelWorkflow(workflow_id, run_id) => {
+                Some(format!("temporal workflow cancel {}", target(workflow_id, run_id)))
+            }
+            AppCommand::SignalWorkflow(workflow_id, run_id, signal_name, args) => {
+                let mut command = format!(
+                    "temporal workflow signal {} --name {}",
+                    target(workflow_id, run_id),
+                    shell_quote(signal_name)
+                );
+                if !args.trim().is_empty() {
+                    command.push_str(&format!(" --input {}", shell_quote(args.trim())));
+                }
+                Some(command)
+            }
+            AppCommand::ResetWorkflow(workflow_id, run_id, event_id, reason, reapply_type) => {
+                let mut command = format!(
+                    "temporal workflow reset {} --event-id {} --reason {}",
+                    target(workflow_id, run_id),
+                    event_id,
+                    shell_quote(reason)
+                );
+                let reapply = match ResetReapplyType::try_from(*reapply_type) {
+                    Ok(ResetReapplyType::AllEligible) => Some("All"),
+                    Ok(ResetReapplyType::Signal) => Some("Signal"),
+                    Ok(ResetReapplyType::None) => Some("None"),
+                    _ => None,
+                };
+                if let Some(reapply) = reapply {
+                    command.push_str(&format!(" --reapply-type {}", reapply));
+                }
+                Some(command)
+            }
+            // Restart has no single CLI equivalent; updates report through their own outcome
+            _ => None,
+        }
+    }
 }
 
 /// Results from async operations
@@ -40,22 +212,785 @@ pub enum AppResult {
     WorkflowsLoaded {
         workflows: Vec<WorkflowExecutionInfo>,
         next_page_token: Vec<u8>,
+        latency: Duration, // Round trip of the list request
+    },
+    WorkflowsError(AppError),
+    NamespaceUnavailable(AppError), // Listing failed with NotFound/PermissionDenied
+    ConnectionLost, // A load failed with Unavailable
+    OrderByUnsupported, // The list was retried without its ORDER BY clause
+    ArchivalUnavailable(String), // Archived mode was turned on for a namespace without visibility archival
+    NamespaceSwitchFailed(AppError),
+    AggregateLoaded {
+        rows: Vec<(String, WorkflowExecutionInfo)>, // (namespace, workflow), newest first
+        errors: Vec<(String, String)>,              // (namespace, error) for namespaces that failed
+    },
+    Reconnected {
+        server_info: GetSystemInfoResponse,
+    },
+    ReconnectFailed(AppError),
+    WorkflowCountLoaded {
+        query: String,
+        count: i64,
+    },
+    WorkflowStatusCountsLoaded {
+        query: String,
+        counts: Vec<(String, i64)>,
+    },
+    WorkflowLastEventLoaded {
+        run_id: String,
+        time: DateTime<Utc>,
     },
-    WorkflowsError(String),
     WorkflowDetailLoaded {
+        workflow_id: String,
+        requested_run_id: String, // As asked for; empty means the latest run
+        run_id: String, // The run that was actually loaded
         workflow: WorkflowExecutionInfo,
         history: Vec<HistoryEvent>,
+        truncated: bool, // More events exist past `max_history_events`
+        latency: Duration, // Round trip of the history requests
+        pending_workflow_task: Option<PendingWorkflowTaskInfo>, // Scheduled or started but not completed yet
     },
-    WorkflowDetailError(String),
+    WorkflowDetailError(AppError),
+    TaskQueueBacklogLoaded(TaskQueueBacklog),
     NamespacesLoaded {
         namespaces: Vec<DescribeNamespaceResponse>,
     },
-    NamespacesError(String),
+    NamespacesError(AppError),
+    BatchOperationsLoaded {
+        operations: Vec<DescribeBatchOperationResponse>,
+    },
+    BatchOperationsError(AppError),
+    NexusEndpointsLoaded {
+        endpoints: Vec<Endpoint>,
+    },
+    NexusEndpointsError(AppError),
+    FollowedEvents {
+        workflow_id: String,
+        events: Vec<HistoryEvent>,
+    },
+    FollowEnded(String), // Why following stopped: the run closed or a poll failed
+    RunInputLoaded(RunInput),
+    RunInputError(AppError),
     NamespaceSwitched {
         namespace: String,
     },
-    WorkflowOperationSuccess(String), // operation description
-    WorkflowOperationError(String),   // error message
+    WorkflowOperationSuccess(String, Option<String>), // operation description, equivalent temporal CLI command
+    WorkflowOperationError(AppError),
+    WorkflowUpdateCompleted(UpdateOutcome),
+    NamespaceOperationSuccess(String), // operation description
+    NamespaceOperationError(AppError),
+}
+
+impl AppResult {
+    /// Whether this result reports the outcome of a mutating command
+    pub fn is_operation_outcome(&self) -> bool {
+        matches!(
+            self,
+            AppResult::WorkflowOperationSuccess(..)
+                | AppResult::WorkflowOperationError(..)
+                | AppResult::WorkflowUpdateCompleted(..)
+                | AppResult::NamespaceOperationSuccess(..)
+                | AppResult::NamespaceOperationError(..)
+        )
+    }
+
+    /// Whether this result answers a load command (and is dropped once cancelled)
+    pub fn is_load(&self) -> bool {
+        matches!(
+            self,
+            AppResult::WorkflowsLoaded { .. }
+                | AppResult::WorkflowsError(..)
+                | AppResult::NamespaceUnavailable(..)
+                | AppResult::AggregateLoaded { .. }
+                | AppResult::OrderByUnsupported
+                | AppResult::ArchivalUnavailable(..)
+                | AppResult::WorkflowCountLoaded { .. }
+                | AppResult::WorkflowStatusCountsLoaded { .. }
+                | AppResult::WorkflowLastEventLoaded { .. }
+                | AppResult::WorkflowDetailLoaded { .. }
+                | AppResult::WorkflowDetailError(..)
+                | AppResult::TaskQueueBacklogLoaded(..)
+                | AppResult::NamespacesLoaded { .. }
+                | AppResult::NamespacesError(..)
+                | AppResult::BatchOperationsLoaded { .. }
+                | AppResult::BatchOperationsError(..)
+                | AppResult::NexusEndpointsLoaded { .. }
+                | AppResult::NexusEndpointsError(..)
+        )
+    }
+}
+
+/// Sends results tagged with the generation of the command that produced them
+struct ResultSender {
+    tx: mpsc::UnboundedSender<(u64, AppResult)>,
+    generation: u64,
+}
+
+impl ResultSender {
+    /// Returns false once the app has gone away
+    fn send(&self, result: AppResult) -> bool {
+        self.tx.send((self.generation, result)).is_ok()
+    }
+
+    /// Follow a failed load with `ConnectionLost` when the server dropped away
+    fn flag_connection_loss(&self, error: &AppError) {
+        if error.is_connection_lost() {
+            let _ = self.send(AppResult::ConnectionLost);
+        }
+    }
+
+    /// Report a failed request as `to_result`, flagging a lost connection after it
+    fn send_error(&self, to_result: impl FnOnce(AppError) -> AppResult, operation: &str, error: &anyhow::Error) {
+        let error = AppError::from_error(operation, error);
+        let _ = self.send(to_result(error.clone()));
+        self.flag_connection_loss(&error);
+    }
+}
+
+/// A failed workflow list, told apart when the namespace itself can't be used
+fn workflows_error(error: AppError) -> AppResult {
+    if error.is_namespace_unavailable() {
+        AppResult::NamespaceUnavailable(error)
+    } else {
+        AppResult::WorkflowsError(error)
+    }
+}
+
+/// Stream a run's events after `last_event_id` until it closes or `stop` is set.
+/// Each long-poll is cut off after FOLLOW_POLL_TIMEOUT and re-issued, so the flag is seen promptly
+/// and no poll outlives the follow by more than that.
+async fn follow_history<C: TemporalApi>(
+    client: &mut C,
+    workflow_id: String,
+    run_id: String,
+    mut last_event_id: i64,
+    stop: &AtomicBool,
+    result_tx: &ResultSender,
+) {
+    // The first poll returns the history so far at once; later ones wait for new events
+    let mut next_page_token = Vec::new();
+    while !stop.load(Ordering::Relaxed) {
+        let poll = client.poll_workflow_history(workflow_id.clone(), run_id.clone(), next_page_token.clone());
+        let response = match tokio::time::timeout(FOLLOW_POLL_TIMEOUT, poll).await {
+            Ok(Ok(response)) => response,
+            Ok(Err(e)) => {
+                if !stop.load(Ordering::Relaxed) {
+                    let _ = result_tx.send(AppResult::FollowEnded(describe_error("History poll failed", &e)));
+                }
+                return;
+            }
+            Err(_) => {
+                tracing::debug!("History poll for {} timed out; re-issuing", workflow_id);
+                continue;
+            }
+        };
+        if stop.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let events: Vec<HistoryEvent> = response
+            .history
+            .map(|history| history.events)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|event| event.event_id > last_event_id)
+            .collect();
+        if let Some(event) = events.last() {
+            last_event_id = event.event_id;
+            let workflow_id = workflow_id.clone();
+            if !result_tx.send(AppResult::FollowedEvents { workflow_id, events }) {
+                return;
+            }
+        }
+
+        if response.next_page_token.is_empty() {
+            let _ = result_tx.send(AppResult::FollowEnded("the workflow closed".to_string()));
+            return;
+        }
+        next_page_token = response.next_page_token;
+    }
+}
+
+/// Run one command against the server and report its outcome. Reconnecting and switching
+/// namespace replace or re-scope `client`, so those are only sent the handler's own client.
+async fn run_command<C: TemporalApi>(
+    client: &mut C,
+    command: AppCommand,
+    result_tx: ResultSender,
+    max_history_events: usize,
+) {
+    let cli_command = command.cli_command(client.namespace());
+    match command {
+        AppCommand::RefreshWorkflows(mut query) => {
+            tracing::info!("Loading workflows with query: '{}'", query);
+            let started = Instant::now();
+            let mut result = client
+                .list_workflow_executions(WORKFLOW_PAGE_SIZE, Vec::new(), query.clone())
+                .await;
+            // Visibility stores without custom ordering reject it; fall back to the default order
+            if result.as_ref().is_err_and(order_by_rejected) {
+                tracing::warn!("Server does not support ORDER BY, listing in its default order");
+                let _ = result_tx.send(AppResult::OrderByUnsupported);
+                query = without_order_by(&query);
+                result = client
+                    .list_workflow_executions(WORKFLOW_PAGE_SIZE, Vec::new(), query.clone())
+                    .await;
+            }
+            match result {
+                Ok(response) => {
+                    let _ = result_tx.send(AppResult::WorkflowsLoaded {
+                        workflows: response.executions,
+                        next_page_token: response.next_page_token,
+                        latency: started.elapsed(),
+                    });
+                }
+                Err(e) => {
+                    result_tx.send_error(workflows_error, "Failed to load workflows", &e);
+                    return;
+                }
+            }
+
+            // Counting needs advanced visibility; without it the title just omits the total
+            match client.count_workflow_executions(without_order_by(&query)).await {
+                Ok(count) => {
+                    let _ = result_tx.send(AppResult::WorkflowCountLoaded { query, count });
+                }
+                Err(e) => {
+                    tracing::debug!("Workflow count unavailable: {}", e);
+                }
+            }
+        }
+        AppCommand::ListArchivedWorkflows(query, page_token) => {
+            tracing::info!("Loading archived workflows with query: '{}'", query);
+            // Check the namespace before the first page, so a cluster without archival
+            // gets an explanation instead of the archiver's raw error
+            if page_token.is_empty() {
+                let namespace = client.namespace().to_string();
+                match client.describe_namespace(namespace.clone()).await {
+                    Ok(response) => {
+                        let state = response.config.map(|c| c.visibility_archival_state).unwrap_or_default();
+                        if state != ArchivalState::Enabled as i32 {
+                            let _ = result_tx.send(AppResult::ArchivalUnavailable(format!(
+                                "Visibility archival is not enabled for namespace {}",
+                                namespace
+                            )));
+                            return;
+                        }
+                    }
+                    Err(e) => {
+                        result_tx.send_error(workflows_error, "Failed to check archival for the namespace", &e);
+                        return;
+                    }
+                }
+            }
+            let started = Instant::now();
+            match client
+                .list_archived_workflow_executions(WORKFLOW_PAGE_SIZE, page_token, query)
+                .await
+            {
+                Ok(response) => {
+                    let _ = result_tx.send(AppResult::WorkflowsLoaded {
+                        workflows: response.executions,
+                        next_page_token: response.next_page_token,
+                        latency: started.elapsed(),
+                    });
+                }
+                Err(e) => {
+                    result_tx.send_error(workflows_error, "Failed to load archived workflows", &e);
+                }
+            }
+        }
+        AppCommand::LoadRunInput(workflow_id, run_id) => {
+            // The first event holds the input and the previous run; the last the next run
+            let first = client
+                .get_workflow_execution_history(workflow_id.clone(), run_id.clone(), 1, Vec::new())
+                .await;
+            let last = client.last_event(workflow_id, run_id.clone()).await;
+            let (first, last) = match (first, last) {
+                (Ok(first), Ok(last)) => (first, last),
+                (Err(e), _) | (_, Err(e)) => {
+                    let context = format!("Failed to load run {}", run_id);
+                    result_tx.send_error(AppResult::RunInputError, &context, &e);
+                    return;
+                }
+            };
+            let started = first
+                .history
+                .and_then(|history| history.events.into_iter().next())
+                .and_then(|event| event.attributes);
+            let (input, previous_run_id) = match started {
+                Some(history_event::Attributes::WorkflowExecutionStartedEventAttributes(attrs)) => {
+                    (payload_lines(attrs.input.as_ref()), attrs.continued_execution_run_id)
+                }
+                _ => (vec!["(no started event)".to_string()], String::new()),
+            };
+            let next_run_id = match last.and_then(|event| event.attributes) {
+                Some(history_event::Attributes::WorkflowExecutionContinuedAsNewEventAttributes(attrs)) => {
+                    attrs.new_execution_run_id
+                }
+                _ => String::new(),
+            };
+            let _ = result_tx.send(AppResult::RunInputLoaded(RunInput {
+                run_id,
+                input,
+                previous_run_id,
+                next_run_id,
+            }));
+        }
+        AppCommand::CheckWorkflowProgress(workflow_id, run_id) => {
+            match client.last_event_time(workflow_id, run_id.clone()).await {
+                Ok(Some(time)) => {
+                    let _ = result_tx.send(AppResult::WorkflowLastEventLoaded { run_id, time });
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    // The badge is best-effort; the list still works without it
+                    tracing::debug!("Could not read latest event of run {}: {}", run_id, e);
+                }
+            }
+        }
+        AppCommand::CheckTaskQueueBacklog(task_queue) => {
+            match client.describe_task_queue(task_queue.clone()).await {
+                Ok(response) => {
+                    // Prefer the stats API; older servers only give the status hint
+                    let tasks = response
+                        .stats
+                        .map(|stats| stats.approximate_backlog_count)
+                        .or(response.task_queue_status.map(|status| status.backlog_count_hint));
+                    if let Some(tasks) = tasks {
+                        let pollers = response.pollers.len();
+                        let _ = result_tx.send(AppResult::TaskQueueBacklogLoaded(TaskQueueBacklog {
+                            task_queue,
+                            tasks,
+                            pollers,
+                        }));
+                    }
+                }
+                Err(e) => {
+                    // The backlog line is best-effort; the detail still works without it
+                    tracing::debug!("Could not describe task queue {}: {}", task_queue, e);
+                }
+            }
+        }
+        AppCommand::CountWorkflowsByStatus(query) => {
+            match client.count_workflow_executions_by_status(without_order_by(&query)).await {
+                Ok(counts) => {
+                    let _ = result_tx.send(AppResult::WorkflowStatusCountsLoaded { query, counts });
+                }
+                Err(e) => {
+                    // The chart falls back to tallying the current page
+                    tracing::debug!("Per-status workflow counts unavailable: {}", e);
+                }
+            }
+        }
+        AppCommand::LoadNextPage(query, page_token) => {
+            tracing::info!("Loading next page with query: '{}'", query);
+            let started = Instant::now();
+            match client
+                .list_workflow_executions(WORKFLOW_PAGE_SIZE, page_token, query)
+                .await
+            {
+                Ok(response) => {
+                    let _ = result_tx.send(AppResult::WorkflowsLoaded {
+                        workflows: response.executions,
+                        next_page_token: response.next_page_token,
+                        latency: started.elapsed(),
+                    });
+                }
+                Err(e) => {
+                    result_tx.send_error(workflows_error, "Failed to load next page", &e);
+                }
+            }
+        }
+        AppCommand::LoadPreviousPage(query) => {
+            tracing::info!("Loading previous page with query: '{}'", query);
+            // Load from the beginning (previous page is handled on the client side)
+            let started = Instant::now();
+            match client
+                .list_workflow_executions(WORKFLOW_PAGE_SIZE, Vec::new(), query)
+                .await
+            {
+                Ok(response) => {
+                    let _ = result_tx.send(AppResult::WorkflowsLoaded {
+                        workflows: response.executions,
+                        next_page_token: response.next_page_token,
+                        latency: started.elapsed(),
+                    });
+                }
+                Err(e) => {
+                    result_tx.send_error(workflows_error, "Failed to load previous page", &e);
+                }
+            }
+        }
+        AppCommand::ViewWorkflowDetail(workflow_id, run_id) => {
+            tracing::info!("Loading workflow detail: {}", workflow_id);
+
+            // Describe the run (the latest one without a run ID) rather than trusting visibility,
+            // which can lag behind a freshly started run and has no reset points or pending task
+            let (workflow_info, history_run_id, pending_workflow_task) =
+                match client.describe_workflow_execution(workflow_id.clone(), run_id.clone()).await {
+                    Ok(response) => {
+                        let info = response.workflow_execution_info;
+                        let described_run_id = info
+                            .as_ref()
+                            .and_then(|i| i.execution.as_ref())
+                            .map(|e| e.run_id.clone())
+                            .filter(|described| !described.is_empty())
+                            .unwrap_or_else(|| run_id.clone());
+                        (info, described_run_id, response.pending_workflow_task)
+                    }
+                    // Runs past retention are only in visibility, or in the archive. Match the run too,
+                    // or a newer run's status and history size would show for this one.
+                    Err(e) if !run_id.is_empty() && AppError::from_error("", &e).kind() == ErrorKind::NotFound => {
+                        let query = format!("WorkflowId = '{}' AND RunId = '{}'", workflow_id, run_id);
+                        let mut info = client
+                            .list_workflow_executions(1, Vec::new(), query.clone())
+                            .await
+                            .ok()
+                            .and_then(|response| response.executions.into_iter().next());
+                        if info.is_none() {
+                            info = client
+                                .list_archived_workflow_executions(1, Vec::new(), query)
+                                .await
+                                .ok()
+                                .and_then(|response| response.executions.into_iter().next());
+                        }
+                        (info, run_id.clone(), None)
+                    }
+                    Err(e) => {
+                        result_tx.send_error(AppResult::WorkflowDetailError, "Failed to describe workflow", &e);
+                        return;
+                    }
+                };
+
+            // Page in the history, up to the configured cap
+            let started = Instant::now();
+            let mut events = Vec::new();
+            let mut next_page_token = Vec::new();
+            let truncated = loop {
+                let page_size = max_history_events.saturating_sub(events.len()).clamp(1, 1000) as i32;
+                match client
+                    .get_workflow_execution_history(
+                        workflow_id.clone(),
+                        history_run_id.clone(),
+                        page_size,
+                        next_page_token,
+                    )
+                    .await
+                {
+                    Ok(response) => {
+                        events.extend(response.history.map(|h| h.events).unwrap_or_default());
+                        next_page_token = response.next_page_token;
+                    }
+                    Err(e) => {
+                        result_tx.send_error(AppResult::WorkflowDetailError, "Failed to load workflow detail", &e);
+                        break None;
+                    }
+                }
+                if next_page_token.is_empty() {
+                    break Some(false);
+                }
+                if events.len() >= max_history_events {
+                    tracing::warn!("History of {} truncated at {} events", workflow_id, events.len());
+                    break Some(true);
+                }
+            };
+            if let Some(truncated) = truncated {
+                let _ = result_tx.send(AppResult::WorkflowDetailLoaded {
+                    workflow_id: workflow_id.clone(),
+                    requested_run_id: run_id.clone(),
+                    run_id: history_run_id,
+                    workflow: workflow_info.unwrap_or_default(),
+                    history: events,
+                    truncated,
+                    latency: started.elapsed(),
+                    pending_workflow_task,
+                });
+            }
+        }
+        AppCommand::RefreshNamespaces => {
+            tracing::info!("Loading namespaces");
+            match client.list_namespaces(50, Vec::new()).await {
+                Ok(response) => {
+                    let _ = result_tx.send(AppResult::NamespacesLoaded {
+                        namespaces: response.namespaces,
+                    });
+                }
+                Err(e) => {
+                    result_tx.send_error(AppResult::NamespacesError, "Failed to load namespaces", &e);
+                }
+            }
+        }
+        AppCommand::RefreshBatchOperations => {
+            tracing::info!("Loading batch operations");
+            match client.list_batch_operations(50).await {
+                Ok(response) => {
+                    // The list only carries state and times; describe each for type and progress
+                    let mut operations = Vec::with_capacity(response.operation_info.len());
+                    for info in response.operation_info {
+                        match client.describe_batch_operation(info.job_id.clone()).await {
+                            Ok(operation) => operations.push(operation),
+                            Err(e) => {
+                                tracing::warn!("Failed to describe batch operation {}: {}", info.job_id, e);
+                                operations.push(DescribeBatchOperationResponse {
+                                    job_id: info.job_id,
+                                    state: info.state,
+                                    start_time: info.start_time,
+                                    close_time: info.close_time,
+                                    ..Default::default()
+                                });
+                            }
+                        }
+                    }
+                    let _ = result_tx.send(AppResult::BatchOperationsLoaded { operations });
+                }
+                Err(e) => {
+                    result_tx.send_error(AppResult::BatchOperationsError, "Failed to load batch operations", &e);
+                }
+            }
+        }
+        AppCommand::RefreshNexusEndpoints => {
+            tracing::info!("Loading Nexus endpoints");
+            match client.list_nexus_endpoints(100).await {
+                Ok(response) => {
+                    let _ = result_tx.send(AppResult::NexusEndpointsLoaded { endpoints: response.endpoints });
+                }
+                Err(e) => {
+                    result_tx.send_error(AppResult::NexusEndpointsError, "Failed to load Nexus endpoints", &e);
+                }
+            }
+        }
+        AppCommand::RefreshAggregate(namespaces, query) => {
+            tracing::info!("Loading workflows in {} namespaces with query: '{}'", namespaces.len(), query);
+            let mut rows = Vec::new();
+            let mut errors = Vec::new();
+            for namespace in namespaces {
+                // A scoped clone keeps the handler's own namespace as it is
+                let mut scoped = client.clone();
+                scoped.set_namespace(namespace.clone());
+                match scoped.list_workflow_executions(WORKFLOW_PAGE_SIZE, Vec::new(), query.clone()).await {
+                    Ok(response) => {
+                        rows.extend(response.executions.into_iter().map(|w| (namespace.clone(), w)));
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to load workflows in {}: {}", namespace, e);
+                        let error = AppError::from_error("Failed to load workflows", &e);
+                        result_tx.flag_connection_loss(&error);
+                        errors.push((namespace, error.to_string()));
+                    }
+                }
+            }
+            rows.sort_by_key(|(_, w)| std::cmp::Reverse(w.start_time.as_ref().map(|t| (t.seconds, t.nanos))));
+            let _ = result_tx.send(AppResult::AggregateLoaded { rows, errors });
+        }
+        AppCommand::FollowWorkflow(workflow_id, run_id, last_event_id, stop) => {
+            // Long-polls get their own task so they never hold up other commands
+            tracing::info!("Following workflow: {}", workflow_id);
+            let mut follower = client.clone();
+            tokio::spawn(async move {
+                follow_history(&mut follower, workflow_id, run_id, last_event_id, &stop, &result_tx).await;
+            });
+        }
+        AppCommand::Reconnect(profile, namespace) => {
+            tracing::info!("Reconnecting to {}", profile.address);
+            match C::connect(&profile).await {
+                Ok(mut reconnected) => {
+                    reconnected.set_namespace(namespace);
+                    let server_info = reconnected.system_info().clone();
+                    *client = reconnected;
+                    let _ = result_tx.send(AppResult::Reconnected { server_info });
+                }
+                Err(e) => {
+                    let _ = result_tx.send(AppResult::ReconnectFailed(AppError::from_error("Reconnect failed", &e)));
+                }
+            }
+        }
+        AppCommand::SwitchNamespace(namespace) => {
+            // Only commit to a namespace the server knows and lets us see
+            tracing::info!("Switching to namespace: {}", namespace);
+            match client.describe_namespace(namespace.clone()).await {
+                Ok(_) => {
+                    client.set_namespace(namespace.clone());
+                    let _ = result_tx.send(AppResult::NamespaceSwitched { namespace });
+                }
+                Err(e) => {
+                    let context = format!("Failed to switch to namespace {}", namespace);
+                    result_tx.send_error(AppResult::NamespaceSwitchFailed, &context, &e);
+                }
+            }
+        }
+        AppCommand::TerminateWorkflow(workflow_id, run_id, reason) => {
+            tracing::info!("Terminating workflow: {} with reason: {}", workflow_id, reason);
+            match client.terminate_workflow(workflow_id.clone(), run_id, reason).await {
+                Ok(_) => {
+                    let _ = result_tx.send(AppResult::WorkflowOperationSuccess(
+                        format!("Workflow {} terminated successfully", workflow_id),
+                        cli_command,
+                    ));
+                }
+                Err(e) => {
+                    let _ = result_tx.send(AppResult::WorkflowOperationError(
+                        AppError::from_error("Failed to terminate workflow", &e),
+                    ));
+                }
+            }
+        }
+        AppCommand::CancelWorkflow(workflow_id, run_id) => {
+            tracing::info!("Canceling workflow: {}", workflow_id);
+            match client.cancel_workflow(workflow_id.clone(), run_id).await {
+                Ok(_) => {
+                    let _ = result_tx.send(AppResult::WorkflowOperationSuccess(
+                        format!("Workflow {} cancel requested successfully", workflow_id),
+                        cli_command,
+                    ));
+                }
+                Err(e) => {
+                    let _ = result_tx.send(AppResult::WorkflowOperationError(
+                        AppError::from_error("Failed to cancel workflow", &e),
+                    ));
+                }
+            }
+        }
+        AppCommand::SignalWorkflow(workflow_id, run_id, signal_name, args) => {
+            tracing::info!("Signaling workflow: {} with signal: {}", workflow_id, signal_name);
+            match client.signal_workflow(workflow_id.clone(), run_id, signal_name.clone(), args).await {
+                Ok(_) => {
+                    let _ = result_tx.send(AppResult::WorkflowOperationSuccess(
+                        format!("Signal '{}' sent to workflow {} successfully", signal_name, workflow_id),
+                        cli_command,
+                    ));
+                }
+                Err(e) => {
+                    let _ = result_tx.send(AppResult::WorkflowOperationError(
+                        AppError::from_error("Failed to signal workflow", &e),
+                    ));
+                }
+            }
+        }
+        AppCommand::UpdateWorkflow(workflow_id, run_id, update_name, args) => {
+            tracing::info!("Updating workflow: {} with update: {}", workflow_id, update_name);
+            match client.update_workflow(workflow_id, run_id, update_name.clone(), args).await {
+                Ok(response) => {
+                    let (success, message) = match response.outcome.and_then(|o| o.value) {
+                        Some(outcome::Value::Success(payloads)) => (true, format_payloads(&payloads)),
+                        Some(outcome::Value::Failure(failure)) => (false, failure.message),
+                        None => (true, "Update accepted (no outcome returned yet)".to_string()),
+                    };
+                    let _ = result_tx.send(AppResult::WorkflowUpdateCompleted(UpdateOutcome {
+                        update_name,
+                        success,
+                        message,
+                    }));
+                }
+                Err(e) => {
+                    let _ = result_tx.send(AppResult::WorkflowOperationError(
+                        AppError::from_error("Failed to update workflow", &e),
+                    ));
+                }
+            }
+        }
+        AppCommand::RestartWorkflow(workflow_id, run_id, terminate) => {
+            tracing::info!("Restarting workflow: {} (terminate current: {})", workflow_id, terminate);
+            match client.restart_workflow(workflow_id.clone(), run_id, terminate).await {
+                Ok(new_run_id) => {
+                    let _ = result_tx.send(AppResult::WorkflowOperationSuccess(
+                        format!("Workflow {} restarted as run {}", workflow_id, new_run_id),
+                        cli_command,
+                    ));
+                }
+                Err(e) => {
+                    let _ = result_tx.send(AppResult::WorkflowOperationError(
+                        AppError::from_error("Failed to restart workflow", &e),
+                    ));
+                }
+            }
+        }
+        AppCommand::ResetWorkflow(workflow_id, run_id, event_id, reason, reapply_type) => {
+            tracing::info!("Resetting workflow: {} to event {}", workflow_id, event_id);
+            match client
+                .reset_workflow(workflow_id.clone(), run_id, event_id, reason, reapply_type)
+                .await
+            {
+                Ok(new_run_id) => {
+                    let _ = result_tx.send(AppResult::WorkflowOperationSuccess(
+                        format!("Workflow {} reset to event {} as run {}", workflow_id, event_id, new_run_id),
+                        cli_command,
+                    ));
+                }
+                Err(e) => {
+                    let _ = result_tx.send(AppResult::WorkflowOperationError(
+                        AppError::from_error("Failed to reset workflow", &e),
+                    ));
+                }
+            }
+        }
+        AppCommand::RegisterNamespace(name, description, retention_days) => {
+            tracing::info!("Registering namespace: {}", name);
+            match client.register_namespace(name.clone(), description, retention_days).await {
+                Ok(_) => {
+                    let _ = result_tx.send(AppResult::NamespaceOperationSuccess(
+                        format!("Namespace {} registered", name),
+                    ));
+                }
+                Err(e) => {
+                    let _ = result_tx.send(AppResult::NamespaceOperationError(
+                        AppError::from_error("Failed to register namespace", &e),
+                    ));
+                }
+            }
+        }
+        AppCommand::UpdateNamespace(name, description, retention_days) => {
+            tracing::info!("Updating namespace: {}", name);
+            match client.update_namespace(name.clone(), description, retention_days).await {
+                Ok(_) => {
+                    let _ = result_tx.send(AppResult::NamespaceOperationSuccess(
+                        format!("Namespace {} updated", name),
+                    ));
+                }
+                Err(e) => {
+                    let _ = result_tx.send(AppResult::NamespaceOperationError(
+                        AppError::from_error("Failed to update namespace", &e),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Tasks waiting in a workflow task queue, from DescribeTaskQueue stats
+#[derive(Debug, Clone)]
+pub struct TaskQueueBacklog {
+    pub task_queue: String,
+    pub tasks: i64,
+    pub pollers: usize, // Workers polling recently; zero with a backlog means nothing is consuming it
+}
+
+/// A run's decoded start input and its neighbours in the continue-as-new chain
+#[derive(Debug, Clone)]
+pub struct RunInput {
+    pub run_id: String,
+    pub input: Vec<String>,      // Pretty-printed, one entry per line
+    pub previous_run_id: String, // The run this one continued from; empty for the first run
+    pub next_run_id: String,     // The run this one continued as; empty if it hasn't
+}
+
+/// Start inputs of two consecutive runs of a continue-as-new chain, side by side
+#[derive(Debug, Clone, Default)]
+pub struct RunDiff {
+    pub workflow_id: String,
+    pub left_run_id: String,             // The earlier run
+    pub right_run_id: String,            // The run it continued as
+    pub runs: HashMap<String, RunInput>, // Loaded so far, kept while walking the chain
+    pub notice: Option<String>,          // End of the chain or a load failure
+    pub scroll: u16,
+}
+
+/// Outcome of an update handler invocation, shown in a modal
+#[derive(Debug, Clone)]
+pub struct UpdateOutcome {
+    pub update_name: String,
+    pub success: bool,
+    pub message: String,
 }
 
 /// State for the workflow list screen
@@ -65,104 +1000,1294 @@ pub struct WorkflowListState {
     pub table_state: TableState,
     pub next_page_token: Vec<u8>,
     pub prev_page_tokens: Vec<Vec<u8>>, // Stack of previous page tokens
+    paging_from: Option<(usize, Vec<Vec<u8>>)>, // Page and tokens to return to if a next/previous page load fails
     pub loading: bool,
-    pub error: Option<String>,
+    pub error: Option<AppError>,
     pub current_page: usize,
     pub query: String,
     pub query_history: Vec<String>,
     pub input_mode: bool,
+    pub cursor: usize, // Position in `query` while typing a search, in characters
     pub active_filter: Option<WorkflowFilter>,
+    pub time_range: Option<TimeRange>,
+    pub order: Option<StartTimeOrder>, // None leaves ordering to the server
+    pub archived: bool, // List closed runs from archival visibility instead of the live store
+    pub group_by_type: bool, // Collapse the loaded page into one row per workflow type
+    pub expanded_types: HashSet<String>, // Type groups showing their executions
     pub auto_refresh_enabled: bool,
     pub auto_refresh_interval_secs: u64,
     pub last_refresh: Option<std::time::Instant>,
+    pub total_count: Option<i64>, // Matches for the current query, when the server can count them
+    pub auto_refresh_paused: bool, // Auto-refresh suspended after inactivity
+    pub auto_refresh_held: bool, // Frozen by the user to read the list; navigating resumes it
+    pub show_status_chart: bool,
+    pub status_counts: Option<Vec<(String, i64)>>, // Namespace-wide per-status counts for the current query
+    pub last_event_times: HashMap<String, Option<DateTime<Utc>>>, // By run ID; None while the check is in flight
+    pub copy_notice: Option<String>, // Shown in the title until the next key, e.g. after 'y'
+    pub marked: HashSet<String>, // Run IDs of workflows marked for bulk actions; kept across pages
+    pub new_ids: HashSet<String>, // Workflow IDs absent from the previous load of the same view
+    seen_ids: HashSet<String>,
+    seen_view: Option<(String, String, usize)>, // Namespace, query and page the seen IDs came from
+}
+
+/// A row of the workflow table; grouping by type adds a header row per type
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkflowRow {
+    Group {
+        workflow_type: String,
+        count: usize,
+        running: usize,
+        expanded: bool,
+    },
+    Workflow {
+        index: usize,  // Into `items`
+        grouped: bool, // Shown under an expanded type group
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkflowFilter {
+    All,
+    Running,
+    Completed,
+    Failed,
+    Canceled,
+}
+
+/// Server-side ordering appended to the list query, so it holds across pages
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StartTimeOrder {
+    Descending,
+    Ascending,
+}
+
+impl StartTimeOrder {
+    pub fn label(&self) -> &'static str {
+        match self {
+            StartTimeOrder::Descending => "StartTime DESC",
+            StartTimeOrder::Ascending => "StartTime ASC",
+        }
+    }
+}
+
+/// A visibility query without its `ORDER BY` clause, which count requests reject
+fn without_order_by(query: &str) -> String {
+    match query.to_ascii_uppercase().find("ORDER BY") {
+        Some(i) => query[..i].trim_end().to_string(),
+        None => query.to_string(),
+    }
+}
+
+/// Quick filter on workflow start time, relative to now
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimeRange {
+    Hour,
+    Day,
+    Week,
+}
+
+impl TimeRange {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimeRange::Hour => "last 1h",
+            TimeRange::Day => "last 24h",
+            TimeRange::Week => "last 7d",
+        }
+    }
+
+    fn duration(&self) -> chrono::Duration {
+        match self {
+            TimeRange::Hour => chrono::Duration::hours(1),
+            TimeRange::Day => chrono::Duration::days(1),
+            TimeRange::Week => chrono::Duration::days(7),
+        }
+    }
+
+    /// `StartTime BETWEEN` clause ending now. Truncated to the minute so refreshes
+    /// within the same minute send an identical query.
+    fn query_clause(&self) -> String {
+        let now = Utc::now();
+        let end = now - chrono::Duration::seconds(now.timestamp() % 60) + chrono::Duration::minutes(1);
+        let start = end - self.duration();
+        format!(
+            "StartTime BETWEEN '{}' AND '{}'",
+            start.format("%Y-%m-%dT%H:%M:00Z"),
+            end.format("%Y-%m-%dT%H:%M:00Z")
+        )
+    }
+}
+
+impl WorkflowListState {
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            table_state: TableState::default(),
+            next_page_token: Vec::new(),
+            prev_page_tokens: Vec::new(),
+            paging_from: None,
+            loading: false,
+            error: None,
+            current_page: 1,
+            query: String::new(),
+            query_history: Vec::new(),
+            input_mode: false,
+            cursor: 0,
+            active_filter: None,
+            time_range: None,
+            order: None,
+            archived: false,
+            group_by_type: false,
+            expanded_types: HashSet::new(),
+            auto_refresh_enabled: false,
+            auto_refresh_interval_secs: 5, // Default 5 seconds
+            last_refresh: None,
+            total_count: None,
+            auto_refresh_paused: false,
+            auto_refresh_held: false,
+            show_status_chart: false,
+            status_counts: None,
+            last_event_times: HashMap::new(),
+            copy_notice: None,
+            marked: HashSet::new(),
+            new_ids: HashSet::new(),
+            seen_ids: HashSet::new(),
+            seen_view: None,
+        }
+    }
+
+    /// Mark workflows that weren't in the previous load of this namespace, query and page.
+    /// The first load of a view marks nothing, and each refresh replaces the marks.
+    fn track_new_workflows(&mut self, namespace: &str) {
+        let ids: HashSet<String> = self
+            .items
+            .iter()
+            .filter_map(|workflow| workflow.execution.as_ref().map(|e| e.workflow_id.clone()))
+            .collect();
+        let view = (namespace.to_string(), self.get_query(), self.current_page);
+        self.new_ids = if self.seen_view.as_ref() == Some(&view) {
+            ids.difference(&self.seen_ids).cloned().collect()
+        } else {
+            HashSet::new()
+        };
+        self.seen_ids = ids;
+        self.seen_view = Some(view);
+    }
+
+    pub fn is_new(&self, workflow: &WorkflowExecutionInfo) -> bool {
+        workflow
+            .execution
+            .as_ref()
+            .is_some_and(|e| self.new_ids.contains(&e.workflow_id))
+    }
+
+    /// Whether a running workflow's latest event is older than the threshold.
+    /// Only runs that have been selected are checked, so others are never flagged.
+    pub fn is_stalled(&self, workflow: &WorkflowExecutionInfo, stall_minutes: u64) -> bool {
+        if stall_minutes == 0 || workflow.status != WorkflowExecutionStatus::Running as i32 {
+            return false;
+        }
+        let Some(run_id) = workflow.execution.as_ref().map(|e| &e.run_id) else {
+            return false;
+        };
+        match self.last_event_times.get(run_id) {
+            Some(Some(time)) => Utc::now() - *time > chrono::Duration::minutes(stall_minutes as i64),
+            _ => false,
+        }
+    }
+
+    pub fn should_refresh(&self) -> bool {
+        if !self.auto_refresh_enabled || self.auto_refresh_paused || self.auto_refresh_held || self.loading {
+            return false;
+        }
+
+        match self.last_refresh {
+            Some(last) => {
+                let elapsed = last.elapsed().as_secs();
+                elapsed >= self.auto_refresh_interval_secs
+            }
+            None => true, // Never refreshed, should refresh
+        }
+    }
+
+    /// Whether the list was last loaded at least `max_age_secs` ago (0 never counts as stale)
+    pub fn is_stale(&self, max_age_secs: u64) -> bool {
+        max_age_secs > 0
+            && !self.loading
+            && self.last_refresh.is_some_and(|last| last.elapsed().as_secs() >= max_age_secs)
+    }
+
+    pub fn mark_refreshed(&mut self) {
+        self.last_refresh = Some(std::time::Instant::now());
+    }
+
+    /// Byte offset of the cursor in the query
+    fn cursor_offset(&self) -> usize {
+        self.query
+            .char_indices()
+            .nth(self.cursor)
+            .map(|(offset, _)| offset)
+            .unwrap_or(self.query.len())
+    }
+
+    /// Insert typed or pasted text at the cursor and move past it
+    pub fn insert_at_cursor(&mut self, text: &str) {
+        let offset = self.cursor_offset();
+        self.query.insert_str(offset, text);
+        self.cursor += text.chars().count();
+    }
+
+    /// Backspace: remove the character before the cursor
+    pub fn delete_before_cursor(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            let offset = self.cursor_offset();
+            self.query.remove(offset);
+        }
+    }
+
+    /// Delete: remove the character under the cursor
+    pub fn delete_at_cursor(&mut self) {
+        let offset = self.cursor_offset();
+        if offset < self.query.len() {
+            self.query.remove(offset);
+        }
+    }
+
+    /// Move the cursor for Left/Right/Home/End
+    pub fn move_cursor(&mut self, key: KeyCode) {
+        let len = self.query.chars().count();
+        self.cursor = match key {
+            KeyCode::Left => self.cursor.saturating_sub(1),
+            KeyCode::Right => (self.cursor + 1).min(len),
+            KeyCode::Home => 0,
+            KeyCode::End => len,
+            _ => self.cursor,
+        };
+    }
+
+    /// The query split at the cursor, for rendering
+    pub fn query_around_cursor(&self) -> (&str, &str) {
+        self.query.split_at(self.cursor_offset())
+    }
+
+    /// The partial name when the cursor is inside an unfinished `WorkflowType = '...` value
+    pub fn typed_workflow_type(&self) -> Option<&str> {
+        let before = &self.query[..self.cursor_offset()];
+        let quote = before.rfind(['\'', '"'])?;
+        let clause = before[..quote].trim_end().strip_suffix('=')?;
+        if !clause.trim_end().ends_with("WorkflowType") {
+            return None;
+        }
+        Some(&before[quote + 1..])
+    }
+
+    /// Replace the partial workflow type before the cursor with `name` and close its quote
+    pub fn complete_workflow_type(&mut self, name: &str) {
+        let Some(partial) = self.typed_workflow_type() else {
+            return;
+        };
+        let partial_len = partial.chars().count();
+        let quote = self.query[..self.cursor_offset() - partial.len()].chars().last().unwrap_or('\'');
+        for _ in 0..partial_len {
+            self.delete_before_cursor();
+        }
+        self.insert_at_cursor(name);
+        if self.query[self.cursor_offset()..].starts_with(quote) {
+            self.cursor += 1;
+        } else {
+            self.insert_at_cursor(quote.encode_utf8(&mut [0; 4]));
+        }
+    }
+
+    pub fn get_query(&self) -> String {
+        // Build query from active filter and custom query
+        let mut queries = Vec::new();
+
+        if let Some(filter) = &self.active_filter {
+            let filter_query = match filter {
+                WorkflowFilter::All => None,
+                WorkflowFilter::Running => Some("ExecutionStatus = 'Running'"),
+                WorkflowFilter::Completed => Some("ExecutionStatus = 'Completed'"),
+                WorkflowFilter::Failed => Some("ExecutionStatus = 'Failed'"),
+                WorkflowFilter::Canceled => Some("ExecutionStatus = 'Canceled'"),
+            };
+            if let Some(fq) = filter_query {
+                queries.push(fq.to_string());
+            }
+        }
+
+        if let Some(range) = &self.time_range {
+            queries.push(range.query_clause());
+        }
+
+        if !self.query.is_empty() {
+            queries.push(self.query.clone());
+        }
+
+        let mut query = queries.join(" AND ");
+        // A search that orders its own results keeps its clause; archival stores can't order at all
+        if let Some(order) = self.order.filter(|_| !self.archived) {
+            if !self.query.to_ascii_uppercase().contains("ORDER BY") {
+                query = format!("{} ORDER BY {}", query, order.label()).trim_start().to_string();
+            }
+        }
+        query
+    }
+
+    pub fn has_next_page(&self) -> bool {
+        !self.next_page_token.is_empty()
+    }
+
+    pub fn has_prev_page(&self) -> bool {
+        !self.prev_page_tokens.is_empty()
+    }
+
+    /// Remember the page being left, so a failed page load can stay on it
+    fn start_paging(&mut self) {
+        self.paging_from = Some((self.current_page, self.prev_page_tokens.clone()));
+    }
+
+    /// Undo the page move of a failed page load, keeping the rows already shown.
+    /// Returns the page now shown, or None for a load that wasn't paging (or had nothing to keep)
+    fn restore_page(&mut self) -> Option<usize> {
+        let (page, tokens) = self.paging_from.take()?;
+        if self.items.is_empty() {
+            return None;
+        }
+        self.current_page = page;
+        self.prev_page_tokens = tokens;
+        self.loading = false;
+        Some(page)
+    }
+
+    /// Total number of pages, if the match count is known
+    pub fn total_pages(&self) -> Option<usize> {
+        let count = usize::try_from(self.total_count?).ok()?;
+        let page_size = WORKFLOW_PAGE_SIZE as usize;
+        Some((count.saturating_add(page_size - 1) / page_size).max(1))
+    }
+
+    /// The table's rows: every workflow, or with `group_by_type` one row per type on this
+    /// page (largest first) followed by its executions when expanded
+    pub fn visible_rows(&self) -> Vec<WorkflowRow> {
+        if !self.group_by_type {
+            return (0..self.items.len())
+                .map(|index| WorkflowRow::Workflow { index, grouped: false })
+                .collect();
+        }
+
+        let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+        for (index, workflow) in self.items.iter().enumerate() {
+            let workflow_type = workflow.r#type.as_ref().map(|t| t.name.clone()).unwrap_or_default();
+            match groups.iter_mut().find(|(name, _)| *name == workflow_type) {
+                Some((_, indices)) => indices.push(index),
+                None => groups.push((workflow_type, vec![index])),
+            }
+        }
+        // Stable, so equal-sized groups keep the order the server returned them in
+        groups.sort_by_key(|(_, indices)| std::cmp::Reverse(indices.len()));
+
+        let mut rows = Vec::new();
+        for (workflow_type, indices) in groups {
+            let expanded = self.expanded_types.contains(&workflow_type);
+            let running = indices
+                .iter()
+                .filter(|&&index| self.items[index].status == WorkflowExecutionStatus::Running as i32)
+                .count();
+            rows.push(WorkflowRow::Group {
+                workflow_type,
+                count: indices.len(),
+                running,
+                expanded,
+            });
+            if expanded {
+                rows.extend(indices.into_iter().map(|index| WorkflowRow::Workflow { index, grouped: true }));
+            }
+        }
+        rows
+    }
+
+    fn selected_row(&self) -> Option<WorkflowRow> {
+        self.table_state
+            .selected()
+            .and_then(|i| self.visible_rows().into_iter().nth(i))
+    }
+
+    /// Turn grouping by type on or off, starting from the top with every group collapsed
+    pub fn toggle_group_by_type(&mut self) {
+        self.group_by_type = !self.group_by_type;
+        self.expanded_types.clear();
+        let first = if self.items.is_empty() { None } else { Some(0) };
+        self.table_state.select(first);
+    }
+
+    /// Expand or collapse the selected type group; false if a workflow is selected instead
+    pub fn toggle_selected_group(&mut self) -> bool {
+        let Some(WorkflowRow::Group { workflow_type, .. }) = self.selected_row() else {
+            return false;
+        };
+        if !self.expanded_types.remove(&workflow_type) {
+            self.expanded_types.insert(workflow_type);
+        }
+        true
+    }
+
+    pub fn select_next(&mut self) {
+        let len = self.visible_rows().len();
+        if len == 0 {
+            return;
+        }
+        let i = match self.table_state.selected() {
+            Some(i) => {
+                if i >= len - 1 {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        self.table_state.select(Some(i));
+    }
+
+    pub fn select_previous(&mut self) {
+        let len = self.visible_rows().len();
+        if len == 0 {
+            return;
+        }
+        let i = match self.table_state.selected() {
+            Some(i) => {
+                if i == 0 {
+                    len - 1
+                } else {
+                    i - 1
+                }
+            }
+            None => 0,
+        };
+        self.table_state.select(Some(i));
+    }
+
+    /// The selected workflow; None when a type group is selected
+    pub fn selected_workflow(&self) -> Option<&WorkflowExecutionInfo> {
+        match self.selected_row() {
+            Some(WorkflowRow::Workflow { index, .. }) => self.items.get(index),
+            _ => None,
+        }
+    }
+
+    fn page_run_ids(&self) -> impl Iterator<Item = &String> {
+        self.items.iter().filter_map(|workflow| workflow.execution.as_ref().map(|e| &e.run_id))
+    }
+
+    pub fn is_marked(&self, workflow: &WorkflowExecutionInfo) -> bool {
+        workflow
+            .execution
+            .as_ref()
+            .is_some_and(|e| self.marked.contains(&e.run_id))
+    }
+
+    pub fn toggle_mark(&mut self) {
+        let Some(run_id) = self
+            .selected_workflow()
+            .and_then(|w| w.execution.as_ref())
+            .map(|e| e.run_id.clone())
+        else {
+            return;
+        };
+        if !self.marked.remove(&run_id) {
+            self.marked.insert(run_id);
+        }
+    }
+
+    pub fn mark_all(&mut self) {
+        let run_ids: Vec<String> = self.page_run_ids().cloned().collect();
+        self.marked.extend(run_ids);
+    }
+
+    /// Flip the marks of the workflows on this page; marks on other pages are kept
+    pub fn invert_marks(&mut self) {
+        let run_ids: Vec<String> = self.page_run_ids().cloned().collect();
+        for run_id in run_ids {
+            if !self.marked.remove(&run_id) {
+                self.marked.insert(run_id);
+            }
+        }
+    }
+}
+
+/// State for the workflow detail screen
+#[derive(Debug, Clone)]
+pub struct WorkflowDetailState {
+    pub workflow_id: Option<String>, // The workflow being shown or requested
+    pub run_id: String, // The run requested; empty means the latest
+    pub workflow: Option<WorkflowExecutionInfo>,
+    pub history: Vec<HistoryEvent>,
+    pub history_truncated: bool, // Paging stopped at `max_history_events`
+    pub table_state: TableState,
+    pub loading: bool,
+    pub reloading: bool, // Re-fetching with `r` while the current history stays on screen
+    pub error: Option<AppError>,
+    pub show_dialog: Option<WorkflowOperation>,
+    pub dialog_input: String,
+    pub dialog_args_input: String, // Second field, used by dialogs that take arguments
+    pub dialog_args_focused: bool,
+    pub restart_terminate: bool, // Restart dialog: terminate the running execution first
+    pub reset_reapply: ResetReapplyType, // Reset dialog: events to reapply after the reset point; Unspecified leaves it to the server
+    pub reset_point_choice: Option<usize>, // Reset dialog: index into `server_reset_points`; None uses the selected event
+    pub success_message: Option<String>,
+    pub notice: Option<String>, // Informational modal, e.g. an operation blocked by read-only mode
+    pub update_outcome: Option<UpdateOutcome>,
+    pub run_diff: Option<RunDiff>, // Open while comparing runs of a continue-as-new chain
+    pub show_metadata: bool, // Every metadata field in a scrollable modal
+    pub metadata_scroll: u16,
+    pub task_queue_backlog: Option<TaskQueueBacklog>, // For a running workflow, when the server reports stats
+    pub pending_workflow_task: Option<PendingWorkflowTaskInfo>, // From describe, for a running workflow
+    pub show_event_detail: bool,
+    pub event_detail_scroll_offset: u16,
+    pub event_detail_wrap: bool, // Wrap long lines in the event modal; off scrolls them horizontally
+    pub event_detail_hscroll: u16, // Columns scrolled right while unwrapped
+    pub event_detail_raw: bool, // Show the event as a hex dump of its encoded protobuf instead of decoded
+    event_detail_scrolls: HashMap<(String, i64), u16>, // Last modal scroll per (workflow ID, event ID)
+    pub modal_notice: Option<String>, // Shown in the event modal title (copy result, unreachable reference) until the next key
+    pub selected_reference: usize, // Index into the modal event's references, followed with Enter
+    pub following: Option<Arc<AtomicBool>>, // Set while new events stream in; storing true stops the poller
+    follow_arrivals: VecDeque<(Instant, usize)>, // When followed events arrived and how many, within FOLLOW_RATE_WINDOW
+    pub search_mode: bool,
+    pub search_query: String,
+    pub search_matches: Vec<usize>, // Indices into history
+    search_text: Vec<Option<String>>, // Lazily built lowercase text per event
+    pub group_activities: bool, // Collapse each activity's attempts into one row
+    pub hide_workflow_tasks: bool, // Leave workflow task events out of the rows and search
+    pub activity_groups: Vec<ActivityGroup>,
+    pub expanded_groups: HashSet<i64>, // Keyed by the group's first event ID
+    pub rows: Vec<HistoryRow>, // What the history table shows; the selection indexes into this
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkflowOperation {
+    Terminate,
+    Cancel,
+    Signal,
+    Update,
+    Restart,
+    Reset,
+}
+
+impl WorkflowOperation {
+    pub fn name(&self) -> &'static str {
+        match self {
+            WorkflowOperation::Terminate => "terminate",
+            WorkflowOperation::Cancel => "cancel",
+            WorkflowOperation::Signal => "signal",
+            WorkflowOperation::Update => "update",
+            WorkflowOperation::Restart => "restart",
+            WorkflowOperation::Reset => "reset",
+        }
+    }
+}
+
+impl WorkflowDetailState {
+    pub fn new() -> Self {
+        Self {
+            workflow_id: None,
+            run_id: String::new(),
+            workflow: None,
+            history: Vec::new(),
+            history_truncated: false,
+            table_state: TableState::default(),
+            loading: false,
+            reloading: false,
+            error: None,
+            show_dialog: None,
+            dialog_input: String::new(),
+            dialog_args_input: String::new(),
+            dialog_args_focused: false,
+            restart_terminate: true,
+            reset_reapply: ResetReapplyType::Unspecified,
+            reset_point_choice: None,
+            success_message: None,
+            notice: None,
+            update_outcome: None,
+            run_diff: None,
+            show_metadata: false,
+            metadata_scroll: 0,
+            task_queue_backlog: None,
+            pending_workflow_task: None,
+            show_event_detail: false,
+            event_detail_scroll_offset: 0,
+            event_detail_wrap: true,
+            event_detail_hscroll: 0,
+            event_detail_raw: false,
+            event_detail_scrolls: HashMap::new(),
+            modal_notice: None,
+            selected_reference: 0,
+            following: None,
+            follow_arrivals: VecDeque::new(),
+            search_mode: false,
+            search_query: String::new(),
+            search_matches: Vec::new(),
+            search_text: Vec::new(),
+            group_activities: false,
+            hide_workflow_tasks: false,
+            activity_groups: Vec::new(),
+            expanded_groups: HashSet::new(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Key for remembering the modal scroll of the selected event
+    fn event_detail_key(&self) -> Option<(String, i64)> {
+        let workflow_id = self.workflow_id.clone()?;
+        self.selected_event().map(|event| (workflow_id, event.event_id))
+    }
+
+    /// Show the selected event's modal where it was last left this session
+    pub fn open_event_detail(&mut self) {
+        let Some(key) = self.event_detail_key() else {
+            return;
+        };
+        self.event_detail_scroll_offset = self.event_detail_scrolls.get(&key).copied().unwrap_or(0);
+        self.event_detail_hscroll = 0;
+        self.selected_reference = 0;
+        self.show_event_detail = true;
+    }
+
+    /// Highlight the modal event's next reference, wrapping around
+    pub fn next_reference(&mut self) {
+        let count = self.selected_event().map(|event| event_references(event).len()).unwrap_or(0);
+        if count > 0 {
+            self.selected_reference = (self.selected_reference + 1) % count;
+        }
+    }
+
+    /// Show the event the highlighted reference points to in the modal, selecting it in the history.
+    /// False if that event isn't in the loaded history.
+    pub fn follow_reference(&mut self) -> bool {
+        let Some(target) = self
+            .selected_event()
+            .and_then(|event| event_references(event).get(self.selected_reference).map(|(_, id)| *id))
+        else {
+            return false;
+        };
+        let Some(index) = self.history.iter().position(|event| event.event_id == target) else {
+            return false;
+        };
+        self.close_event_detail();
+        // Expand a collapsed group so the event gets its own row
+        if let Some(group) = self.activity_groups.iter().find(|group| group.events.contains(&index)) {
+            self.expanded_groups.insert(group.key(&self.history));
+            self.rebuild_rows();
+        }
+        // References often point at a workflow task completion; show those again rather than miss it
+        if self.hide_workflow_tasks && is_workflow_task_event(&self.history[index]) {
+            self.hide_workflow_tasks = false;
+            self.rebuild_rows();
+            self.find_matches();
+        }
+        self.table_state.select(self.row_for_event(index));
+        self.open_event_detail();
+        true
+    }
+
+    pub fn close_event_detail(&mut self) {
+        if let Some(key) = self.event_detail_key() {
+            self.event_detail_scrolls.insert(key, self.event_detail_scroll_offset);
+        }
+        self.show_event_detail = false;
+        self.event_detail_scroll_offset = 0;
+    }
+
+    /// Replace the history, regrouping it and keeping the selection in range
+    pub fn set_history(&mut self, history: Vec<HistoryEvent>) {
+        self.history = history;
+        self.search_text.clear();
+        self.regroup();
+    }
+
+    fn regroup(&mut self) {
+        self.activity_groups = if self.group_activities {
+            group_activities(&self.history)
+        } else {
+            Vec::new()
+        };
+        self.rebuild_rows();
+        if self.table_state.selected().is_some_and(|row| row >= self.rows.len()) {
+            self.table_state.select(self.rows.len().checked_sub(1));
+        }
+    }
+
+    fn rebuild_rows(&mut self) {
+        self.rows = build_rows(
+            &self.history,
+            &self.activity_groups,
+            &self.expanded_groups,
+            self.hide_workflow_tasks,
+        );
+    }
+
+    /// Whether an event is left out of the rows because workflow task events are hidden
+    fn is_hidden(&self, index: usize) -> bool {
+        self.hide_workflow_tasks && is_workflow_task_event(&self.history[index])
+    }
+
+    /// How many events the rows leave out, for the history title
+    pub fn hidden_event_count(&self) -> usize {
+        (0..self.history.len()).filter(|&i| self.is_hidden(i)).count()
+    }
+
+    /// Show or hide workflow task events, staying on the selected event (or the next shown one)
+    pub fn toggle_workflow_tasks(&mut self) {
+        let event = self.selected_event_index();
+        self.hide_workflow_tasks = !self.hide_workflow_tasks;
+        self.rebuild_rows();
+        if let Some(event) = event {
+            let row = self
+                .row_for_event(event)
+                .or_else(|| self.rows.iter().position(|&row| self.row_event(row) >= event))
+                .or(self.rows.len().checked_sub(1));
+            self.table_state.select(row);
+        }
+        self.find_matches();
+    }
+
+    /// Switch between one row per event and grouped activity attempts, staying on the same event
+    pub fn toggle_grouping(&mut self) {
+        let event = self.selected_event_index();
+        self.group_activities = !self.group_activities;
+        self.regroup();
+        if let Some(event) = event {
+            self.table_state.select(self.row_for_event(event));
+        }
+    }
+
+    /// Expand or collapse the selected group; false if a group isn't selected
+    pub fn toggle_selected_group(&mut self) -> bool {
+        let Some(HistoryRow::Group(index)) = self.selected_row() else {
+            return false;
+        };
+        let key = self.activity_groups[index].key(&self.history);
+        if !self.expanded_groups.remove(&key) {
+            self.expanded_groups.insert(key);
+        }
+        self.rebuild_rows();
+        true
+    }
+
+    fn selected_row(&self) -> Option<HistoryRow> {
+        self.table_state.selected().and_then(|row| self.rows.get(row).copied())
+    }
+
+    /// History index of the event a row stands for (a group's latest event)
+    fn row_event(&self, row: HistoryRow) -> usize {
+        match row {
+            HistoryRow::Event(i) | HistoryRow::Member(i) => i,
+            HistoryRow::Group(index) => *self.activity_groups[index].events.last().unwrap_or(&0),
+        }
+    }
+
+    pub fn selected_event_index(&self) -> Option<usize> {
+        self.selected_row().map(|row| self.row_event(row))
+    }
+
+    /// Whether a row shows a search match (for a collapsed group, any of its events)
+    pub fn row_matches(&self, row: HistoryRow) -> bool {
+        match row {
+            HistoryRow::Event(i) | HistoryRow::Member(i) => self.search_matches.binary_search(&i).is_ok(),
+            HistoryRow::Group(index) => self.activity_groups[index]
+                .events
+                .iter()
+                .any(|i| self.search_matches.binary_search(i).is_ok()),
+        }
+    }
+
+    /// The row showing an event: its own row, or its group's when collapsed
+    fn row_for_event(&self, event: usize) -> Option<usize> {
+        self.rows
+            .iter()
+            .position(|row| matches!(row, HistoryRow::Event(i) | HistoryRow::Member(i) if *i == event))
+            .or_else(|| {
+                self.rows.iter().position(|row| {
+                    matches!(row, HistoryRow::Group(index) if self.activity_groups[*index].events.contains(&event))
+                })
+            })
+    }
+
+    pub fn open_dialog(&mut self, operation: WorkflowOperation) {
+        self.show_dialog = Some(operation);
+        self.dialog_input.clear();
+        self.dialog_args_input.clear();
+        self.dialog_args_focused = false;
+        self.restart_terminate = true;
+        self.reset_reapply = ResetReapplyType::Unspecified;
+        self.reset_point_choice = None;
+        self.success_message = None;
+        self.error = None;
+    }
+
+    /// Attributes the current run was started with, from the first history event
+    pub fn started_attributes(&self) -> Option<&WorkflowExecutionStartedEventAttributes> {
+        match self.history.first().and_then(|e| e.attributes.as_ref()) {
+            Some(history_event::Attributes::WorkflowExecutionStartedEventAttributes(attrs)) => Some(attrs),
+            _ => None,
+        }
+    }
+
+    /// The run this one continued as, from a closing `WorkflowExecutionContinuedAsNew` event
+    pub fn continued_as_run_id(&self) -> Option<&str> {
+        match self.history.last().and_then(|e| e.attributes.as_ref()) {
+            Some(history_event::Attributes::WorkflowExecutionContinuedAsNewEventAttributes(attrs))
+                if !attrs.new_execution_run_id.is_empty() =>
+            {
+                Some(&attrs.new_execution_run_id)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the shown workflow is still running
+    pub fn is_running(&self) -> bool {
+        self.workflow
+            .as_ref()
+            .is_some_and(|w| w.status == WorkflowExecutionStatus::Running as i32)
+    }
+
+    /// The WorkflowTaskCompleted event a reset goes back to: the server reset point picked in the
+    /// dialog, otherwise the latest one at or before the selected event (or in the whole history)
+    pub fn reset_point(&self) -> Option<i64> {
+        if let Some(point) = self.reset_point_choice.and_then(|i| self.server_reset_points().get(i).copied()) {
+            return Some(point.first_workflow_task_completed_id);
+        }
+        let end = self.selected_event_index().map_or(self.history.len(), |i| i + 1);
+        self.history[..end.min(self.history.len())]
+            .iter()
+            .rev()
+            .find(|e| e.event_type == EventType::WorkflowTaskCompleted as i32)
+            .map(|e| e.event_id)
+    }
+
+    /// The server's auto reset points that can still be used for this run, oldest first.
+    /// Points carried over from earlier runs of a continue-as-new chain are left out.
+    pub fn server_reset_points(&self) -> Vec<&ResetPointInfo> {
+        let Some(workflow) = &self.workflow else {
+            return Vec::new();
+        };
+        let run_id = workflow.execution.as_ref().map(|e| e.run_id.as_str()).unwrap_or_default();
+        workflow
+            .auto_reset_points
+            .as_ref()
+            .map(|points| {
+                points
+                    .points
+                    .iter()
+                    .filter(|point| point.resettable && (point.run_id.is_empty() || point.run_id == run_id))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Reset dialog: step through the server reset points, with the selected event's point before the first
+    pub fn step_reset_point(&mut self, forward: bool) {
+        let count = self.server_reset_points().len();
+        if count == 0 {
+            return;
+        }
+        self.reset_point_choice = match (self.reset_point_choice, forward) {
+            (None, true) => Some(0),
+            (Some(i), true) => Some((i + 1).min(count - 1)),
+            (Some(0), false) | (None, false) => None,
+            (Some(i), false) => Some(i - 1),
+        };
+    }
+
+    /// Reset dialog: server default -> all eligible -> signals only -> none
+    pub fn cycle_reset_reapply(&mut self) {
+        self.reset_reapply = match self.reset_reapply {
+            ResetReapplyType::Unspecified => ResetReapplyType::AllEligible,
+            ResetReapplyType::AllEligible => ResetReapplyType::Signal,
+            ResetReapplyType::Signal => ResetReapplyType::None,
+            ResetReapplyType::None => ResetReapplyType::Unspecified,
+        };
+    }
+
+    pub fn close_dialog(&mut self) {
+        self.show_dialog = None;
+        self.dialog_input.clear();
+        self.dialog_args_input.clear();
+        self.dialog_args_focused = false;
+    }
+
+    /// Searchable text for an event: its type plus the attribute dump with payloads decoded.
+    /// Built on first use since large histories carry a lot of payload data.
+    fn event_search_text(&mut self, index: usize) -> &str {
+        if self.search_text.len() != self.history.len() {
+            self.search_text = vec![None; self.history.len()];
+        }
+        let event = &self.history[index];
+        self.search_text[index].get_or_insert_with(|| {
+            let event_type = EventType::try_from(event.event_type)
+                .map(|t| t.as_str_name())
+                .unwrap_or_default();
+            format!("{} {}", event_type, decode_debug_bytes(&format!("{:?}", event.attributes)))
+                .to_lowercase()
+        })
+    }
+
+    /// Recompute which shown events match the search query, leaving the selection alone
+    fn find_matches(&mut self) {
+        let query = self.search_query.trim().to_lowercase();
+        self.search_matches.clear();
+        if query.is_empty() {
+            return;
+        }
+        for i in 0..self.history.len() {
+            if !self.is_hidden(i) && self.event_search_text(i).contains(&query) {
+                self.search_matches.push(i);
+            }
+        }
+    }
+
+    /// Find all events matching the search query and jump to the first one after the selection
+    pub fn run_search(&mut self) {
+        self.find_matches();
+        let current = self.selected_event_index().unwrap_or(0);
+        let first = self
+            .search_matches
+            .iter()
+            .find(|&&i| i >= current)
+            .or(self.search_matches.first());
+        if let Some(&i) = first {
+            self.table_state.select(self.row_for_event(i));
+        }
+    }
+
+    /// Jump to the next (or previous) search match, wrapping around
+    pub fn jump_to_match(&mut self, forward: bool) {
+        if self.search_matches.is_empty() {
+            return;
+        }
+        // From a collapsed group, skip past all of its events
+        let (first, last) = match self.selected_row() {
+            Some(HistoryRow::Group(index)) => {
+                let events = &self.activity_groups[index].events;
+                (events[0], *events.last().unwrap_or(&events[0]))
+            }
+            Some(row) => (self.row_event(row), self.row_event(row)),
+            None => (0, 0),
+        };
+        let target = if forward {
+            self.search_matches
+                .iter()
+                .find(|&&i| i > last)
+                .or(self.search_matches.first())
+        } else {
+            self.search_matches
+                .iter()
+                .rev()
+                .find(|&&i| i < first)
+                .or(self.search_matches.last())
+        };
+        if let Some(&i) = target {
+            self.table_state.select(self.row_for_event(i));
+        }
+    }
+
+    /// Add events that arrived while following, keeping the selection on the newest row if it was there
+    pub fn append_history(&mut self, events: Vec<HistoryEvent>) {
+        let at_end = match self.table_state.selected() {
+            Some(row) => row + 1 >= self.rows.len(),
+            None => true,
+        };
+        let first_new = self.history.len();
+        let now = Instant::now();
+        self.follow_arrivals.push_back((now, events.len()));
+        while self.follow_arrivals.front().is_some_and(|(at, _)| now.duration_since(*at) > FOLLOW_RATE_WINDOW) {
+            self.follow_arrivals.pop_front();
+        }
+        self.history.extend(events);
+        self.regroup();
+
+        let query = self.search_query.trim().to_lowercase();
+        if !query.is_empty() {
+            for i in first_new..self.history.len() {
+                if !self.is_hidden(i) && self.event_search_text(i).contains(&query) {
+                    self.search_matches.push(i);
+                }
+            }
+        }
+        if at_end && !self.rows.is_empty() {
+            self.table_state.select(Some(self.rows.len() - 1));
+        }
+    }
+
+    /// Stop streaming new events, if following
+    pub fn stop_following(&mut self) {
+        if let Some(stop) = self.following.take() {
+            stop.store(true, Ordering::Relaxed);
+        }
+        self.follow_arrivals.clear();
+    }
+
+    /// Followed events that arrived within the last `FOLLOW_RATE_WINDOW`
+    pub fn recent_event_count(&self) -> usize {
+        self.follow_arrivals
+            .iter()
+            .filter(|(at, _)| at.elapsed() <= FOLLOW_RATE_WINDOW)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    pub fn clear_search(&mut self) {
+        self.search_mode = false;
+        self.search_query.clear();
+        self.search_matches.clear();
+    }
+
+    pub fn selected_event(&self) -> Option<&HistoryEvent> {
+        self.selected_event_index().and_then(|i| self.history.get(i))
+    }
+
+    pub fn select_next(&mut self) {
+        if self.rows.is_empty() {
+            return;
+        }
+        let i = match self.table_state.selected() {
+            Some(i) => {
+                if i >= self.rows.len() - 1 {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        self.table_state.select(Some(i));
+    }
+
+    pub fn select_previous(&mut self) {
+        if self.rows.is_empty() {
+            return;
+        }
+        let i = match self.table_state.selected() {
+            Some(i) => {
+                if i == 0 {
+                    self.rows.len() - 1
+                } else {
+                    i - 1
+                }
+            }
+            None => 0,
+        };
+        self.table_state.select(Some(i));
+    }
+}
+
+/// State for the namespace list screen
+#[derive(Debug, Clone)]
+pub struct NamespaceListState {
+    pub items: Vec<DescribeNamespaceResponse>,
+    pub table_state: TableState,
+    pub loading: bool,
+    pub error: Option<AppError>,
+    pub state_filter: NamespaceStateFilter,
+    pub dialog: Option<NamespaceDialog>,
+    pub message: Option<String>, // Shown in a modal until any key is pressed
+    pub show_detail: bool,       // Details of the selected namespace in a modal
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub enum WorkflowFilter {
-    All,
-    Running,
-    Completed,
-    Failed,
-    Canceled,
+/// Fields of the namespace create/update dialog
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NamespaceField {
+    Name,
+    Description,
+    RetentionDays,
 }
 
-impl WorkflowListState {
-    pub fn new() -> Self {
+impl NamespaceField {
+    pub fn label(&self) -> &'static str {
+        match self {
+            NamespaceField::Name => "Name",
+            NamespaceField::Description => "Description",
+            NamespaceField::RetentionDays => "Retention (days)",
+        }
+    }
+}
+
+/// Dialog for registering a new namespace or updating the selected one
+#[derive(Debug, Clone)]
+pub struct NamespaceDialog {
+    pub is_update: bool,
+    pub name: String,
+    pub description: String,
+    pub retention_days: String,
+    pub focused: usize, // Index into fields()
+}
+
+impl NamespaceDialog {
+    pub fn create() -> Self {
         Self {
-            items: Vec::new(),
-            table_state: TableState::default(),
-            next_page_token: Vec::new(),
-            prev_page_tokens: Vec::new(),
-            loading: false,
-            error: None,
-            current_page: 1,
-            query: String::new(),
-            query_history: Vec::new(),
-            input_mode: false,
-            active_filter: None,
-            auto_refresh_enabled: false,
-            auto_refresh_interval_secs: 5, // Default 5 seconds
-            last_refresh: None,
+            is_update: false,
+            name: String::new(),
+            description: String::new(),
+            retention_days: "3".to_string(),
+            focused: 0,
         }
     }
 
-    pub fn should_refresh(&self) -> bool {
-        if !self.auto_refresh_enabled || self.loading {
-            return false;
+    /// Prefill from the namespace being edited
+    pub fn update(namespace: &DescribeNamespaceResponse) -> Self {
+        let info = namespace.namespace_info.as_ref();
+        let retention_days = namespace
+            .config
+            .as_ref()
+            .and_then(|c| c.workflow_execution_retention_ttl.as_ref())
+            .map(|ttl| (ttl.seconds / 86400).to_string())
+            .unwrap_or_default();
+
+        Self {
+            is_update: true,
+            name: info.map(|i| i.name.clone()).unwrap_or_default(),
+            description: info.map(|i| i.description.clone()).unwrap_or_default(),
+            retention_days,
+            focused: 0,
         }
+    }
 
-        match self.last_refresh {
-            Some(last) => {
-                let elapsed = last.elapsed().as_secs();
-                elapsed >= self.auto_refresh_interval_secs
-            }
-            None => true, // Never refreshed, should refresh
+    /// Editable fields; a namespace can't be renamed
+    pub fn fields(&self) -> &'static [NamespaceField] {
+        if self.is_update {
+            &[NamespaceField::Description, NamespaceField::RetentionDays]
+        } else {
+            &[NamespaceField::Name, NamespaceField::Description, NamespaceField::RetentionDays]
         }
     }
 
-    pub fn mark_refreshed(&mut self) {
-        self.last_refresh = Some(std::time::Instant::now());
+    pub fn focused_field(&self) -> NamespaceField {
+        self.fields()[self.focused]
     }
 
-    pub fn get_query(&self) -> String {
-        // Build query from active filter and custom query
-        let mut queries = Vec::new();
+    pub fn value(&self, field: NamespaceField) -> &str {
+        match field {
+            NamespaceField::Name => &self.name,
+            NamespaceField::Description => &self.description,
+            NamespaceField::RetentionDays => &self.retention_days,
+        }
+    }
 
-        if let Some(filter) = &self.active_filter {
-            let filter_query = match filter {
-                WorkflowFilter::All => None,
-                WorkflowFilter::Running => Some("ExecutionStatus = 'Running'"),
-                WorkflowFilter::Completed => Some("ExecutionStatus = 'Completed'"),
-                WorkflowFilter::Failed => Some("ExecutionStatus = 'Failed'"),
-                WorkflowFilter::Canceled => Some("ExecutionStatus = 'Canceled'"),
-            };
-            if let Some(fq) = filter_query {
-                queries.push(fq.to_string());
-            }
+    fn focused_value_mut(&mut self) -> &mut String {
+        match self.focused_field() {
+            NamespaceField::Name => &mut self.name,
+            NamespaceField::Description => &mut self.description,
+            NamespaceField::RetentionDays => &mut self.retention_days,
         }
+    }
 
-        if !self.query.is_empty() {
-            queries.push(self.query.clone());
+    /// Turn the form into a register/update command, validating its fields
+    fn to_command(&self) -> std::result::Result<AppCommand, String> {
+        let name = self.name.trim().to_string();
+        if name.is_empty() {
+            return Err("Namespace name cannot be empty".to_string());
         }
+        let retention_days = match self.retention_days.trim() {
+            "" => None,
+            days => Some(
+                days.parse::<u32>()
+                    .ok()
+                    .filter(|d| *d > 0)
+                    .ok_or_else(|| format!("Invalid retention '{}': expected a number of days", days))?,
+            ),
+        };
+        let description = self.description.trim().to_string();
 
-        queries.join(" AND ")
+        Ok(if self.is_update {
+            AppCommand::UpdateNamespace(name, description, retention_days)
+        } else {
+            AppCommand::RegisterNamespace(name, description, retention_days)
+        })
     }
+}
 
-    pub fn has_next_page(&self) -> bool {
-        !self.next_page_token.is_empty()
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NamespaceStateFilter {
+    All,
+    RegisteredOnly,
+    ExcludeDeleted,
+}
+
+impl NamespaceStateFilter {
+    pub fn next(self) -> Self {
+        match self {
+            NamespaceStateFilter::All => NamespaceStateFilter::RegisteredOnly,
+            NamespaceStateFilter::RegisteredOnly => NamespaceStateFilter::ExcludeDeleted,
+            NamespaceStateFilter::ExcludeDeleted => NamespaceStateFilter::All,
+        }
     }
 
-    pub fn has_prev_page(&self) -> bool {
-        !self.prev_page_tokens.is_empty()
+    pub fn label(self) -> &'static str {
+        match self {
+            NamespaceStateFilter::All => "All",
+            NamespaceStateFilter::RegisteredOnly => "Registered",
+            NamespaceStateFilter::ExcludeDeleted => "Not Deleted",
+        }
+    }
+
+    fn matches(self, state: i32) -> bool {
+        let state = NamespaceState::try_from(state).unwrap_or(NamespaceState::Unspecified);
+        match self {
+            NamespaceStateFilter::All => true,
+            NamespaceStateFilter::RegisteredOnly => state == NamespaceState::Registered,
+            NamespaceStateFilter::ExcludeDeleted => state != NamespaceState::Deleted,
+        }
+    }
+}
+
+impl NamespaceListState {
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            table_state: TableState::default(),
+            loading: false,
+            error: None,
+            state_filter: NamespaceStateFilter::All,
+            dialog: None,
+            message: None,
+            show_detail: false,
+        }
+    }
+
+    /// Namespaces that pass the active state filter, in display order
+    pub fn visible_items(&self) -> Vec<&DescribeNamespaceResponse> {
+        self.items
+            .iter()
+            .filter(|ns| {
+                let state = ns.namespace_info.as_ref().map(|info| info.state).unwrap_or_default();
+                self.state_filter.matches(state)
+            })
+            .collect()
+    }
+
+    /// Move pinned namespaces to the top in their configured order; the rest keep the server's order
+    pub fn pin(&mut self, pinned: &[String]) {
+        self.items.sort_by_key(|ns| {
+            let name = ns.namespace_info.as_ref().map(|info| info.name.as_str()).unwrap_or_default();
+            pinned.iter().position(|p| p == name).unwrap_or(pinned.len())
+        });
+    }
+
+    pub fn cycle_state_filter(&mut self) {
+        self.state_filter = self.state_filter.next();
+        let selection = if self.visible_items().is_empty() { None } else { Some(0) };
+        self.table_state.select(selection);
     }
 
     pub fn select_next(&mut self) {
-        if self.items.is_empty() {
+        let len = self.visible_items().len();
+        if len == 0 {
             return;
         }
         let i = match self.table_state.selected() {
             Some(i) => {
-                if i >= self.items.len() - 1 {
+                if i >= len - 1 {
                     0
                 } else {
                     i + 1
@@ -174,13 +2299,14 @@ impl WorkflowListState {
     }
 
     pub fn select_previous(&mut self) {
-        if self.items.is_empty() {
+        let len = self.visible_items().len();
+        if len == 0 {
             return;
         }
         let i = match self.table_state.selected() {
             Some(i) => {
                 if i == 0 {
-                    self.items.len() - 1
+                    len - 1
                 } else {
                     i - 1
                 }
@@ -190,102 +2316,164 @@ impl WorkflowListState {
         self.table_state.select(Some(i));
     }
 
-    pub fn selected_workflow(&self) -> Option<&WorkflowExecutionInfo> {
+    pub fn selected_namespace(&self) -> Option<&DescribeNamespaceResponse> {
         self.table_state
             .selected()
-            .and_then(|i| self.items.get(i))
+            .and_then(|i| self.visible_items().get(i).copied())
     }
 }
 
-/// State for the workflow detail screen
+/// State for the all-namespaces workflow view
 #[derive(Debug, Clone)]
-pub struct WorkflowDetailState {
-    pub workflow: Option<WorkflowExecutionInfo>,
-    pub history: Vec<HistoryEvent>,
+pub struct AggregateState {
+    pub rows: Vec<(String, WorkflowExecutionInfo)>, // (namespace, workflow), newest first
+    pub errors: Vec<(String, String)>, // Namespaces whose query failed; the rest still show
     pub table_state: TableState,
     pub loading: bool,
-    pub error: Option<String>,
-    pub show_dialog: Option<WorkflowOperation>,
-    pub dialog_input: String,
-    pub success_message: Option<String>,
-    pub show_event_detail: bool,
-    pub event_detail_scroll_offset: u16,
+    pub query: String, // The query the rows were loaded with
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub enum WorkflowOperation {
-    Terminate,
-    Cancel,
-    Signal,
+impl AggregateState {
+    pub fn new() -> Self {
+        Self {
+            rows: Vec::new(),
+            errors: Vec::new(),
+            table_state: TableState::default(),
+            loading: false,
+            query: String::new(),
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.rows.is_empty() {
+            return;
+        }
+        let i = match self.table_state.selected() {
+            Some(i) if i + 1 < self.rows.len() => i + 1,
+            _ => 0,
+        };
+        self.table_state.select(Some(i));
+    }
+
+    pub fn select_previous(&mut self) {
+        if self.rows.is_empty() {
+            return;
+        }
+        let i = match self.table_state.selected() {
+            Some(i) if i > 0 => i - 1,
+            _ => self.rows.len() - 1,
+        };
+        self.table_state.select(Some(i));
+    }
 }
 
-impl WorkflowDetailState {
+/// State for the batch operations screen
+#[derive(Debug, Clone)]
+pub struct BatchListState {
+    pub items: Vec<DescribeBatchOperationResponse>,
+    pub table_state: TableState,
+    pub loading: bool,
+    pub error: Option<AppError>,
+    pub state_filter: Option<BatchOperationState>, // None shows every state
+}
+
+impl BatchListState {
     pub fn new() -> Self {
         Self {
-            workflow: None,
-            history: Vec::new(),
+            items: Vec::new(),
             table_state: TableState::default(),
             loading: false,
             error: None,
-            show_dialog: None,
-            dialog_input: String::new(),
-            success_message: None,
-            show_event_detail: false,
-            event_detail_scroll_offset: 0,
+            state_filter: None,
         }
     }
 
-    pub fn selected_event(&self) -> Option<&HistoryEvent> {
-        self.table_state
-            .selected()
-            .and_then(|i| self.history.get(i))
+    /// Batch operations that pass the active state filter, in display order
+    pub fn visible_items(&self) -> Vec<&DescribeBatchOperationResponse> {
+        self.items
+            .iter()
+            .filter(|op| match self.state_filter {
+                Some(state) => op.state == state as i32,
+                None => true,
+            })
+            .collect()
+    }
+
+    pub fn filter_label(&self) -> &'static str {
+        match self.state_filter {
+            None => "All",
+            Some(state) => batch_state_label(state as i32),
+        }
+    }
+
+    pub fn cycle_state_filter(&mut self) {
+        self.state_filter = match self.state_filter {
+            None => Some(BatchOperationState::Running),
+            Some(BatchOperationState::Running) => Some(BatchOperationState::Completed),
+            Some(BatchOperationState::Completed) => Some(BatchOperationState::Failed),
+            Some(_) => None,
+        };
+        let selection = if self.visible_items().is_empty() { None } else { Some(0) };
+        self.table_state.select(selection);
     }
 
     pub fn select_next(&mut self) {
-        if self.history.is_empty() {
+        let len = self.visible_items().len();
+        if len == 0 {
             return;
         }
         let i = match self.table_state.selected() {
-            Some(i) => {
-                if i >= self.history.len() - 1 {
-                    0
-                } else {
-                    i + 1
-                }
-            }
-            None => 0,
+            Some(i) if i + 1 < len => i + 1,
+            _ => 0,
         };
         self.table_state.select(Some(i));
     }
 
     pub fn select_previous(&mut self) {
-        if self.history.is_empty() {
+        let len = self.visible_items().len();
+        if len == 0 {
             return;
         }
         let i = match self.table_state.selected() {
-            Some(i) => {
-                if i == 0 {
-                    self.history.len() - 1
-                } else {
-                    i - 1
-                }
-            }
-            None => 0,
+            Some(0) | None => len - 1,
+            Some(i) => i - 1,
         };
         self.table_state.select(Some(i));
     }
 }
 
-/// State for the namespace list screen
+/// Display name of a batch operation state
+pub fn batch_state_label(state: i32) -> &'static str {
+    match BatchOperationState::try_from(state) {
+        Ok(BatchOperationState::Running) => "Running",
+        Ok(BatchOperationState::Completed) => "Completed",
+        Ok(BatchOperationState::Failed) => "Failed",
+        _ => "Unknown",
+    }
+}
+
+/// Display name of a batch operation type
+pub fn batch_type_label(operation_type: i32) -> &'static str {
+    match BatchOperationType::try_from(operation_type) {
+        Ok(BatchOperationType::Terminate) => "Terminate",
+        Ok(BatchOperationType::Cancel) => "Cancel",
+        Ok(BatchOperationType::Signal) => "Signal",
+        Ok(BatchOperationType::Delete) => "Delete",
+        Ok(BatchOperationType::Reset) => "Reset",
+        _ => "Unknown",
+    }
+}
+
+/// State for the Nexus endpoints screen. Endpoints belong to the cluster, not a namespace
 #[derive(Debug, Clone)]
-pub struct NamespaceListState {
-    pub items: Vec<DescribeNamespaceResponse>,
+pub struct NexusListState {
+    pub items: Vec<Endpoint>,
     pub table_state: TableState,
     pub loading: bool,
-    pub error: Option<String>,
+    pub error: Option<AppError>,
 }
 
-impl NamespaceListState {
+impl NexusListState {
     pub fn new() -> Self {
         Self {
             items: Vec::new(),
@@ -296,87 +2484,275 @@ impl NamespaceListState {
     }
 
     pub fn select_next(&mut self) {
-        if self.items.is_empty() {
+        let len = self.items.len();
+        if len == 0 {
             return;
         }
         let i = match self.table_state.selected() {
-            Some(i) => {
-                if i >= self.items.len() - 1 {
-                    0
-                } else {
-                    i + 1
-                }
-            }
-            None => 0,
+            Some(i) if i + 1 < len => i + 1,
+            _ => 0,
+        };
+        self.table_state.select(Some(i));
+    }
+
+    pub fn select_previous(&mut self) {
+        let len = self.items.len();
+        if len == 0 {
+            return;
+        }
+        let i = match self.table_state.selected() {
+            Some(0) | None => len - 1,
+            Some(i) => i - 1,
         };
         self.table_state.select(Some(i));
     }
+}
+
+/// State for the help screen
+#[derive(Debug, Clone)]
+pub struct HelpState {
+    pub scroll_offset: u16,
+    pub return_to: Screen, // Screen help was opened from
+}
+
+impl HelpState {
+    pub fn new() -> Self {
+        Self {
+            scroll_offset: 0,
+            return_to: Screen::Workflows,
+        }
+    }
+
+    pub fn scroll_down(&mut self, amount: u16) {
+        self.scroll_offset = self.scroll_offset.saturating_add(amount);
+    }
+
+    pub fn scroll_up(&mut self, amount: u16) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(amount);
+    }
+}
+
+/// Fields of the first-run setup form, in tab order
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SetupField {
+    Address,
+    Namespace,
+    ApiKey,
+}
+
+impl SetupField {
+    pub const ALL: [SetupField; 3] = [SetupField::Address, SetupField::Namespace, SetupField::ApiKey];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SetupField::Address => "Address",
+            SetupField::Namespace => "Namespace",
+            SetupField::ApiKey => "API key (optional)",
+        }
+    }
+}
+
+/// State for the first-run setup wizard shown when there is no usable profile
+#[derive(Debug, Clone)]
+pub struct SetupState {
+    pub address: String,
+    pub namespace: String,
+    pub api_key: String,
+    pub focused: SetupField,
+    pub error: Option<String>,
+    submitted: bool,
+}
+
+impl SetupState {
+    pub fn new() -> Self {
+        Self {
+            address: "localhost:7233".to_string(),
+            namespace: "default".to_string(),
+            api_key: String::new(),
+            focused: SetupField::Address,
+            error: None,
+            submitted: false,
+        }
+    }
+
+    pub fn value(&self, field: SetupField) -> &str {
+        match field {
+            SetupField::Address => &self.address,
+            SetupField::Namespace => &self.namespace,
+            SetupField::ApiKey => &self.api_key,
+        }
+    }
+
+    fn focused_value_mut(&mut self) -> &mut String {
+        match self.focused {
+            SetupField::Address => &mut self.address,
+            SetupField::Namespace => &mut self.namespace,
+            SetupField::ApiKey => &mut self.api_key,
+        }
+    }
 
-    pub fn select_previous(&mut self) {
-        if self.items.is_empty() {
-            return;
+    pub fn focus_next(&mut self) {
+        let i = SetupField::ALL.iter().position(|f| *f == self.focused).unwrap_or(0);
+        self.focused = SetupField::ALL[(i + 1) % SetupField::ALL.len()];
+    }
+
+    pub fn focus_previous(&mut self) {
+        let i = SetupField::ALL.iter().position(|f| *f == self.focused).unwrap_or(0);
+        self.focused = SetupField::ALL[(i + SetupField::ALL.len() - 1) % SetupField::ALL.len()];
+    }
+
+    /// Build a profile from the form, validating the required fields
+    fn to_profile(&self) -> std::result::Result<ConnectionProfile, String> {
+        let address = self.address.trim();
+        if address.is_empty() {
+            return Err("Address is required (e.g. localhost:7233)".to_string());
         }
-        let i = match self.table_state.selected() {
-            Some(i) => {
-                if i == 0 {
-                    self.items.len() - 1
-                } else {
-                    i - 1
-                }
-            }
-            None => 0,
+        let namespace = match self.namespace.trim() {
+            "" => "default",
+            namespace => namespace,
         };
-        self.table_state.select(Some(i));
-    }
+        let api_key = Some(self.api_key.trim().to_string()).filter(|k| !k.is_empty());
 
-    pub fn selected_namespace(&self) -> Option<&DescribeNamespaceResponse> {
-        self.table_state
-            .selected()
-            .and_then(|i| self.items.get(i))
+        Ok(ConnectionProfile {
+            name: "default".to_string(),
+            address: address.to_string(),
+            namespace: namespace.to_string(),
+            // API keys are only accepted over TLS (Temporal Cloud)
+            tls: api_key.as_ref().map(|_| TlsConfig {
+                cert_path: None,
+                key_path: None,
+                ca_path: None,
+                enabled: true,
+                server_name: None,
+                insecure_skip_verify: false,
+            }),
+            api_key,
+            columns: Vec::new(),
+            short_workflow_ids: false,
+            headers: HashMap::new(),
+            default_screen: None,
+        })
     }
 }
 
-/// State for the help screen
-#[derive(Debug, Clone)]
-pub struct HelpState {
-    pub scroll_offset: u16,
+/// Small LRU cache of recently loaded workflow details, keyed by (workflow_id, run_id)
+#[derive(Debug, Default)]
+pub struct DetailCache {
+    entries: Vec<DetailCacheEntry>, // Most recently used first
 }
 
-impl HelpState {
-    pub fn new() -> Self {
-        Self {
-            scroll_offset: 0,
-        }
+#[derive(Debug)]
+struct DetailCacheEntry {
+    key: (String, String),
+    loaded_at: Instant,
+    workflow: WorkflowExecutionInfo,
+    history: Vec<HistoryEvent>,
+    truncated: bool,
+}
+
+impl DetailCache {
+    /// Fresh cached detail for a workflow run, marking it as recently used
+    pub fn get(&mut self, key: &(String, String)) -> Option<(&WorkflowExecutionInfo, &[HistoryEvent], bool)> {
+        let index = self
+            .entries
+            .iter()
+            .position(|e| &e.key == key && e.loaded_at.elapsed() < DETAIL_CACHE_TTL)?;
+        let entry = self.entries.remove(index);
+        self.entries.insert(0, entry);
+        let entry = &self.entries[0];
+        Some((&entry.workflow, entry.history.as_slice(), entry.truncated))
     }
 
-    pub fn scroll_down(&mut self, amount: u16) {
-        self.scroll_offset = self.scroll_offset.saturating_add(amount);
+    pub fn insert(
+        &mut self,
+        key: (String, String),
+        workflow: WorkflowExecutionInfo,
+        history: Vec<HistoryEvent>,
+        truncated: bool,
+    ) {
+        self.entries.retain(|e| e.key != key);
+        self.entries.insert(0, DetailCacheEntry {
+            key,
+            loaded_at: Instant::now(),
+            workflow,
+            history,
+            truncated,
+        });
+        self.entries.truncate(DETAIL_CACHE_CAPACITY);
     }
 
-    pub fn scroll_up(&mut self, amount: u16) {
-        self.scroll_offset = self.scroll_offset.saturating_sub(amount);
+    /// Drop every cached run of a workflow, e.g. after an operation changed it
+    pub fn invalidate(&mut self, workflow_id: &str) {
+        self.entries.retain(|e| e.key.0 != workflow_id);
     }
+}
+
+/// State for the namespace quick switcher overlay
+#[derive(Debug, Clone, Default)]
+pub struct NamespaceSwitcherState {
+    pub input: String,
+    pub selected: usize,
+}
 
-    pub fn reset_scroll(&mut self) {
-        self.scroll_offset = 0;
+impl NamespaceSwitcherState {
+    /// The namespace filter, accepting an optional `ns ` command prefix
+    pub fn query(&self) -> &str {
+        let input = self.input.trim_start();
+        input.strip_prefix("ns ").unwrap_or(input).trim()
     }
 }
 
+/// Config values the task handler reads for each command, so a config reload reaches it
+struct HandlerSettings {
+    read_only: AtomicBool,
+    max_history_events: AtomicUsize,
+}
+
 pub struct App {
     pub config: Config,
     pub running: bool,
     pub current_screen: Screen,
+    previous_screen: Screen, // Screen shown on the previous loop iteration, to notice returns to Workflows
     pub event_handler: EventHandler,
     pub client: Option<TemporalClient>,
     pub workflow_list_state: WorkflowListState,
     pub workflow_detail_state: WorkflowDetailState,
     pub namespace_list_state: NamespaceListState,
+    pub batch_list_state: BatchListState,
+    pub nexus_list_state: NexusListState,
+    pub aggregate_state: AggregateState,
+    pending_detail: Option<(String, String)>, // Workflow to open once a pending namespace switch lands
+    pub switching_namespace: Option<String>, // Target of an in-flight switch, shown in an overlay
     pub help_state: HelpState,
     pub connection_status: ConnectionStatus,
     pub current_namespace: String,
+    pub compact: bool,
+    edit_config_requested: bool, // Handled in the run loop, which owns the terminal
+    pub footer_notice: Option<String>, // Replaces the footer hints until the next key, e.g. a failed config reload
+    pub read_only: bool,
+    cli_read_only: bool, // `--read-only` holds across config reloads
+    handler_settings: Arc<HandlerSettings>,
+    pub short_workflow_ids: bool,
+    pub seen_workflow_types: BTreeSet<String>, // Every workflow type in a list loaded this session, for search suggestions
+    pub server_info: Option<GetSystemInfoResponse>,
+    pub show_connection_info: bool,
+    pub namespace_fallback: Option<String>, // Offered in a prompt after the current namespace became unusable
+    pub pending_operations: usize, // Mutating commands sent whose outcome hasn't arrived
+    pub confirm_quit: bool, // Quit was requested while operations were in flight
+    pub namespace_switcher: Option<NamespaceSwitcherState>,
+    detail_cache: DetailCache,
+    pub setup_state: SetupState,
+    pub show_error_details: bool,
+    pub last_load_latency: Option<Duration>, // Round trip of the latest workflow list or history request
     pub frame_count: u16,
-    command_tx: mpsc::UnboundedSender<AppCommand>,
-    result_rx: mpsc::UnboundedReceiver<AppResult>,
+    terminal_height: u16, // Last known height, for clamping modal scroll
+    last_refresh_dispatch: Option<(Instant, String, String)>, // when, namespace, query
+    last_input: Instant, // Last keypress or paste, for pausing auto-refresh when idle
+    generation: Arc<AtomicU64>, // Bumped to abandon in-flight loads; commands and results carry the value they were issued under
+    command_tx: mpsc::UnboundedSender<(u64, AppCommand)>,
+    result_tx: mpsc::UnboundedSender<(u64, AppResult)>,
+    result_rx: mpsc::UnboundedReceiver<(u64, AppResult)>,
+    pending_command_rx: Option<mpsc::UnboundedReceiver<(u64, AppCommand)>>, // Until a client is available
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -388,9 +2764,49 @@ pub enum ConnectionStatus {
 }
 
 impl App {
-    pub async fn new() -> Result<Self> {
+    pub async fn new(cli: &Cli) -> Result<Self> {
+        let first_run = !Config::exists();
         let config = Config::load()?;
+        // Without a config file or a usable profile there is nothing to connect to yet
+        let needs_setup = first_run || config.get_active_profile().is_none();
+
+        let mut app = Self::with_config(config, cli.read_only, needs_setup);
+
+        if needs_setup {
+            tracing::info!("No usable connection profile, starting setup wizard");
+            return Ok(app);
+        }
+
+        // Connect to Temporal
+        app.connect_temporal().await?;
+
+        // Spawn async task handler
+        app.start_task_handler();
+
+        // Load initial workflow list, even behind another landing screen so `1` finds it ready
+        app.send_command(AppCommand::RefreshWorkflows(String::new()));
+        if app.current_screen == Screen::Namespaces {
+            app.namespace_list_state.loading = true;
+            app.send_command(AppCommand::RefreshNamespaces);
+        }
+
+        // Deep link straight to a workflow; the list stays loaded behind it for ESC
+        if let Some(workflow_id) = &cli.workflow {
+            tracing::info!("Opening workflow {} from the command line", workflow_id);
+            app.open_workflow_detail(workflow_id.clone(), cli.run_id.clone().unwrap_or_default());
+        }
+
+        Ok(app)
+    }
+
+    /// App state for a loaded config, not yet connected; commands queue until a client is attached
+    fn with_config(config: Config, cli_read_only: bool, needs_setup: bool) -> Self {
         let event_handler = EventHandler::new();
+        let read_only = config.read_only || cli_read_only;
+        let handler_settings = Arc::new(HandlerSettings {
+            read_only: AtomicBool::new(read_only),
+            max_history_events: AtomicUsize::new(config.max_history_events),
+        });
 
         // Create channels for async communication
         let (command_tx, command_rx) = mpsc::unbounded_channel();
@@ -402,199 +2818,674 @@ impl App {
             .map(|p| p.namespace.clone())
             .unwrap_or_else(|| "default".to_string());
 
-        let mut app = Self {
+        let compact = config.compact;
+        let landing_screen = match config.get_active_profile().and_then(|p| p.default_screen.as_deref()) {
+            Some("namespaces") => Screen::Namespaces,
+            _ => Screen::Workflows,
+        };
+        let mut workflow_detail_state = WorkflowDetailState::new();
+        workflow_detail_state.hide_workflow_tasks = config.hide_workflow_task_events;
+        let short_workflow_ids = config
+            .get_active_profile()
+            .map(|p| p.short_workflow_ids)
+            .unwrap_or(false);
+
+        Self {
             config,
             running: true,
-            current_screen: Screen::Workflows,
+            current_screen: if needs_setup { Screen::Setup } else { landing_screen },
+            previous_screen: Screen::Workflows,
             event_handler,
             client: None,
             workflow_list_state: WorkflowListState::new(),
-            workflow_detail_state: WorkflowDetailState::new(),
+            workflow_detail_state,
             namespace_list_state: NamespaceListState::new(),
+            batch_list_state: BatchListState::new(),
+            nexus_list_state: NexusListState::new(),
+            aggregate_state: AggregateState::new(),
+            pending_detail: None,
+            switching_namespace: None,
             help_state: HelpState::new(),
             connection_status: ConnectionStatus::Disconnected,
             current_namespace: initial_namespace,
+            compact,
+            edit_config_requested: false,
+            footer_notice: None,
+            read_only,
+            cli_read_only,
+            handler_settings,
+            short_workflow_ids,
+            seen_workflow_types: BTreeSet::new(),
+            server_info: None,
+            show_connection_info: false,
+            namespace_fallback: None,
+            pending_operations: 0,
+            confirm_quit: false,
+            namespace_switcher: None,
+            detail_cache: DetailCache::default(),
+            setup_state: SetupState::new(),
+            show_error_details: false,
+            last_load_latency: None,
             frame_count: 0,
+            terminal_height: 0,
+            last_refresh_dispatch: None,
+            last_input: Instant::now(),
+            generation: Arc::new(AtomicU64::new(0)),
             command_tx,
+            result_tx,
             result_rx,
+            pending_command_rx: Some(command_rx),
+        }
+    }
+
+    /// Hand the connected client to the background task handler
+    fn start_task_handler(&mut self) {
+        if let Some(client) = self.client.take() {
+            self.attach_client(client);
+        }
+    }
+
+    /// Start serving queued and future commands with `client`; a no-op once a client is attached
+    pub(crate) fn attach_client<C: TemporalApi>(&mut self, client: C) {
+        if let Some(command_rx) = self.pending_command_rx.take() {
+            self.server_info = Some(client.system_info().clone());
+            self.spawn_task_handler(
+                client,
+                command_rx,
+                self.result_tx.clone(),
+                self.generation.clone(),
+                self.handler_settings.clone(),
+            );
+        }
+    }
+
+    /// Save the profile entered in the setup wizard, then connect with it
+    async fn complete_setup(&mut self) -> Result<()> {
+        let profile = match self.setup_state.to_profile() {
+            Ok(profile) => profile,
+            Err(message) => {
+                self.setup_state.error = Some(message);
+                return Ok(());
+            }
         };
 
-        // Connect to Temporal
-        app.connect_temporal().await?;
+        let namespace = profile.namespace.clone();
+        // Save on top of the file as written so `${VAR}` references aren't replaced by their values
+        let mut stored = match Config::load_raw() {
+            Ok(stored) => stored,
+            Err(e) => {
+                self.setup_state.error = Some(format!("Failed to read config: {}", e));
+                return Ok(());
+            }
+        };
+        let first_run = !Config::exists();
+        for config in [&mut self.config, &mut stored] {
+            if first_run {
+                // Don't persist the built-in fallback profile alongside the one just entered
+                config.profiles.clear();
+            }
+            config.profiles.retain(|p| p.name != profile.name);
+            config.active_profile = Some(profile.name.clone());
+            config.profiles.push(profile.clone());
+        }
 
-        // Spawn async task handler
-        if let Some(client) = app.client.take() {
-            app.spawn_task_handler(client, command_rx, result_tx);
+        if let Err(e) = stored.save() {
+            self.setup_state.error = Some(format!("Failed to write config: {}", e));
+            return Ok(());
+        }
+
+        self.connect_temporal().await?;
+        if let ConnectionStatus::Error(message) = &self.connection_status {
+            // Keep the wizard open so the details can be corrected
+            self.setup_state.error = Some(message.clone());
+            return Ok(());
         }
 
-        // Load initial workflow list
-        app.command_tx.send(AppCommand::RefreshWorkflows(String::new()))?;
+        self.start_task_handler();
+        self.current_namespace = namespace;
+        self.current_screen = Screen::Workflows;
+        let query = self.workflow_list_state.get_query();
+        self.dispatch_workflow_refresh(query);
 
-        Ok(app)
+        Ok(())
     }
 
-    fn spawn_task_handler(
+    fn spawn_task_handler<C: TemporalApi>(
         &self,
-        mut client: TemporalClient,
-        mut command_rx: mpsc::UnboundedReceiver<AppCommand>,
-        result_tx: mpsc::UnboundedSender<AppResult>,
+        mut client: C,
+        mut command_rx: mpsc::UnboundedReceiver<(u64, AppCommand)>,
+        results: mpsc::UnboundedSender<(u64, AppResult)>,
+        current_generation: Arc<AtomicU64>,
+        settings: Arc<HandlerSettings>,
     ) {
+        let permits = Arc::new(Semaphore::new(self.config.max_concurrent_requests.max(1)));
         tokio::spawn(async move {
-            while let Some(command) = command_rx.recv().await {
-                match command {
-                    AppCommand::RefreshWorkflows(query) => {
-                        tracing::info!("Loading workflows with query: '{}'", query);
-                        match client
-                            .list_workflow_executions(50, Vec::new(), query)
-                            .await
-                        {
-                            Ok(response) => {
-                                let _ = result_tx.send(AppResult::WorkflowsLoaded {
-                                    workflows: response.executions,
-                                    next_page_token: response.next_page_token,
-                                });
-                            }
-                            Err(e) => {
-                                let _ = result_tx
-                                    .send(AppResult::WorkflowsError(format!("Failed to load workflows: {}", e)));
-                            }
-                        }
-                    }
-                    AppCommand::LoadNextPage(query, page_token) => {
-                        tracing::info!("Loading next page with query: '{}'", query);
-                        match client
-                            .list_workflow_executions(50, page_token, query)
-                            .await
-                        {
-                            Ok(response) => {
-                                let _ = result_tx.send(AppResult::WorkflowsLoaded {
-                                    workflows: response.executions,
-                                    next_page_token: response.next_page_token,
-                                });
-                            }
-                            Err(e) => {
-                                let _ = result_tx
-                                    .send(AppResult::WorkflowsError(format!("Failed to load next page: {}", e)));
-                            }
-                        }
-                    }
-                    AppCommand::LoadPreviousPage(query) => {
-                        tracing::info!("Loading previous page with query: '{}'", query);
-                        // Load from the beginning (previous page is handled on the client side)
-                        match client
-                            .list_workflow_executions(50, Vec::new(), query)
-                            .await
-                        {
-                            Ok(response) => {
-                                let _ = result_tx.send(AppResult::WorkflowsLoaded {
-                                    workflows: response.executions,
-                                    next_page_token: response.next_page_token,
-                                });
-                            }
-                            Err(e) => {
-                                let _ = result_tx
-                                    .send(AppResult::WorkflowsError(format!("Failed to load previous page: {}", e)));
+            // The latest load in each lane; it signals (by dropping its sender) once it has finished
+            let mut last_loads: HashMap<std::mem::Discriminant<AppCommand>, oneshot::Receiver<()>> = HashMap::new();
+            while let Some((generation, command)) = command_rx.recv().await {
+                // Loads queued before a cancel would only be thrown away on arrival
+                if command.is_load() && generation < current_generation.load(Ordering::Relaxed) {
+                    tracing::debug!("Skipping cancelled command: {:?}", command);
+                    continue;
+                }
+                let result_tx = ResultSender { tx: results.clone(), generation };
+                let max_history_events = settings.max_history_events.load(Ordering::Relaxed);
+
+                // Never let a mutating command reach the server in read-only mode
+                if settings.read_only.load(Ordering::Relaxed) {
+                    if let Some(operation) = command.mutation_name() {
+                        tracing::warn!("Blocked {} in read-only mode", operation);
+                        let message = AppError::message(format!("Read-only mode: {} is disabled", operation));
+                        let _ = result_tx.send(match command {
+                            AppCommand::RegisterNamespace(..) | AppCommand::UpdateNamespace(..) => {
+                                AppResult::NamespaceOperationError(message)
                             }
-                        }
+                            _ => AppResult::WorkflowOperationError(message),
+                        });
+                        continue;
                     }
-                    AppCommand::ViewWorkflowDetail(workflow_id, run_id) => {
-                        tracing::info!("Loading workflow detail: {}", workflow_id);
+                }
 
-                        // First, find the workflow in our list
-                        let workflow_info = client
-                            .list_workflow_executions(1, Vec::new(), format!("WorkflowId = '{}'", workflow_id))
-                            .await
-                            .ok()
-                            .and_then(|response| response.executions.into_iter().next());
+                if !command.is_load() {
+                    run_command(&mut client, command, result_tx, max_history_events).await;
+                    continue;
+                }
 
-                        // Get the history
-                        match client
-                            .get_workflow_execution_history(workflow_id.clone(), run_id, 100, Vec::new())
-                            .await
-                        {
-                            Ok(response) => {
-                                if let Some(history) = response.history {
-                                    let _ = result_tx.send(AppResult::WorkflowDetailLoaded {
-                                        workflow: workflow_info.unwrap_or_default(),
-                                        history: history.events,
-                                    });
-                                }
-                            }
-                            Err(e) => {
-                                let _ = result_tx.send(AppResult::WorkflowDetailError(
-                                    format!("Failed to load workflow detail: {}", e),
-                                ));
-                            }
-                        }
-                    }
-                    AppCommand::RefreshNamespaces => {
-                        tracing::info!("Loading namespaces");
-                        match client.list_namespaces(50, Vec::new()).await {
-                            Ok(response) => {
-                                let _ = result_tx.send(AppResult::NamespacesLoaded {
-                                    namespaces: response.namespaces,
-                                });
-                            }
-                            Err(e) => {
-                                let _ = result_tx.send(AppResult::NamespacesError(
-                                    format!("Failed to load namespaces: {}", e),
-                                ));
-                            }
-                        }
-                    }
-                    AppCommand::SwitchNamespace(namespace) => {
-                        tracing::info!("Switching to namespace: {}", namespace);
-                        client.set_namespace(namespace.clone());
-                        let _ = result_tx.send(AppResult::NamespaceSwitched { namespace });
-                    }
-                    AppCommand::TerminateWorkflow(workflow_id, run_id, reason) => {
-                        tracing::info!("Terminating workflow: {} with reason: {}", workflow_id, reason);
-                        match client.terminate_workflow(workflow_id.clone(), run_id, reason).await {
-                            Ok(_) => {
-                                let _ = result_tx.send(AppResult::WorkflowOperationSuccess(
-                                    format!("Workflow {} terminated successfully", workflow_id),
-                                ));
-                            }
-                            Err(e) => {
-                                let _ = result_tx.send(AppResult::WorkflowOperationError(
-                                    format!("Failed to terminate workflow: {}", e),
-                                ));
-                            }
-                        }
-                    }
-                    AppCommand::CancelWorkflow(workflow_id, run_id) => {
-                        tracing::info!("Canceling workflow: {}", workflow_id);
-                        match client.cancel_workflow(workflow_id.clone(), run_id).await {
-                            Ok(_) => {
-                                let _ = result_tx.send(AppResult::WorkflowOperationSuccess(
-                                    format!("Workflow {} cancel requested successfully", workflow_id),
-                                ));
-                            }
-                            Err(e) => {
-                                let _ = result_tx.send(AppResult::WorkflowOperationError(
-                                    format!("Failed to cancel workflow: {}", e),
-                                ));
-                            }
-                        }
+                // Loads in different lanes overlap up to the limit, each on its own client clone.
+                // Loads in one lane wait for the previous one, so an older page or list can never
+                // land after a newer one. All waiting happens in the load's task, never in this loop.
+                let (done, finished) = oneshot::channel::<()>();
+                let previous = last_loads.insert(command.lane(), finished);
+                let permits = permits.clone();
+                let current_generation = current_generation.clone();
+                let mut load_client = client.clone();
+                tokio::spawn(async move {
+                    if let Some(previous) = previous {
+                        let _ = previous.await;
                     }
-                    AppCommand::SignalWorkflow(workflow_id, run_id, signal_name) => {
-                        tracing::info!("Signaling workflow: {} with signal: {}", workflow_id, signal_name);
-                        match client.signal_workflow(workflow_id.clone(), run_id, signal_name.clone()).await {
-                            Ok(_) => {
-                                let _ = result_tx.send(AppResult::WorkflowOperationSuccess(
-                                    format!("Signal '{}' sent to workflow {} successfully", signal_name, workflow_id),
-                                ));
-                            }
-                            Err(e) => {
-                                let _ = result_tx.send(AppResult::WorkflowOperationError(
-                                    format!("Failed to signal workflow: {}", e),
-                                ));
-                            }
-                        }
+                    let _permit = permits.acquire_owned().await.expect("the semaphore is never closed");
+                    if generation < current_generation.load(Ordering::Relaxed) {
+                        tracing::debug!("Skipping command cancelled while queued: {:?}", command);
+                        return;
                     }
+                    run_command(&mut load_client, command, result_tx, max_history_events).await;
+                    drop(done);
+                });
+            }
+        });
+    }
+
+    /// Show a workflow's detail, served from the cache when it was viewed recently.
+    /// History is always re-fetched in the background so the view catches up.
+    fn open_workflow_detail(&mut self, workflow_id: String, run_id: String) {
+        let state = &mut self.workflow_detail_state;
+        state.stop_following();
+        state.clear_search();
+        state.error = None;
+        state.reloading = false;
+        state.run_diff = None;
+        state.show_metadata = false;
+        state.task_queue_backlog = None;
+        state.pending_workflow_task = None;
+        state.workflow_id = Some(workflow_id.clone());
+        state.run_id = run_id.clone();
+
+        let key = (workflow_id.clone(), run_id.clone());
+        match self.detail_cache.get(&key) {
+            Some((workflow, history, truncated)) => {
+                tracing::debug!("Showing cached detail for {}", workflow_id);
+                state.workflow = Some(workflow.clone());
+                state.set_history(history.to_vec());
+                state.history_truncated = truncated;
+                state.loading = false;
+                if !state.history.is_empty() && state.table_state.selected().is_none() {
+                    state.table_state.select(Some(0));
+                }
+            }
+            None => {
+                state.loading = true;
+            }
+        }
+
+        self.send_command(AppCommand::ViewWorkflowDetail(workflow_id, run_id));
+        self.current_screen = Screen::WorkflowDetail;
+    }
+
+    /// Re-fetch the shown workflow in place, keeping the selection, search and grouping
+    fn reload_workflow_detail(&mut self) {
+        let state = &mut self.workflow_detail_state;
+        if state.loading || state.reloading {
+            return;
+        }
+        let Some(workflow_id) = state.workflow_id.clone() else {
+            return;
+        };
+        let run_id = state.run_id.clone();
+        state.reloading = true;
+        state.notice = None;
+        self.send_command(AppCommand::ViewWorkflowDetail(workflow_id, run_id));
+    }
+
+    /// Compare the shown run's input with its neighbour in the continue-as-new chain:
+    /// the run it continued as, or else the run it continued from
+    fn open_run_diff(&mut self) {
+        let state = &mut self.workflow_detail_state;
+        let (Some(workflow_id), Some(run_id)) = (
+            state.workflow_id.clone(),
+            state.workflow.as_ref().and_then(|w| w.execution.as_ref()).map(|e| e.run_id.clone()),
+        ) else {
+            return;
+        };
+        let next_run_id = state.continued_as_run_id().unwrap_or_default().to_string();
+        let previous_run_id = state
+            .started_attributes()
+            .map(|attrs| attrs.continued_execution_run_id.clone())
+            .unwrap_or_default();
+        let (left_run_id, right_run_id) = if !next_run_id.is_empty() {
+            (run_id, next_run_id)
+        } else if !previous_run_id.is_empty() {
+            (previous_run_id, run_id)
+        } else {
+            state.notice = Some("This run is not part of a continue-as-new chain".to_string());
+            return;
+        };
+
+        state.run_diff = Some(RunDiff {
+            workflow_id,
+            left_run_id,
+            right_run_id,
+            ..Default::default()
+        });
+        self.load_run_diff();
+    }
+
+    /// Open the run the shown one continued as, keeping the same workflow ID
+    fn open_continuation(&mut self) {
+        let state = &mut self.workflow_detail_state;
+        let Some(workflow_id) = state.workflow_id.clone() else {
+            return;
+        };
+        let Some(run_id) = state.continued_as_run_id().map(str::to_string) else {
+            state.notice = Some("This run did not continue as new".to_string());
+            return;
+        };
+        tracing::info!("Following continue-as-new of {} to run {}", workflow_id, run_id);
+        self.open_workflow_detail(workflow_id, run_id);
+    }
+
+    /// Fetch whichever of the compared runs haven't been loaded yet
+    fn load_run_diff(&mut self) {
+        let Some(diff) = &self.workflow_detail_state.run_diff else {
+            return;
+        };
+        let missing: Vec<String> = [&diff.left_run_id, &diff.right_run_id]
+            .into_iter()
+            .filter(|run_id| !diff.runs.contains_key(*run_id))
+            .cloned()
+            .collect();
+        let workflow_id = diff.workflow_id.clone();
+        for run_id in missing {
+            self.send_command(AppCommand::LoadRunInput(workflow_id.clone(), run_id));
+        }
+    }
+
+    /// Move the compared pair one run along the chain, newer when `forward`
+    fn step_run_diff(&mut self, forward: bool) {
+        let Some(diff) = &mut self.workflow_detail_state.run_diff else {
+            return;
+        };
+        diff.notice = None;
+        if forward {
+            let Some(right) = diff.runs.get(&diff.right_run_id) else {
+                return;
+            };
+            if right.next_run_id.is_empty() {
+                diff.notice = Some("Newest run in the chain".to_string());
+                return;
+            }
+            let next_run_id = right.next_run_id.clone();
+            diff.left_run_id = std::mem::replace(&mut diff.right_run_id, next_run_id);
+        } else {
+            let Some(left) = diff.runs.get(&diff.left_run_id) else {
+                return;
+            };
+            if left.previous_run_id.is_empty() {
+                diff.notice = Some("First run in the chain".to_string());
+                return;
+            }
+            let previous_run_id = left.previous_run_id.clone();
+            diff.right_run_id = std::mem::replace(&mut diff.left_run_id, previous_run_id);
+        }
+        diff.scroll = 0;
+        self.load_run_diff();
+    }
+
+    /// Seen workflow types matching the one being typed in the search bar; prefix matches first
+    pub fn workflow_type_suggestions(&self) -> Vec<&str> {
+        let state = &self.workflow_list_state;
+        let Some(partial) = state.typed_workflow_type().filter(|_| state.input_mode) else {
+            return Vec::new();
+        };
+        let partial = partial.to_lowercase();
+        let mut matches: Vec<&str> = self
+            .seen_workflow_types
+            .iter()
+            .map(String::as_str)
+            .filter(|name| name.to_lowercase().contains(&partial) && name.to_lowercase() != partial)
+            .collect();
+        matches.sort_by_key(|name| !name.to_lowercase().starts_with(&partial));
+        matches.truncate(MAX_TYPE_SUGGESTIONS);
+        matches
+    }
+
+    /// Make the highlighted namespace the current one
+    fn switch_to_selected_namespace(&mut self) {
+        if let Some(ns_info) = self.namespace_list_state.selected_namespace().and_then(|ns| ns.namespace_info.as_ref()) {
+            let namespace_name = ns_info.name.clone();
+            self.switch_namespace(namespace_name);
+        }
+    }
+
+    /// Open the details modal for the highlighted namespace
+    fn view_selected_namespace(&mut self) {
+        if self.namespace_list_state.selected_namespace().is_some() {
+            self.namespace_list_state.show_detail = true;
+        }
+    }
+
+    /// Ask the handler to move to another namespace; the overlay shows until it answers
+    fn switch_namespace(&mut self, namespace: String) {
+        tracing::info!("Switching to namespace: {}", namespace);
+        self.switching_namespace = Some(namespace.clone());
+        self.send_command(AppCommand::SwitchNamespace(namespace));
+    }
+
+    /// Show the configured namespaces' workflows in one table
+    fn open_aggregate_view(&mut self) {
+        if self.config.aggregate_namespaces.is_empty() {
+            self.workflow_list_state.copy_notice =
+                Some("Set aggregate_namespaces in the config to use the all-namespaces view".to_string());
+            return;
+        }
+        self.current_screen = Screen::AllNamespaces;
+        self.refresh_aggregate();
+    }
+
+    /// Run the workflow list's current query against every aggregate namespace
+    fn refresh_aggregate(&mut self) {
+        let query = self.workflow_list_state.get_query();
+        self.aggregate_state.loading = true;
+        self.aggregate_state.query = query.clone();
+        self.send_command(AppCommand::RefreshAggregate(self.config.aggregate_namespaces.clone(), query));
+    }
+
+    /// Open the selected aggregate row, switching to its namespace first when needed
+    fn open_aggregate_selection(&mut self) {
+        let Some((namespace, workflow)) = self
+            .aggregate_state
+            .table_state
+            .selected()
+            .and_then(|i| self.aggregate_state.rows.get(i))
+        else {
+            return;
+        };
+        let Some(execution) = &workflow.execution else {
+            return;
+        };
+        let (workflow_id, run_id) = (execution.workflow_id.clone(), execution.run_id.clone());
+        if *namespace == self.current_namespace {
+            self.open_workflow_detail(workflow_id, run_id);
+        } else {
+            tracing::info!("Switching to namespace {} to view workflow {}", namespace, workflow_id);
+            let namespace = namespace.clone();
+            self.pending_detail = Some((workflow_id, run_id));
+            self.switch_namespace(namespace);
+        }
+    }
+
+    /// Re-issue the load behind the current screen, e.g. after a reconnect
+    fn restore_view(&mut self) {
+        match self.current_screen {
+            Screen::Workflows => {
+                let query = self.workflow_list_state.get_query();
+                self.dispatch_workflow_refresh(query);
+            }
+            Screen::WorkflowDetail => {
+                if let Some(workflow_id) = self.workflow_detail_state.workflow_id.clone() {
+                    let run_id = self.workflow_detail_state.run_id.clone();
+                    self.open_workflow_detail(workflow_id, run_id);
+                }
+            }
+            Screen::Namespaces => {
+                self.namespace_list_state.loading = true;
+                self.send_command(AppCommand::RefreshNamespaces);
+            }
+            Screen::Batches => {
+                self.batch_list_state.loading = true;
+                self.send_command(AppCommand::RefreshBatchOperations);
+            }
+            Screen::Nexus => {
+                self.nexus_list_state.loading = true;
+                self.send_command(AppCommand::RefreshNexusEndpoints);
+            }
+            Screen::AllNamespaces => self.refresh_aggregate(),
+            Screen::Help | Screen::Setup => {}
+        }
+    }
+
+    /// A namespace to recover to when the current one is unusable: the profile's own, else `default`
+    fn fallback_namespace(&self) -> Option<String> {
+        let configured = self.config.get_active_profile().map(|p| p.namespace.clone());
+        [configured, Some("default".to_string())]
+            .into_iter()
+            .flatten()
+            .find(|namespace| *namespace != self.current_namespace)
+    }
+
+    /// Start or stop streaming new events of the shown workflow
+    fn toggle_follow(&mut self) {
+        let state = &mut self.workflow_detail_state;
+        if state.following.is_some() {
+            state.stop_following();
+            return;
+        }
+        // Following would page in everything past the cap
+        if state.history_truncated {
+            state.notice = Some("History is truncated; follow is unavailable (raise max_history_events)".to_string());
+            return;
+        }
+        let Some(workflow_id) = state.workflow_id.clone() else {
+            return;
+        };
+        // Poll the run on screen, which may be newer than the requested "latest"
+        let run_id = state
+            .workflow
+            .as_ref()
+            .and_then(|w| w.execution.as_ref())
+            .map(|e| e.run_id.clone())
+            .filter(|run_id| !run_id.is_empty())
+            .unwrap_or_else(|| state.run_id.clone());
+        let last_event_id = state.history.last().map(|e| e.event_id).unwrap_or(0);
+        let stop = Arc::new(AtomicBool::new(false));
+        state.following = Some(stop.clone());
+        self.send_command(AppCommand::FollowWorkflow(workflow_id, run_id, last_event_id, stop));
+    }
+
+    /// Queue a command for the task handler, tagged with the current generation
+    fn send_command(&mut self, command: AppCommand) {
+        if command.mutation_name().is_some() {
+            self.pending_operations += 1;
+        }
+        let _ = self.command_tx.send((self.generation.load(Ordering::Relaxed), command));
+    }
+
+    /// Quit, unless an operation's outcome is still unknown; then ask first
+    fn request_quit(&mut self) {
+        if self.pending_operations > 0 {
+            self.confirm_quit = true;
+        } else {
+            self.running = false;
+        }
+    }
+
+    /// Whether the visible screen is waiting on a load that ESC can cancel
+    fn current_screen_loading(&self) -> bool {
+        match self.current_screen {
+            Screen::Workflows => self.workflow_list_state.loading && !self.workflow_list_state.input_mode,
+            Screen::WorkflowDetail => self.workflow_detail_state.loading,
+            Screen::Namespaces => self.namespace_list_state.loading,
+            Screen::Batches => self.batch_list_state.loading,
+            Screen::Nexus => self.nexus_list_state.loading,
+            Screen::AllNamespaces => self.aggregate_state.loading,
+            _ => false,
+        }
+    }
+
+    /// Abandon in-flight loads: their results are ignored and queued ones are skipped
+    fn cancel_pending(&mut self) {
+        let generation = self.generation.fetch_add(1, Ordering::Relaxed) + 1;
+        tracing::info!("Cancelled pending requests (generation {})", generation);
+        self.workflow_list_state.loading = false;
+        self.workflow_detail_state.loading = false;
+        self.workflow_detail_state.reloading = false;
+        self.namespace_list_state.loading = false;
+        self.batch_list_state.loading = false;
+        self.nexus_list_state.loading = false;
+        self.aggregate_state.loading = false;
+        // Let the next refresh through even if it repeats the cancelled one
+        self.last_refresh_dispatch = None;
+    }
+
+    fn open_namespace_dialog(&mut self, dialog: NamespaceDialog) {
+        if self.read_only {
+            self.namespace_list_state.message = Some("Read-only mode: namespace changes are disabled".to_string());
+            return;
+        }
+        self.namespace_list_state.dialog = Some(dialog);
+    }
+
+    fn handle_namespace_dialog_key(&mut self, key: KeyCode) {
+        let Some(dialog) = &mut self.namespace_list_state.dialog else {
+            return;
+        };
+
+        match key {
+            KeyCode::Esc => {
+                self.namespace_list_state.dialog = None;
+            }
+            KeyCode::Tab | KeyCode::Down => {
+                dialog.focused = (dialog.focused + 1) % dialog.fields().len();
+            }
+            KeyCode::BackTab | KeyCode::Up => {
+                dialog.focused = (dialog.focused + dialog.fields().len() - 1) % dialog.fields().len();
+            }
+            KeyCode::Enter => match dialog.to_command() {
+                Ok(command) => {
+                    self.namespace_list_state.dialog = None;
+                    self.send_command(command);
+                }
+                Err(message) => {
+                    // Keep the dialog open so the input can be fixed
+                    self.namespace_list_state.message = Some(message);
                 }
+            },
+            KeyCode::Backspace => {
+                dialog.focused_value_mut().pop();
             }
-        });
+            KeyCode::Char(c) => {
+                dialog.focused_value_mut().push(c);
+            }
+            _ => {}
+        }
+    }
+
+    /// Look up when the selected running workflow last had an event, once per run and refresh
+    fn check_selected_progress(&mut self) {
+        if self.config.stall_minutes == 0 {
+            return;
+        }
+        let state = &mut self.workflow_list_state;
+        let Some(workflow) = state.selected_workflow() else {
+            return;
+        };
+        if workflow.status != WorkflowExecutionStatus::Running as i32 {
+            return;
+        }
+        let Some(execution) = workflow.execution.clone() else {
+            return;
+        };
+        if state.last_event_times.contains_key(&execution.run_id) {
+            return;
+        }
+        state.last_event_times.insert(execution.run_id.clone(), None);
+        self.send_command(AppCommand::CheckWorkflowProgress(execution.workflow_id, execution.run_id));
+    }
+
+    /// Send a configured quick signal to the shown run and close the signal dialog
+    fn send_quick_signal(&mut self, signal: QuickSignal) {
+        let execution = self
+            .workflow_detail_state
+            .workflow
+            .as_ref()
+            .and_then(|workflow| workflow.execution.as_ref());
+        if let Some(execution) = execution {
+            let workflow_id = execution.workflow_id.clone();
+            let run_id = execution.run_id.clone();
+            self.detail_cache.invalidate(&workflow_id);
+            let args = signal.payload_json();
+            self.send_command(AppCommand::SignalWorkflow(workflow_id, run_id, signal.name, args));
+        }
+        self.workflow_detail_state.close_dialog();
+    }
+
+    /// Open an operation dialog, or explain why not when running read-only
+    fn open_operation_dialog(&mut self, operation: WorkflowOperation) {
+        if self.read_only {
+            self.workflow_detail_state.notice = Some(format!(
+                "Read-only mode: {} is disabled",
+                operation.name()
+            ));
+            return;
+        }
+        let prefill = match (&operation, &self.config.terminate_reason_template) {
+            (WorkflowOperation::Terminate, Some(template)) => expand_reason_template(template),
+            _ => String::new(),
+        };
+        self.workflow_detail_state.open_dialog(operation);
+        self.workflow_detail_state.dialog_input = prefill;
+    }
+
+    /// Send a workflow list refresh, dropping it if the same namespace and query
+    /// were already dispatched within `MIN_REFRESH_INTERVAL` (held keys, fast auto-refresh)
+    fn dispatch_workflow_refresh(&mut self, query: String) {
+        if let Some((at, namespace, last_query)) = &self.last_refresh_dispatch {
+            if at.elapsed() < MIN_REFRESH_INTERVAL
+                && *namespace == self.current_namespace
+                && *last_query == query
+            {
+                tracing::debug!("Dropping refresh issued within {:?} of the previous one", MIN_REFRESH_INTERVAL);
+                return;
+            }
+        }
+
+        // Counts for a different query would be misleading until the new ones arrive
+        if self.last_refresh_dispatch.as_ref().is_some_and(|(_, _, last_query)| *last_query != query) {
+            self.workflow_list_state.total_count = None;
+            self.workflow_list_state.status_counts = None;
+        }
+
+        self.last_refresh_dispatch = Some((Instant::now(), self.current_namespace.clone(), query.clone()));
+        self.workflow_list_state.loading = true;
+        self.workflow_list_state.paging_from = None;
+        if self.workflow_list_state.archived {
+            // Archival visibility has no count API
+            self.send_command(AppCommand::ListArchivedWorkflows(query, Vec::new()));
+            return;
+        }
+        if self.workflow_list_state.show_status_chart {
+            self.send_command(AppCommand::CountWorkflowsByStatus(query.clone()));
+        }
+        self.send_command(AppCommand::RefreshWorkflows(query));
     }
 
     async fn connect_temporal(&mut self) -> Result<()> {
@@ -625,36 +3516,150 @@ impl App {
 
     fn process_results(&mut self) {
         // Process all available results from async tasks
-        while let Ok(result) = self.result_rx.try_recv() {
+        while let Ok((generation, result)) = self.result_rx.try_recv() {
+            if result.is_load() && generation < self.generation.load(Ordering::Relaxed) {
+                tracing::debug!("Ignoring result of a cancelled request");
+                continue;
+            }
+
+            // With nothing left in flight, quitting no longer needs confirming; the outcome shows instead
+            if result.is_operation_outcome() {
+                self.pending_operations = self.pending_operations.saturating_sub(1);
+                if self.pending_operations == 0 {
+                    self.confirm_quit = false;
+                }
+            }
+
             match result {
                 AppResult::WorkflowsLoaded {
                     workflows,
                     next_page_token,
+                    latency,
                 } => {
+                    self.last_load_latency = Some(latency);
+                    self.seen_workflow_types.extend(
+                        workflows
+                            .iter()
+                            .filter_map(|w| w.r#type.as_ref())
+                            .filter(|t| !t.name.is_empty())
+                            .map(|t| t.name.clone()),
+                    );
                     self.workflow_list_state.items = workflows;
                     self.workflow_list_state.next_page_token = next_page_token;
+                    self.workflow_list_state.paging_from = None;
                     self.workflow_list_state.loading = false;
                     self.workflow_list_state.error = None;
                     self.workflow_list_state.mark_refreshed();
+                    self.workflow_list_state.track_new_workflows(&self.current_namespace);
 
                     // Select first item if list is not empty
                     if !self.workflow_list_state.items.is_empty() {
                         self.workflow_list_state.table_state.select(Some(0));
                     }
 
+                    // Progress is rechecked after every refresh
+                    self.workflow_list_state.last_event_times.clear();
+                    self.check_selected_progress();
+
                     tracing::info!("Loaded {} workflows (page {})",
                                    self.workflow_list_state.items.len(),
                                    self.workflow_list_state.current_page);
                 }
+                AppResult::WorkflowCountLoaded { query, count } => {
+                    // Ignore counts for a query the user has since changed
+                    if query == self.workflow_list_state.get_query() {
+                        self.workflow_list_state.total_count = Some(count);
+                    }
+                }
+                AppResult::WorkflowLastEventLoaded { run_id, time } => {
+                    self.workflow_list_state.last_event_times.insert(run_id, Some(time));
+                }
+                AppResult::WorkflowStatusCountsLoaded { query, counts } => {
+                    if query == self.workflow_list_state.get_query() {
+                        self.workflow_list_state.status_counts = Some(counts);
+                    }
+                }
                 AppResult::WorkflowsError(error) => {
+                    // Deep in pagination, keep the current page on screen; the error panel is for initial loads
+                    if let Some(page) = self.workflow_list_state.restore_page() {
+                        tracing::error!("{}", error);
+                        self.footer_notice = Some(format!("{} (still on page {})", error.summary(), page));
+                        continue;
+                    }
                     self.workflow_list_state.error = Some(error.clone());
                     self.workflow_list_state.loading = false;
                     tracing::error!("{}", error);
                 }
-                AppResult::WorkflowDetailLoaded { workflow, history } => {
+                AppResult::ConnectionLost => {
+                    // One attempt at a time; further failures while it runs are the same outage
+                    if !matches!(self.connection_status, ConnectionStatus::Connecting) {
+                        if let Some(profile) = self.config.get_active_profile().cloned() {
+                            tracing::warn!("Connection lost, reconnecting");
+                            self.connection_status = ConnectionStatus::Connecting;
+                            let namespace = self.current_namespace.clone();
+                            self.send_command(AppCommand::Reconnect(Box::new(profile), namespace));
+                        }
+                    }
+                }
+                AppResult::Reconnected { server_info } => {
+                    tracing::info!("Reconnected to Temporal");
+                    self.connection_status = ConnectionStatus::Connected;
+                    self.server_info = Some(server_info);
+                    // The profile may now point at another cluster, with other endpoints
+                    self.nexus_list_state = NexusListState::new();
+                    self.restore_view();
+                }
+                AppResult::ReconnectFailed(error) => {
+                    tracing::error!("{}", error);
+                    self.connection_status = ConnectionStatus::Error(error.summary());
+                }
+                AppResult::NamespaceUnavailable(error) => {
+                    self.workflow_list_state.paging_from = None;
+                    self.workflow_list_state.error = Some(error.clone());
+                    self.workflow_list_state.loading = false;
+                    tracing::error!("Namespace {} unavailable: {}", self.current_namespace, error);
+                    self.namespace_fallback = self.fallback_namespace();
+                }
+                AppResult::WorkflowDetailLoaded {
+                    workflow_id,
+                    requested_run_id,
+                    run_id,
+                    workflow,
+                    history,
+                    truncated,
+                    latency,
+                    pending_workflow_task,
+                } => {
+                    self.last_load_latency = Some(latency);
+                    // Cache under the key that was asked for, and the run it turned out to be
+                    if requested_run_id != run_id {
+                        self.detail_cache.insert(
+                            (workflow_id.clone(), requested_run_id.clone()),
+                            workflow.clone(),
+                            history.clone(),
+                            truncated,
+                        );
+                    }
+                    self.detail_cache
+                        .insert((workflow_id.clone(), run_id.clone()), workflow.clone(), history.clone(), truncated);
+
+                    // A background refresh may land after the user moved on to another workflow or run
+                    let shown_run = &self.workflow_detail_state.run_id;
+                    if self.workflow_detail_state.workflow_id.as_deref() != Some(workflow_id.as_str())
+                        || (*shown_run != requested_run_id && *shown_run != run_id)
+                    {
+                        tracing::debug!("Cached detail for {} run {} without displaying it", workflow_id, run_id);
+                        continue;
+                    }
+
+                    self.workflow_detail_state.run_id = run_id;
                     self.workflow_detail_state.workflow = Some(workflow);
-                    self.workflow_detail_state.history = history;
+                    self.workflow_detail_state.pending_workflow_task = pending_workflow_task;
+                    self.workflow_detail_state.set_history(history);
+                    self.workflow_detail_state.history_truncated = truncated;
+                    self.workflow_detail_state.run_search();
                     self.workflow_detail_state.loading = false;
+                    self.workflow_detail_state.reloading = false;
                     self.workflow_detail_state.error = None;
 
                     // Select first event if list is not empty
@@ -665,19 +3670,32 @@ impl App {
                     }
 
                     tracing::info!("Loaded {} history events", self.workflow_detail_state.history.len());
+
+                    // Slow progress is either missing or overwhelmed workers; the backlog tells which
+                    if let Some(workflow) = &self.workflow_detail_state.workflow {
+                        if workflow.status == WorkflowExecutionStatus::Running as i32 && !workflow.task_queue.is_empty() {
+                            let task_queue = workflow.task_queue.clone();
+                            self.send_command(AppCommand::CheckTaskQueueBacklog(task_queue));
+                        }
+                    }
+                }
+                AppResult::TaskQueueBacklogLoaded(backlog) => {
+                    self.workflow_detail_state.task_queue_backlog = Some(backlog);
                 }
                 AppResult::WorkflowDetailError(error) => {
                     self.workflow_detail_state.error = Some(error.clone());
                     self.workflow_detail_state.loading = false;
+                    self.workflow_detail_state.reloading = false;
                     tracing::error!("{}", error);
                 }
                 AppResult::NamespacesLoaded { namespaces } => {
                     self.namespace_list_state.items = namespaces;
+                    self.namespace_list_state.pin(&self.config.pinned_namespaces);
                     self.namespace_list_state.loading = false;
                     self.namespace_list_state.error = None;
 
                     // Select first item if list is not empty
-                    if !self.namespace_list_state.items.is_empty()
+                    if !self.namespace_list_state.visible_items().is_empty()
                         && self.namespace_list_state.table_state.selected().is_none()
                     {
                         self.namespace_list_state.table_state.select(Some(0));
@@ -690,27 +3708,167 @@ impl App {
                     self.namespace_list_state.loading = false;
                     tracing::error!("{}", error);
                 }
+                AppResult::BatchOperationsLoaded { operations } => {
+                    self.batch_list_state.items = operations;
+                    self.batch_list_state.loading = false;
+                    self.batch_list_state.error = None;
+
+                    let len = self.batch_list_state.visible_items().len();
+                    let selection = match self.batch_list_state.table_state.selected() {
+                        _ if len == 0 => None,
+                        Some(i) => Some(i.min(len - 1)),
+                        None => Some(0),
+                    };
+                    self.batch_list_state.table_state.select(selection);
+
+                    tracing::info!("Loaded {} batch operations", self.batch_list_state.items.len());
+                }
+                AppResult::ArchivalUnavailable(message) => {
+                    tracing::warn!("{}", message);
+                    self.workflow_list_state.archived = false;
+                    self.footer_notice = Some(message);
+                    self.workflow_list_state.prev_page_tokens.clear();
+                    self.workflow_list_state.current_page = 1;
+                    let query = self.workflow_list_state.get_query();
+                    self.last_refresh_dispatch = None;
+                    self.dispatch_workflow_refresh(query);
+                }
+                AppResult::OrderByUnsupported => {
+                    self.workflow_list_state.order = None;
+                    self.workflow_list_state.copy_notice =
+                        Some("Server doesn't support ORDER BY; showing its default order".to_string());
+                }
+                AppResult::AggregateLoaded { rows, errors } => {
+                    let state = &mut self.aggregate_state;
+                    tracing::info!("Loaded {} workflows across namespaces ({} failed)", rows.len(), errors.len());
+                    state.rows = rows;
+                    state.errors = errors;
+                    state.loading = false;
+                    let selection = match state.table_state.selected() {
+                        _ if state.rows.is_empty() => None,
+                        Some(i) => Some(i.min(state.rows.len() - 1)),
+                        None => Some(0),
+                    };
+                    state.table_state.select(selection);
+                }
+                AppResult::NexusEndpointsLoaded { endpoints } => {
+                    self.nexus_list_state.items = endpoints;
+                    self.nexus_list_state.loading = false;
+                    self.nexus_list_state.error = None;
+                    let len = self.nexus_list_state.items.len();
+                    let selection = match self.nexus_list_state.table_state.selected() {
+                        _ if len == 0 => None,
+                        Some(i) => Some(i.min(len - 1)),
+                        None => Some(0),
+                    };
+                    self.nexus_list_state.table_state.select(selection);
+                    tracing::info!("Loaded {} Nexus endpoints", len);
+                }
+                AppResult::NexusEndpointsError(error) => {
+                    self.nexus_list_state.error = Some(error.clone());
+                    self.nexus_list_state.loading = false;
+                    tracing::error!("{}", error);
+                }
+                AppResult::BatchOperationsError(error) => {
+                    self.batch_list_state.error = Some(error.clone());
+                    self.batch_list_state.loading = false;
+                    tracing::error!("{}", error);
+                }
+                AppResult::FollowedEvents { workflow_id, events } => {
+                    let state = &mut self.workflow_detail_state;
+                    if state.following.is_some() && state.workflow_id.as_deref() == Some(workflow_id.as_str()) {
+                        tracing::debug!("Followed {} new events", events.len());
+                        state.append_history(events);
+                    }
+                }
+                AppResult::FollowEnded(reason) => {
+                    if self.workflow_detail_state.following.is_some() {
+                        tracing::info!("Follow ended: {}", reason);
+                        self.workflow_detail_state.stop_following();
+                        self.workflow_detail_state.notice = Some(format!("Stopped following: {}", reason));
+                        // Pick up the final status
+                        if let Some(workflow_id) = self.workflow_detail_state.workflow_id.clone() {
+                            let run_id = self.workflow_detail_state.run_id.clone();
+                            self.send_command(AppCommand::ViewWorkflowDetail(workflow_id, run_id));
+                        }
+                    }
+                }
                 AppResult::NamespaceSwitched { namespace } => {
+                    self.switching_namespace = None;
                     self.current_namespace = namespace.clone();
+                    // Batch operations and marks are per namespace
+                    self.batch_list_state = BatchListState::new();
+                    self.workflow_list_state.marked.clear();
+                    self.workflow_list_state.total_count = None;
+                    self.workflow_list_state.status_counts = None;
                     tracing::info!("Switched to namespace: {}", namespace);
                     // Refresh workflows after switching namespace
-                    self.workflow_list_state.loading = true;
                     let query = self.workflow_list_state.get_query();
-                    let _ = self.command_tx.send(AppCommand::RefreshWorkflows(query));
-                    // Switch back to workflows screen
-                    self.current_screen = Screen::Workflows;
+                    self.dispatch_workflow_refresh(query);
+                    // Switch back to workflows screen unless configured to stay put
+                    if self.config.switch_returns_to_workflows {
+                        self.current_screen = Screen::Workflows;
+                    }
+                    // Opened from the all-namespaces view, which needed the switch first
+                    if let Some((workflow_id, run_id)) = self.pending_detail.take() {
+                        self.open_workflow_detail(workflow_id, run_id);
+                    }
+                }
+                AppResult::NamespaceSwitchFailed(error) => {
+                    // Stay where we were; the list still shows the previous namespace
+                    tracing::error!("{}", error);
+                    self.switching_namespace = None;
+                    self.pending_detail = None;
+                    let summary = error.summary();
+                    if self.current_screen == Screen::Namespaces {
+                        self.namespace_list_state.message = Some(summary);
+                    } else {
+                        self.footer_notice = Some(summary);
+                    }
                 }
-                AppResult::WorkflowOperationSuccess(message) => {
-                    self.workflow_detail_state.success_message = Some(message.clone());
-                    self.workflow_detail_state.show_dialog = None;
-                    self.workflow_detail_state.dialog_input.clear();
+                AppResult::WorkflowOperationSuccess(mut message, cli_command) => {
                     tracing::info!("{}", message);
+                    if let Some(command) = cli_command {
+                        tracing::info!("Equivalent CLI command: {}", command);
+                        match copy_to_clipboard(&command) {
+                            Ok(()) => message.push_str("\nCopied the equivalent temporal CLI command"),
+                            Err(e) => tracing::warn!("Failed to copy CLI command: {}", e),
+                        }
+                    }
+                    self.workflow_detail_state.success_message = Some(message);
+                    self.workflow_detail_state.close_dialog();
                 }
                 AppResult::WorkflowOperationError(error) => {
                     self.workflow_detail_state.error = Some(error.clone());
-                    self.workflow_detail_state.show_dialog = None;
-                    self.workflow_detail_state.dialog_input.clear();
+                    self.workflow_detail_state.close_dialog();
+                    tracing::error!("{}", error);
+                }
+                AppResult::NamespaceOperationSuccess(message) => {
+                    tracing::info!("{}", message);
+                    self.namespace_list_state.message = Some(message);
+                    // Show the new or changed namespace
+                    self.namespace_list_state.loading = true;
+                    self.send_command(AppCommand::RefreshNamespaces);
+                }
+                AppResult::NamespaceOperationError(error) => {
+                    tracing::error!("{}", error);
+                    self.namespace_list_state.error = Some(error);
+                }
+                AppResult::RunInputLoaded(run) => {
+                    if let Some(diff) = &mut self.workflow_detail_state.run_diff {
+                        diff.runs.insert(run.run_id.clone(), run);
+                    }
+                }
+                AppResult::RunInputError(error) => {
                     tracing::error!("{}", error);
+                    if let Some(diff) = &mut self.workflow_detail_state.run_diff {
+                        diff.notice = Some(error.summary());
+                    }
+                }
+                AppResult::WorkflowUpdateCompleted(outcome) => {
+                    tracing::info!("Update '{}' completed (success: {})", outcome.update_name, outcome.success);
+                    self.workflow_detail_state.update_outcome = Some(outcome);
+                    self.workflow_detail_state.close_dialog();
                 }
             }
         }
@@ -720,48 +3878,489 @@ impl App {
     where
         <B as Backend>::Error: Send + Sync + 'static,
     {
+        self.terminal_height = terminal.size()?.height;
+
         while self.running {
             // Process any async results
             self.process_results();
 
+            self.pause_auto_refresh_if_idle();
+
+            // Coming back to a stale workflow list refreshes it
+            if self.current_screen == Screen::Workflows
+                && self.previous_screen != Screen::Workflows
+                && self.workflow_list_state.is_stale(self.config.refresh_on_focus_secs)
+            {
+                tracing::debug!("Refreshing stale workflows on return to the list");
+                let query = self.workflow_list_state.get_query();
+                self.dispatch_workflow_refresh(query);
+            }
+            self.previous_screen = self.current_screen;
+
+            // Following only lasts while its workflow is on screen
+            if self.current_screen != Screen::WorkflowDetail && self.workflow_detail_state.following.is_some() {
+                tracing::debug!("Stopped following after leaving the workflow");
+                self.workflow_detail_state.stop_following();
+            }
+
             // Check if auto-refresh is needed (only on Workflows screen)
             if matches!(self.current_screen, Screen::Workflows) && self.workflow_list_state.should_refresh() {
                 tracing::debug!("Auto-refreshing workflows");
-                self.workflow_list_state.loading = true;
                 let query = self.workflow_list_state.get_query();
-                let _ = self.command_tx.send(AppCommand::RefreshWorkflows(query));
+                self.dispatch_workflow_refresh(query);
             }
 
             terminal.draw(|f| ui::render(&self, f))?;
 
+            // Connect after drawing so the wizard shows the connecting state meanwhile
+            if self.setup_state.submitted {
+                self.setup_state.submitted = false;
+                self.complete_setup().await?;
+            }
+
+            if self.edit_config_requested {
+                self.edit_config_requested = false;
+                self.edit_config(terminal)?;
+            }
+
             // Increment frame count for animations
             self.frame_count = self.frame_count.wrapping_add(1);
 
-            if let Event::Key(key) = self.event_handler.next()? {
-                self.handle_key(key.code)?;
+            match self.event_handler.next()? {
+                Event::Key(key) => {
+                    self.note_input();
+                    self.handle_key(key.code)?;
+                    self.clamp_scroll();
+                }
+                Event::Paste(text) => {
+                    self.note_input();
+                    self.handle_paste(&text)
+                }
+                Event::Resize(width, height) => {
+                    tracing::debug!("Terminal resized to {}x{}", width, height);
+                    self.terminal_height = height;
+                    self.clamp_scroll();
+                }
+                Event::Tick => {}
             }
         }
 
         Ok(())
     }
 
+    /// Suspend the TUI, open the config file in `$VISUAL`/`$EDITOR`, then reload it and reconnect
+    fn edit_config<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<()>
+    where
+        <B as Backend>::Error: Send + Sync + 'static,
+    {
+        let path = Config::get_config_path()?;
+        if !path.exists() {
+            // Give the editor the current settings to start from
+            Config::load_raw()?.save()?;
+        }
+        let editor = std::env::var("VISUAL")
+            .or_else(|_| std::env::var("EDITOR"))
+            .unwrap_or_else(|_| "vi".to_string());
+        // Allow editors configured with arguments, e.g. "code --wait"
+        let mut parts = editor.split_whitespace();
+        let program = parts.next().unwrap_or("vi");
+        tracing::info!("Opening {:?} in {}", path, editor);
+
+        disable_raw_mode()?;
+        execute!(std::io::stdout(), LeaveAlternateScreen, DisableMouseCapture, DisableBracketedPaste)?;
+        let status = std::process::Command::new(program).args(parts).arg(&path).status();
+        enable_raw_mode()?;
+        execute!(std::io::stdout(), EnterAlternateScreen, EnableMouseCapture, EnableBracketedPaste)?;
+        terminal.clear()?;
+
+        match status {
+            Ok(status) if status.success() => self.reload_config(),
+            Ok(status) => {
+                self.footer_notice = Some(format!("{} exited with {}; config not reloaded", program, status));
+            }
+            Err(e) => {
+                self.footer_notice = Some(format!("Failed to run {}: {}", program, e));
+            }
+        }
+        Ok(())
+    }
+
+    /// Pick up the edited config file and reconnect with its active profile
+    fn reload_config(&mut self) {
+        let config = match Config::load() {
+            Ok(config) => config,
+            Err(e) => {
+                tracing::error!("Config reload failed: {:#}", e);
+                self.footer_notice = Some(format!("Config not reloaded: {:#}", e));
+                return;
+            }
+        };
+        self.compact = config.compact;
+        if self.workflow_detail_state.hide_workflow_tasks != config.hide_workflow_task_events {
+            self.workflow_detail_state.toggle_workflow_tasks();
+        }
+        let previous_ids = self.config.get_active_profile().map(|p| (p.name.clone(), p.short_workflow_ids));
+        self.read_only = config.read_only || self.cli_read_only;
+        self.handler_settings.read_only.store(self.read_only, Ordering::Relaxed);
+        self.handler_settings.max_history_events.store(config.max_history_events, Ordering::Relaxed);
+        self.config = config;
+
+        let Some(profile) = self.config.get_active_profile().cloned() else {
+            self.footer_notice = Some("Config reloaded, but it has no usable profile".to_string());
+            return;
+        };
+        // A `w` toggle lasts for the session, until another profile (or a changed default) applies
+        if previous_ids != Some((profile.name.clone(), profile.short_workflow_ids)) {
+            self.short_workflow_ids = profile.short_workflow_ids;
+        }
+        tracing::info!("Config reloaded, reconnecting with profile {}", profile.name);
+        self.connection_status = ConnectionStatus::Connecting;
+        let namespace = self.current_namespace.clone();
+        self.send_command(AppCommand::Reconnect(Box::new(profile), namespace));
+    }
+
+    /// Stop auto-refreshing once nobody has touched the keyboard for the configured time
+    fn pause_auto_refresh_if_idle(&mut self) {
+        let idle_minutes = self.config.auto_refresh_idle_minutes;
+        let state = &mut self.workflow_list_state;
+        if idle_minutes == 0 || !state.auto_refresh_enabled || state.auto_refresh_paused {
+            return;
+        }
+        if self.last_input.elapsed() >= Duration::from_secs(idle_minutes * 60) {
+            tracing::info!("No input for {} minutes, pausing auto-refresh", idle_minutes);
+            state.auto_refresh_paused = true;
+        }
+    }
+
+    /// Record user activity, resuming auto-refresh if it was paused for inactivity
+    fn note_input(&mut self) {
+        self.last_input = Instant::now();
+        if self.workflow_list_state.auto_refresh_paused {
+            tracing::info!("Input received, resuming auto-refresh");
+            self.workflow_list_state.auto_refresh_paused = false;
+        }
+    }
+
+    /// Whether the connected server reported the Nexus capability
+    pub fn supports_nexus(&self) -> bool {
+        self.server_info
+            .as_ref()
+            .and_then(|info| info.capabilities.as_ref())
+            .is_some_and(|capabilities| capabilities.nexus)
+    }
+
     pub fn spinner(&self) -> &str {
-        let frames = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+        let frames = self.config.spinner_style.frames();
         let index = (self.frame_count / 3) as usize % frames.len();
         frames[index]
     }
 
+    fn open_namespace_switcher(&mut self) {
+        self.namespace_switcher = Some(NamespaceSwitcherState::default());
+        // Namespaces are only fetched on demand, so make sure there is something to match
+        if self.namespace_list_state.items.is_empty() && !self.namespace_list_state.loading {
+            self.namespace_list_state.loading = true;
+            self.send_command(AppCommand::RefreshNamespaces);
+        }
+    }
+
+    /// Namespace names matching the quick switcher input, best match first
+    pub fn namespace_switcher_matches(&self) -> Vec<&str> {
+        let Some(switcher) = &self.namespace_switcher else {
+            return Vec::new();
+        };
+        let query = switcher.query();
+
+        let mut matches: Vec<(i32, &str)> = self
+            .namespace_list_state
+            .items
+            .iter()
+            .filter_map(|ns| ns.namespace_info.as_ref())
+            .filter_map(|info| fuzzy_score(query, &info.name).map(|score| (score, info.name.as_str())))
+            .collect();
+        matches.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(b.1)));
+        matches.into_iter().map(|(_, name)| name).collect()
+    }
+
+    fn handle_namespace_switcher_key(&mut self, key: KeyCode) {
+        let match_count = self.namespace_switcher_matches().len();
+        let Some(switcher) = &mut self.namespace_switcher else {
+            return;
+        };
+
+        match key {
+            KeyCode::Esc => {
+                self.namespace_switcher = None;
+            }
+            KeyCode::Enter => {
+                let selected = switcher.selected;
+                let typed = switcher.query().to_string();
+                // Fall back to the typed name so unlisted namespaces can still be reached
+                let target = self
+                    .namespace_switcher_matches()
+                    .get(selected)
+                    .map(|name| name.to_string())
+                    .or_else(|| (!typed.is_empty()).then_some(typed));
+                self.namespace_switcher = None;
+
+                if let Some(namespace) = target {
+                    self.switch_namespace(namespace);
+                }
+            }
+            KeyCode::Up => {
+                switcher.selected = switcher.selected.saturating_sub(1);
+            }
+            KeyCode::Down if switcher.selected + 1 < match_count => {
+                switcher.selected += 1;
+            }
+            KeyCode::Backspace => {
+                switcher.input.pop();
+                switcher.selected = 0;
+            }
+            KeyCode::Char(c) => {
+                switcher.input.push(c);
+                switcher.selected = 0;
+            }
+            _ => {}
+        }
+    }
+
+    /// Keep the help and event modal scroll offsets within their content for the current
+    /// terminal height, so scrolling stops at the last line and a resize never leaves a
+    /// modal showing blank space below it
+    fn clamp_scroll(&mut self) {
+        let content_height = ui::content_height(self, self.terminal_height);
+
+        // Help fills the content area
+        let help_visible = content_height.saturating_sub(2);
+        let help_max = ui::screens::help::line_count(self).saturating_sub(help_visible);
+        self.help_state.scroll_offset = self.help_state.scroll_offset.min(help_max);
+
+        // The event detail modal takes 80% of the content area
+        let modal_visible = (content_height * 4 / 5).saturating_sub(2);
+        let modal_max = ui::screens::workflow_detail::event_detail_line_count(self).saturating_sub(modal_visible);
+        self.workflow_detail_state.event_detail_scroll_offset =
+            self.workflow_detail_state.event_detail_scroll_offset.min(modal_max);
+    }
+
+    /// Append pasted text to whichever text input is active. Inputs are single-line,
+    /// so line breaks in multi-line queries are folded into spaces.
+    fn handle_paste(&mut self, text: &str) {
+        let text = text.lines().map(str::trim_end).collect::<Vec<_>>().join(" ");
+
+        if self.current_screen == Screen::Setup {
+            self.setup_state.focused_value_mut().push_str(text.trim());
+            return;
+        }
+
+        if let Some(dialog) = &mut self.namespace_list_state.dialog {
+            dialog.focused_value_mut().push_str(&text);
+            return;
+        }
+
+        if let Some(switcher) = &mut self.namespace_switcher {
+            switcher.input.push_str(&text);
+            switcher.selected = 0;
+            return;
+        }
+
+        match self.current_screen {
+            Screen::Workflows if self.workflow_list_state.input_mode => {
+                self.workflow_list_state.insert_at_cursor(&text);
+            }
+            Screen::WorkflowDetail => {
+                let state = &mut self.workflow_detail_state;
+                if state.show_dialog.is_some() {
+                    if state.dialog_args_focused {
+                        state.dialog_args_input.push_str(&text);
+                    } else {
+                        state.dialog_input.push_str(&text);
+                    }
+                } else if state.search_mode {
+                    state.search_query.push_str(&text);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Copy the list's namespace and query as a CLI command that reproduces the view
+    fn copy_workflow_query(&mut self) {
+        let query = self.workflow_list_state.get_query();
+        let mut command = format!("temporal workflow list --namespace {}", shell_quote(&self.current_namespace));
+        if self.workflow_list_state.archived {
+            command.push_str(" --archived");
+        }
+        if !query.is_empty() {
+            command.push_str(&format!(" --query {}", shell_quote(&query)));
+        }
+        let notice = match copy_to_clipboard(&command) {
+            Ok(()) => "Copied query to clipboard".to_string(),
+            Err(e) => {
+                tracing::warn!("Failed to copy query: {}", e);
+                format!("Copy failed: {}", e)
+            }
+        };
+        tracing::info!("Current view: {}", command);
+        self.workflow_list_state.copy_notice = Some(notice);
+    }
+
+    /// Copy the rows shown in the table, in their current order, as TSV for pasting into a spreadsheet
+    fn copy_workflow_table(&mut self) {
+        let (text, count) = ui::screens::workflows::visible_rows_tsv(self);
+        let notice = match copy_to_clipboard(&text) {
+            Ok(()) => format!("Copied {} row{} as TSV", count, if count == 1 { "" } else { "s" }),
+            Err(e) => {
+                tracing::warn!("Failed to copy table: {}", e);
+                format!("Copy failed: {}", e)
+            }
+        };
+        self.workflow_list_state.copy_notice = Some(notice);
+    }
+
+    /// Copy the selected event's details to the system clipboard via OSC 52,
+    /// which also works over SSH in terminals that support it
+    fn copy_selected_event(&mut self) {
+        let Some(event) = self.workflow_detail_state.selected_event() else {
+            return;
+        };
+        let event_id = event.event_id;
+        let text = if self.workflow_detail_state.event_detail_raw {
+            ui::screens::workflow_detail::event_hex_dump(event)
+        } else {
+            ui::screens::workflow_detail::event_detail_text(event)
+        };
+        let notice = match copy_to_clipboard(&text) {
+            Ok(()) => format!("Copied event {} to clipboard", event_id),
+            Err(e) => {
+                tracing::warn!("Failed to copy event {}: {}", event_id, e);
+                format!("Copy failed: {}", e)
+            }
+        };
+        self.workflow_detail_state.modal_notice = Some(notice);
+    }
+
+    /// Actions shared by the Workflows, Namespaces and Workflow Detail screens
+    fn handle_global_action(&mut self, action: Action) {
+        match action {
+            Action::ShowWorkflows => {
+                self.current_screen = Screen::Workflows;
+            }
+            Action::ShowNamespaces => {
+                self.current_screen = Screen::Namespaces;
+                // Load namespaces if empty
+                if self.namespace_list_state.items.is_empty() && !self.namespace_list_state.loading {
+                    self.namespace_list_state.loading = true;
+                    self.send_command(AppCommand::RefreshNamespaces);
+                }
+            }
+            Action::ShowBatches => {
+                self.current_screen = Screen::Batches;
+                if self.batch_list_state.items.is_empty() && !self.batch_list_state.loading {
+                    self.batch_list_state.loading = true;
+                    self.send_command(AppCommand::RefreshBatchOperations);
+                }
+            }
+            Action::ShowNexus => {
+                // Servers without Nexus don't serve the endpoint registry
+                if !self.supports_nexus() {
+                    self.footer_notice = Some("This server does not support Nexus".to_string());
+                    return;
+                }
+                self.current_screen = Screen::Nexus;
+                if self.nexus_list_state.items.is_empty() && !self.nexus_list_state.loading {
+                    self.nexus_list_state.loading = true;
+                    self.send_command(AppCommand::RefreshNexusEndpoints);
+                }
+            }
+            Action::Help => {
+                // Help keeps its scroll position between visits
+                self.help_state.return_to = self.current_screen;
+                self.current_screen = Screen::Help;
+            }
+            Action::ConnectionInfo => {
+                self.show_connection_info = true;
+            }
+            Action::NamespaceSwitcher => {
+                self.open_namespace_switcher();
+            }
+            Action::ErrorDetails => {
+                self.show_error_details = !self.show_error_details;
+            }
+            Action::ToggleCompact => {
+                self.compact = !self.compact;
+            }
+            Action::EditConfig => {
+                self.edit_config_requested = true;
+            }
+            _ => {}
+        }
+    }
+
     fn handle_key(&mut self, key: KeyCode) -> Result<()> {
+        self.footer_notice = None;
+
+        // Connection info modal sits above every screen - any key dismisses it
+        if self.show_connection_info {
+            self.show_connection_info = false;
+            return Ok(());
+        }
+
+        // Quitting with operations in flight - 'y'/Enter/'q' quits anyway, anything else stays
+        if self.confirm_quit {
+            self.confirm_quit = false;
+            if matches!(key, KeyCode::Char('y') | KeyCode::Enter | KeyCode::Char('q')) {
+                tracing::warn!("Quitting with {} operation(s) in flight", self.pending_operations);
+                self.running = false;
+            }
+            return Ok(());
+        }
+
+        // Offer to leave a deleted or inaccessible namespace - 'y'/Enter switches, anything else stays
+        if let Some(namespace) = self.namespace_fallback.take() {
+            if matches!(key, KeyCode::Char('y') | KeyCode::Enter) {
+                tracing::info!("Falling back to namespace: {}", namespace);
+                self.switch_namespace(namespace);
+            }
+            return Ok(());
+        }
+
+        // The namespace quick switcher captures all keys while open
+        if self.namespace_switcher.is_some() {
+            self.handle_namespace_switcher_key(key);
+            return Ok(());
+        }
+
+        // ESC while loading cancels the request instead of its usual action
+        if key == KeyCode::Esc && self.current_screen_loading() {
+            self.cancel_pending();
+            if self.current_screen == Screen::WorkflowDetail {
+                self.current_screen = Screen::Workflows;
+            }
+            return Ok(());
+        }
+
         match self.current_screen {
             Screen::Workflows => {
                 // Handle input mode separately
                 if self.workflow_list_state.input_mode {
                     match key {
                         KeyCode::Char(c) => {
-                            self.workflow_list_state.query.push(c);
+                            self.workflow_list_state.insert_at_cursor(c.encode_utf8(&mut [0; 4]));
                         }
                         KeyCode::Backspace => {
-                            self.workflow_list_state.query.pop();
+                            self.workflow_list_state.delete_before_cursor();
+                        }
+                        KeyCode::Delete => {
+                            self.workflow_list_state.delete_at_cursor();
+                        }
+                        KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End => {
+                            self.workflow_list_state.move_cursor(key);
+                        }
+                        KeyCode::Tab => {
+                            if let Some(name) = self.workflow_type_suggestions().first().map(|name| name.to_string()) {
+                                self.workflow_list_state.complete_workflow_type(&name);
+                            }
                         }
                         KeyCode::Enter => {
                             // Save to history if non-empty
@@ -770,11 +4369,10 @@ impl App {
                             }
                             // Exit input mode and refresh (reset to page 1)
                             self.workflow_list_state.input_mode = false;
-                            self.workflow_list_state.loading = true;
                             self.workflow_list_state.prev_page_tokens.clear();
                             self.workflow_list_state.current_page = 1;
                             let query = self.workflow_list_state.get_query();
-                            let _ = self.command_tx.send(AppCommand::RefreshWorkflows(query));
+                            self.dispatch_workflow_refresh(query);
                         }
                         KeyCode::Esc => {
                             // Exit input mode without searching
@@ -786,31 +4384,23 @@ impl App {
                 }
 
                 // Normal mode key handling
-                match key {
-                    KeyCode::Char('q') | KeyCode::Esc => {
-                        self.running = false;
-                    }
-                    KeyCode::Char('1') => {
-                        self.current_screen = Screen::Workflows;
-                    }
-                    KeyCode::Char('2') => {
-                        self.current_screen = Screen::Namespaces;
-                        // Load namespaces if empty
-                        if self.namespace_list_state.items.is_empty() && !self.namespace_list_state.loading {
-                            self.namespace_list_state.loading = true;
-                            let _ = self.command_tx.send(AppCommand::RefreshNamespaces);
-                        }
-                    }
-                    KeyCode::Char('?') => {
-                        self.help_state.reset_scroll();
-                        self.current_screen = Screen::Help;
+                self.workflow_list_state.copy_notice = None;
+                let action = keymap::action_for(Screen::Workflows, key);
+                // Moving around the list resumes a held auto-refresh
+                if matches!(action, Some(Action::Down | Action::Up | Action::NextPage | Action::PreviousPage)) {
+                    self.workflow_list_state.auto_refresh_held = false;
+                }
+                match action {
+                    Some(Action::Back) => {
+                        self.request_quit();
                     }
-                    KeyCode::Char('/') => {
+                    Some(Action::Search) => {
                         // Enter search mode
                         self.workflow_list_state.input_mode = true;
                         self.workflow_list_state.query.clear();
+                        self.workflow_list_state.cursor = 0;
                     }
-                    KeyCode::Char('f') => {
+                    Some(Action::CycleFilter) => {
                         // Cycle through filters
                         self.workflow_list_state.active_filter = match self.workflow_list_state.active_filter {
                             None => Some(WorkflowFilter::Running),
@@ -821,49 +4411,137 @@ impl App {
                             Some(WorkflowFilter::All) => None,
                         };
                         // Refresh with new filter (reset to page 1)
-                        self.workflow_list_state.loading = true;
                         self.workflow_list_state.prev_page_tokens.clear();
                         self.workflow_list_state.current_page = 1;
                         let query = self.workflow_list_state.get_query();
-                        let _ = self.command_tx.send(AppCommand::RefreshWorkflows(query));
+                        self.dispatch_workflow_refresh(query);
+                    }
+                    Some(Action::CycleTimeRange) => {
+                        // Cycle through start time ranges
+                        self.workflow_list_state.time_range = match self.workflow_list_state.time_range {
+                            None => Some(TimeRange::Hour),
+                            Some(TimeRange::Hour) => Some(TimeRange::Day),
+                            Some(TimeRange::Day) => Some(TimeRange::Week),
+                            Some(TimeRange::Week) => None,
+                        };
+                        self.workflow_list_state.prev_page_tokens.clear();
+                        self.workflow_list_state.current_page = 1;
+                        let query = self.workflow_list_state.get_query();
+                        self.dispatch_workflow_refresh(query);
+                    }
+                    Some(Action::CycleOrder) => {
+                        // Server-side order: default -> newest first -> oldest first
+                        self.workflow_list_state.order = match self.workflow_list_state.order {
+                            None => Some(StartTimeOrder::Descending),
+                            Some(StartTimeOrder::Descending) => Some(StartTimeOrder::Ascending),
+                            Some(StartTimeOrder::Ascending) => None,
+                        };
+                        self.workflow_list_state.prev_page_tokens.clear();
+                        self.workflow_list_state.current_page = 1;
+                        let query = self.workflow_list_state.get_query();
+                        self.dispatch_workflow_refresh(query);
                     }
-                    KeyCode::Char('c') => {
+                    Some(Action::ToggleArchived) => {
+                        // Switch between live visibility and archived runs, back on page 1
+                        let state = &mut self.workflow_list_state;
+                        state.archived = !state.archived;
+                        state.total_count = None;
+                        state.status_counts = None;
+                        state.prev_page_tokens.clear();
+                        state.current_page = 1;
+                        tracing::info!("Archived mode {}", if state.archived { "on" } else { "off" });
+                        let query = state.get_query();
+                        self.last_refresh_dispatch = None;
+                        self.dispatch_workflow_refresh(query);
+                    }
+                    Some(Action::ClearFilters) => {
                         // Clear filter and search (reset to page 1)
                         self.workflow_list_state.active_filter = None;
+                        self.workflow_list_state.time_range = None;
                         self.workflow_list_state.query.clear();
-                        self.workflow_list_state.loading = true;
                         self.workflow_list_state.prev_page_tokens.clear();
                         self.workflow_list_state.current_page = 1;
-                        let _ = self.command_tx.send(AppCommand::RefreshWorkflows(String::new()));
+                        self.dispatch_workflow_refresh(String::new());
+                    }
+                    Some(Action::RerunLastSearch) => {
+                        // Restore the latest search, keeping the status and time filters
+                        if let Some(last) = self.workflow_list_state.query_history.last() {
+                            self.workflow_list_state.query = last.clone();
+                            self.workflow_list_state.prev_page_tokens.clear();
+                            self.workflow_list_state.current_page = 1;
+                            let query = self.workflow_list_state.get_query();
+                            self.dispatch_workflow_refresh(query);
+                        }
+                    }
+                    Some(Action::ToggleShortIds) => {
+                        // Toggle between full workflow IDs and their last path segment
+                        self.short_workflow_ids = !self.short_workflow_ids;
+                    }
+                    Some(Action::ToggleStatusChart) => {
+                        // Toggle the status distribution chart, fetching counts when it opens
+                        let state = &mut self.workflow_list_state;
+                        state.show_status_chart = !state.show_status_chart;
+                        if state.show_status_chart {
+                            let query = state.get_query();
+                            self.send_command(AppCommand::CountWorkflowsByStatus(query));
+                        }
+                    }
+                    Some(Action::CopyQuery) => {
+                        self.copy_workflow_query();
                     }
-                    KeyCode::Char('a') => {
+                    Some(Action::CopyTable) => {
+                        self.copy_workflow_table();
+                    }
+                    Some(Action::ToggleAutoRefresh) => {
                         // Toggle auto-refresh
                         self.workflow_list_state.auto_refresh_enabled = !self.workflow_list_state.auto_refresh_enabled;
+                        self.workflow_list_state.auto_refresh_held = false;
                         if self.workflow_list_state.auto_refresh_enabled {
                             tracing::info!("Auto-refresh enabled ({}s interval)", self.workflow_list_state.auto_refresh_interval_secs);
                         } else {
                             tracing::info!("Auto-refresh disabled");
                         }
                     }
-                    KeyCode::Down | KeyCode::Char('j') => {
+                    Some(Action::HoldAutoRefresh) => {
+                        // Freeze the list without losing the auto-refresh setting
+                        let state = &mut self.workflow_list_state;
+                        if state.auto_refresh_enabled {
+                            state.auto_refresh_held = !state.auto_refresh_held;
+                        }
+                    }
+                    Some(Action::Down) => {
                         self.workflow_list_state.select_next();
+                        self.check_selected_progress();
+                    }
+                    Some(Action::ToggleMark) => {
+                        self.workflow_list_state.toggle_mark();
+                    }
+                    Some(Action::MarkAll) => {
+                        self.workflow_list_state.mark_all();
+                    }
+                    Some(Action::ClearMarks) => {
+                        self.workflow_list_state.marked.clear();
                     }
-                    KeyCode::Up | KeyCode::Char('k') => {
+                    Some(Action::InvertMarks) => {
+                        self.workflow_list_state.invert_marks();
+                    }
+                    Some(Action::Up) => {
                         self.workflow_list_state.select_previous();
+                        self.check_selected_progress();
                     }
-                    KeyCode::Char('r') => {
+                    Some(Action::Refresh) => {
                         // Refresh workflows with current query (reset to page 1)
-                        self.workflow_list_state.loading = true;
                         self.workflow_list_state.prev_page_tokens.clear();
                         self.workflow_list_state.current_page = 1;
                         let query = self.workflow_list_state.get_query();
-                        let _ = self.command_tx.send(AppCommand::RefreshWorkflows(query));
+                        self.dispatch_workflow_refresh(query);
                     }
-                    KeyCode::Char('n') | KeyCode::Right => {
+                    Some(Action::NextPage) => {
                         // Next page
                         if self.workflow_list_state.has_next_page() && !self.workflow_list_state.loading {
                             tracing::info!("Loading next page");
                             self.workflow_list_state.loading = true;
+                            self.workflow_list_state.start_paging();
 
                             // Save current page token for going back
                             if !self.workflow_list_state.next_page_token.is_empty() {
@@ -876,80 +4554,232 @@ impl App {
 
                             let query = self.workflow_list_state.get_query();
                             let page_token = self.workflow_list_state.next_page_token.clone();
-                            let _ = self.command_tx.send(AppCommand::LoadNextPage(query, page_token));
+                            if self.workflow_list_state.archived {
+                                self.send_command(AppCommand::ListArchivedWorkflows(query, page_token));
+                            } else {
+                                self.send_command(AppCommand::LoadNextPage(query, page_token));
+                            }
                         }
                     }
-                    KeyCode::Char('p') | KeyCode::Left => {
+                    Some(Action::PreviousPage) => {
                         // Previous page
                         if self.workflow_list_state.has_prev_page() && !self.workflow_list_state.loading {
                             tracing::info!("Loading previous page");
                             self.workflow_list_state.loading = true;
+                            self.workflow_list_state.start_paging();
 
                             // Pop the last page token
                             self.workflow_list_state.prev_page_tokens.pop();
                             self.workflow_list_state.current_page = self.workflow_list_state.current_page.saturating_sub(1).max(1);
 
                             let query = self.workflow_list_state.get_query();
-                            let _ = self.command_tx.send(AppCommand::LoadPreviousPage(query));
+                            if self.workflow_list_state.archived {
+                                self.send_command(AppCommand::ListArchivedWorkflows(query, Vec::new()));
+                            } else {
+                                self.send_command(AppCommand::LoadPreviousPage(query));
+                            }
                         }
                     }
-                    KeyCode::Enter => {
-                        // View workflow detail
+                    Some(Action::GroupByType) => {
+                        self.workflow_list_state.toggle_group_by_type();
+                        self.check_selected_progress();
+                    }
+                    Some(Action::Select) => {
+                        // Expand/collapse a type group, or view workflow detail
+                        if self.workflow_list_state.toggle_selected_group() {
+                            return Ok(());
+                        }
                         if let Some(workflow) = self.workflow_list_state.selected_workflow() {
                             if let Some(execution) = &workflow.execution {
-                                tracing::info!("Viewing workflow: {}", execution.workflow_id);
-                                self.workflow_detail_state.loading = true;
-                                let _ = self.command_tx.send(AppCommand::ViewWorkflowDetail(
-                                    execution.workflow_id.clone(),
-                                    execution.run_id.clone(),
-                                ));
-                                self.current_screen = Screen::WorkflowDetail;
+                                let workflow_id = execution.workflow_id.clone();
+                                let run_id = execution.run_id.clone();
+                                tracing::info!("Viewing workflow: {}", workflow_id);
+                                self.open_workflow_detail(workflow_id, run_id);
                             }
                         }
                     }
-                    _ => {}
+                    Some(Action::AllNamespaces) => {
+                        self.open_aggregate_view();
+                    }
+                    Some(action) => self.handle_global_action(action),
+                    None => {}
+                }
+            }
+            Screen::Namespaces => {
+                // Handle message dismissal - any key dismisses
+                if self.namespace_list_state.message.is_some() {
+                    self.namespace_list_state.message = None;
+                    return Ok(());
+                }
+
+                // The create/update dialog captures input while open
+                if self.namespace_list_state.dialog.is_some() {
+                    self.handle_namespace_dialog_key(key);
+                    return Ok(());
+                }
+
+                // The details modal closes on its own keys; `s` switches from it
+                if self.namespace_list_state.show_detail {
+                    match key {
+                        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('v') | KeyCode::Enter => {
+                            self.namespace_list_state.show_detail = false;
+                        }
+                        KeyCode::Char('s') => {
+                            self.namespace_list_state.show_detail = false;
+                            self.switch_to_selected_namespace();
+                        }
+                        _ => {}
+                    }
+                    return Ok(());
+                }
+
+                match keymap::action_for(Screen::Namespaces, key) {
+                    Some(Action::Back) => {
+                        self.current_screen = Screen::Workflows;
+                    }
+                    Some(Action::Down) => {
+                        self.namespace_list_state.select_next();
+                    }
+                    Some(Action::Up) => {
+                        self.namespace_list_state.select_previous();
+                    }
+                    Some(Action::Refresh) => {
+                        // Refresh namespaces
+                        self.namespace_list_state.loading = true;
+                        self.send_command(AppCommand::RefreshNamespaces);
+                    }
+                    Some(Action::CycleFilter) => {
+                        // Cycle through namespace state filters
+                        self.namespace_list_state.cycle_state_filter();
+                    }
+                    Some(Action::CreateNamespace) => {
+                        // Show create namespace dialog
+                        self.open_namespace_dialog(NamespaceDialog::create());
+                    }
+                    Some(Action::UpdateNamespace) => {
+                        // Show update dialog for the selected namespace
+                        if let Some(namespace) = self.namespace_list_state.selected_namespace() {
+                            let dialog = NamespaceDialog::update(namespace);
+                            self.open_namespace_dialog(dialog);
+                        }
+                    }
+                    Some(Action::Select) => {
+                        // Guard against switching by accident when configured to
+                        if self.config.enter_views_namespace {
+                            self.view_selected_namespace();
+                        } else {
+                            self.switch_to_selected_namespace();
+                        }
+                    }
+                    Some(Action::SwitchNamespace) => {
+                        self.switch_to_selected_namespace();
+                    }
+                    Some(Action::ViewNamespace) => {
+                        self.view_selected_namespace();
+                    }
+                    Some(action) => self.handle_global_action(action),
+                    None => {}
                 }
             }
-            Screen::Namespaces => match key {
-                KeyCode::Char('q') | KeyCode::Esc => {
+            Screen::AllNamespaces => match keymap::action_for(Screen::AllNamespaces, key) {
+                Some(Action::Back) => {
                     self.current_screen = Screen::Workflows;
                 }
-                KeyCode::Char('1') => {
+                Some(Action::Down) => {
+                    self.aggregate_state.select_next();
+                }
+                Some(Action::Up) => {
+                    self.aggregate_state.select_previous();
+                }
+                Some(Action::Refresh) => {
+                    self.refresh_aggregate();
+                }
+                Some(Action::Select) => {
+                    self.open_aggregate_selection();
+                }
+                Some(action) => self.handle_global_action(action),
+                None => {}
+            },
+            Screen::Batches => match keymap::action_for(Screen::Batches, key) {
+                Some(Action::Back) => {
                     self.current_screen = Screen::Workflows;
                 }
-                KeyCode::Char('2') => {
-                    self.current_screen = Screen::Namespaces;
+                Some(Action::Down) => {
+                    self.batch_list_state.select_next();
                 }
-                KeyCode::Down | KeyCode::Char('j') => {
-                    self.namespace_list_state.select_next();
+                Some(Action::Up) => {
+                    self.batch_list_state.select_previous();
                 }
-                KeyCode::Up | KeyCode::Char('k') => {
-                    self.namespace_list_state.select_previous();
+                Some(Action::Refresh) => {
+                    self.batch_list_state.loading = true;
+                    self.send_command(AppCommand::RefreshBatchOperations);
                 }
-                KeyCode::Char('r') => {
-                    // Refresh namespaces
-                    self.namespace_list_state.loading = true;
-                    let _ = self.command_tx.send(AppCommand::RefreshNamespaces);
+                Some(Action::CycleFilter) => {
+                    self.batch_list_state.cycle_state_filter();
                 }
-                KeyCode::Enter => {
-                    // Switch to selected namespace
-                    if let Some(ns_response) = self.namespace_list_state.selected_namespace() {
-                        if let Some(ns_info) = &ns_response.namespace_info {
-                            let namespace_name = ns_info.name.clone();
-                            tracing::info!("Switching to namespace: {}", namespace_name);
-                            let _ = self.command_tx.send(AppCommand::SwitchNamespace(namespace_name));
-                        }
-                    }
+                Some(action) => self.handle_global_action(action),
+                None => {}
+            },
+            Screen::Nexus => match keymap::action_for(Screen::Nexus, key) {
+                Some(Action::Back) => {
+                    self.current_screen = Screen::Workflows;
                 }
-                _ => {}
+                Some(Action::Down) => {
+                    self.nexus_list_state.select_next();
+                }
+                Some(Action::Up) => {
+                    self.nexus_list_state.select_previous();
+                }
+                Some(Action::Refresh) => {
+                    self.nexus_list_state.loading = true;
+                    self.send_command(AppCommand::RefreshNexusEndpoints);
+                }
+                Some(action) => self.handle_global_action(action),
+                None => {}
             },
             Screen::WorkflowDetail => {
                 // Handle event detail modal scrolling and dismissal
                 if self.workflow_detail_state.show_event_detail {
+                    self.workflow_detail_state.modal_notice = None;
                     match key {
+                        KeyCode::Char('y') => {
+                            self.copy_selected_event();
+                        }
+                        KeyCode::Tab => {
+                            self.workflow_detail_state.next_reference();
+                        }
+                        KeyCode::Enter => {
+                            let has_references = self
+                                .workflow_detail_state
+                                .selected_event()
+                                .is_some_and(|event| !event_references(event).is_empty());
+                            if has_references && !self.workflow_detail_state.follow_reference() {
+                                self.workflow_detail_state.modal_notice =
+                                    Some("Referenced event is not in the loaded history".to_string());
+                            }
+                        }
                         KeyCode::Esc | KeyCode::Char('q') => {
-                            self.workflow_detail_state.show_event_detail = false;
-                            self.workflow_detail_state.event_detail_scroll_offset = 0;
+                            self.workflow_detail_state.close_event_detail();
+                        }
+                        KeyCode::Char('w') => {
+                            let state = &mut self.workflow_detail_state;
+                            state.event_detail_wrap = !state.event_detail_wrap;
+                            state.event_detail_hscroll = 0;
+                        }
+                        KeyCode::Char('R') => {
+                            let state = &mut self.workflow_detail_state;
+                            state.event_detail_raw = !state.event_detail_raw;
+                            state.event_detail_scroll_offset = 0;
+                            state.event_detail_hscroll = 0;
+                        }
+                        KeyCode::Right | KeyCode::Char('l') if !self.workflow_detail_state.event_detail_wrap => {
+                            let max = ui::screens::workflow_detail::event_detail_width(self).saturating_sub(1);
+                            let state = &mut self.workflow_detail_state;
+                            state.event_detail_hscroll = state.event_detail_hscroll.saturating_add(EVENT_DETAIL_HSCROLL_STEP).min(max);
+                        }
+                        KeyCode::Left | KeyCode::Char('h') if !self.workflow_detail_state.event_detail_wrap => {
+                            let state = &mut self.workflow_detail_state;
+                            state.event_detail_hscroll = state.event_detail_hscroll.saturating_sub(EVENT_DETAIL_HSCROLL_STEP);
                         }
                         KeyCode::Down | KeyCode::Char('j') => {
                             self.workflow_detail_state.event_detail_scroll_offset =
@@ -972,20 +4802,99 @@ impl App {
                     return Ok(());
                 }
 
+                if self.workflow_detail_state.show_metadata {
+                    let max = ui::screens::metadata::metadata_line_count(self).saturating_sub(1);
+                    let state = &mut self.workflow_detail_state;
+                    match key {
+                        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('m') => state.show_metadata = false,
+                        KeyCode::Down | KeyCode::Char('j') => state.metadata_scroll = state.metadata_scroll.saturating_add(1).min(max),
+                        KeyCode::Up | KeyCode::Char('k') => state.metadata_scroll = state.metadata_scroll.saturating_sub(1),
+                        KeyCode::PageDown => state.metadata_scroll = state.metadata_scroll.saturating_add(10).min(max),
+                        KeyCode::PageUp => state.metadata_scroll = state.metadata_scroll.saturating_sub(10),
+                        _ => {}
+                    }
+                    return Ok(());
+                }
+
+                // Walk the continue-as-new chain while comparing runs
+                if let Some(diff) = &mut self.workflow_detail_state.run_diff {
+                    match key {
+                        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('D') => {
+                            self.workflow_detail_state.run_diff = None;
+                        }
+                        KeyCode::Right | KeyCode::Char('l') => self.step_run_diff(true),
+                        KeyCode::Left | KeyCode::Char('h') => self.step_run_diff(false),
+                        KeyCode::Down | KeyCode::Char('j') => diff.scroll = diff.scroll.saturating_add(1),
+                        KeyCode::Up | KeyCode::Char('k') => diff.scroll = diff.scroll.saturating_sub(1),
+                        KeyCode::PageDown => diff.scroll = diff.scroll.saturating_add(10),
+                        KeyCode::PageUp => diff.scroll = diff.scroll.saturating_sub(10),
+                        _ => {}
+                    }
+                    return Ok(());
+                }
+
+                // Handle update outcome dismissal - any key dismisses
+                if self.workflow_detail_state.update_outcome.is_some() {
+                    self.workflow_detail_state.update_outcome = None;
+                    return Ok(());
+                }
+
                 // Handle success message dismissal - any key dismisses
                 if self.workflow_detail_state.success_message.is_some() {
                     self.workflow_detail_state.success_message = None;
                     return Ok(());
                 }
 
+                // Handle notice dismissal - any key dismisses
+                if self.workflow_detail_state.notice.is_some() {
+                    self.workflow_detail_state.notice = None;
+                    return Ok(());
+                }
+
                 // Handle dialog input mode separately
                 if let Some(operation) = &self.workflow_detail_state.show_dialog {
                     match key {
+                        KeyCode::Tab if *operation == WorkflowOperation::Update => {
+                            self.workflow_detail_state.dialog_args_focused = !self.workflow_detail_state.dialog_args_focused;
+                        }
+                        KeyCode::Tab if *operation == WorkflowOperation::Restart => {
+                            self.workflow_detail_state.restart_terminate = !self.workflow_detail_state.restart_terminate;
+                        }
+                        KeyCode::Char(_) if *operation == WorkflowOperation::Restart => {}
+                        KeyCode::Tab if *operation == WorkflowOperation::Reset => {
+                            self.workflow_detail_state.cycle_reset_reapply();
+                        }
+                        KeyCode::Down if *operation == WorkflowOperation::Reset => {
+                            self.workflow_detail_state.step_reset_point(true);
+                        }
+                        KeyCode::Up if *operation == WorkflowOperation::Reset => {
+                            self.workflow_detail_state.step_reset_point(false);
+                        }
+                        KeyCode::Char(c @ '1'..='9')
+                            if *operation == WorkflowOperation::Signal
+                                && self.workflow_detail_state.dialog_input.is_empty() =>
+                        {
+                            // Numbered quick signals send right away; digits only shortcut an empty name
+                            let index = c as usize - '1' as usize;
+                            if let Some(signal) = self.config.quick_signals.get(index).cloned() {
+                                self.send_quick_signal(signal);
+                            } else {
+                                self.workflow_detail_state.dialog_input.push(c);
+                            }
+                        }
                         KeyCode::Char(c) => {
-                            self.workflow_detail_state.dialog_input.push(c);
+                            if self.workflow_detail_state.dialog_args_focused {
+                                self.workflow_detail_state.dialog_args_input.push(c);
+                            } else {
+                                self.workflow_detail_state.dialog_input.push(c);
+                            }
                         }
                         KeyCode::Backspace => {
-                            self.workflow_detail_state.dialog_input.pop();
+                            if self.workflow_detail_state.dialog_args_focused {
+                                self.workflow_detail_state.dialog_args_input.pop();
+                            } else {
+                                self.workflow_detail_state.dialog_input.pop();
+                            }
                         }
                         KeyCode::Enter => {
                             // Execute the operation
@@ -995,34 +4904,85 @@ impl App {
                                     let run_id = execution.run_id.clone();
                                     let input = self.workflow_detail_state.dialog_input.clone();
 
+                                    // The cached detail is stale once the workflow has been acted on
+                                    self.detail_cache.invalidate(&workflow_id);
+
                                     match operation {
                                         WorkflowOperation::Terminate => {
                                             let reason = if input.is_empty() { "Terminated by user".to_string() } else { input };
-                                            let _ = self.command_tx.send(AppCommand::TerminateWorkflow(workflow_id, run_id, reason));
+                                            self.send_command(AppCommand::TerminateWorkflow(workflow_id, run_id, reason));
                                         }
                                         WorkflowOperation::Cancel => {
-                                            let _ = self.command_tx.send(AppCommand::CancelWorkflow(workflow_id, run_id));
+                                            self.send_command(AppCommand::CancelWorkflow(workflow_id, run_id));
                                         }
                                         WorkflowOperation::Signal => {
                                             if !input.is_empty() {
-                                                let _ = self.command_tx.send(AppCommand::SignalWorkflow(workflow_id, run_id, input));
+                                                self.send_command(AppCommand::SignalWorkflow(workflow_id, run_id, input, String::new()));
+                                            } else {
+                                                self.workflow_detail_state.error = Some(AppError::message("Signal name cannot be empty"));
+                                            }
+                                        }
+                                        WorkflowOperation::Update => {
+                                            if !input.is_empty() {
+                                                let args = self.workflow_detail_state.dialog_args_input.clone();
+                                                self.send_command(AppCommand::UpdateWorkflow(workflow_id, run_id, input, args));
                                             } else {
-                                                self.workflow_detail_state.error = Some("Signal name cannot be empty".to_string());
-                                                self.workflow_detail_state.show_dialog = None;
-                                                self.workflow_detail_state.dialog_input.clear();
+                                                self.workflow_detail_state.error = Some(AppError::message("Update name cannot be empty"));
                                             }
                                         }
+                                        WorkflowOperation::Restart => {
+                                            // Only a running execution needs terminating; a closed one frees the ID
+                                            let terminate = self.workflow_detail_state.restart_terminate
+                                                && self.workflow_detail_state.is_running();
+                                            self.send_command(AppCommand::RestartWorkflow(workflow_id, run_id, terminate));
+                                        }
+                                        WorkflowOperation::Reset => match self.workflow_detail_state.reset_point() {
+                                            Some(event_id) => {
+                                                let reason = if input.is_empty() { "Reset by user".to_string() } else { input };
+                                                let reapply_type = self.workflow_detail_state.reset_reapply as i32;
+                                                self.send_command(AppCommand::ResetWorkflow(
+                                                    workflow_id,
+                                                    run_id,
+                                                    event_id,
+                                                    reason,
+                                                    reapply_type,
+                                                ));
+                                            }
+                                            None => {
+                                                self.workflow_detail_state.error =
+                                                    Some(AppError::message("No completed workflow task to reset to"));
+                                            }
+                                        },
                                     }
                                 }
                             }
                             // Close dialog after sending command
-                            self.workflow_detail_state.show_dialog = None;
-                            self.workflow_detail_state.dialog_input.clear();
+                            self.workflow_detail_state.close_dialog();
                         }
                         KeyCode::Esc => {
                             // Cancel dialog
-                            self.workflow_detail_state.show_dialog = None;
-                            self.workflow_detail_state.dialog_input.clear();
+                            self.workflow_detail_state.close_dialog();
+                        }
+                        _ => {}
+                    }
+                    return Ok(());
+                }
+
+                // Handle search input mode
+                if self.workflow_detail_state.search_mode {
+                    match key {
+                        KeyCode::Char(c) => {
+                            self.workflow_detail_state.search_query.push(c);
+                        }
+                        KeyCode::Backspace => {
+                            self.workflow_detail_state.search_query.pop();
+                        }
+                        KeyCode::Enter => {
+                            self.workflow_detail_state.search_mode = false;
+                            self.workflow_detail_state.run_search();
+                        }
+                        KeyCode::Esc => {
+                            self.workflow_detail_state.clear_search();
                         }
                         _ => {}
                     }
@@ -1030,61 +4990,128 @@ impl App {
                 }
 
                 // Normal mode key handling
-                match key {
-                    KeyCode::Char('q') | KeyCode::Esc => {
-                        self.current_screen = Screen::Workflows;
+                match keymap::action_for(Screen::WorkflowDetail, key) {
+                    Some(Action::Back) if !self.workflow_detail_state.search_query.is_empty() => {
+                        // First ESC clears an active search
+                        self.workflow_detail_state.clear_search();
                     }
-                    KeyCode::Char('1') => {
+                    Some(Action::Back) => {
                         self.current_screen = Screen::Workflows;
                     }
-                    KeyCode::Char('2') => {
-                        self.current_screen = Screen::Namespaces;
-                        // Load namespaces if empty
-                        if self.namespace_list_state.items.is_empty() && !self.namespace_list_state.loading {
-                            self.namespace_list_state.loading = true;
-                            let _ = self.command_tx.send(AppCommand::RefreshNamespaces);
+                    Some(Action::Refresh) if self.workflow_detail_state.error.is_some() => {
+                        // Retry loading the workflow that failed
+                        if let Some(workflow_id) = self.workflow_detail_state.workflow_id.clone() {
+                            let run_id = self.workflow_detail_state.run_id.clone();
+                            self.open_workflow_detail(workflow_id, run_id);
                         }
                     }
-                    KeyCode::Char('t') => {
+                    Some(Action::Refresh) => {
+                        self.reload_workflow_detail();
+                    }
+                    Some(Action::Search) => {
+                        // Search event types and attributes
+                        self.workflow_detail_state.search_mode = true;
+                    }
+                    Some(Action::NextMatch) => {
+                        self.workflow_detail_state.jump_to_match(true);
+                    }
+                    Some(Action::PreviousMatch) => {
+                        self.workflow_detail_state.jump_to_match(false);
+                    }
+                    Some(Action::Terminate) => {
                         // Show terminate dialog
-                        self.workflow_detail_state.show_dialog = Some(WorkflowOperation::Terminate);
-                        self.workflow_detail_state.dialog_input.clear();
-                        self.workflow_detail_state.success_message = None;
-                        self.workflow_detail_state.error = None;
+                        self.open_operation_dialog(WorkflowOperation::Terminate);
                     }
-                    KeyCode::Char('x') => {
+                    Some(Action::Cancel) => {
                         // Show cancel dialog
-                        self.workflow_detail_state.show_dialog = Some(WorkflowOperation::Cancel);
-                        self.workflow_detail_state.dialog_input.clear();
-                        self.workflow_detail_state.success_message = None;
-                        self.workflow_detail_state.error = None;
+                        self.open_operation_dialog(WorkflowOperation::Cancel);
                     }
-                    KeyCode::Char('s') => {
+                    Some(Action::Signal) => {
                         // Show signal dialog
-                        self.workflow_detail_state.show_dialog = Some(WorkflowOperation::Signal);
-                        self.workflow_detail_state.dialog_input.clear();
-                        self.workflow_detail_state.success_message = None;
-                        self.workflow_detail_state.error = None;
+                        self.open_operation_dialog(WorkflowOperation::Signal);
+                    }
+                    Some(Action::Update) => {
+                        // Show update dialog
+                        self.open_operation_dialog(WorkflowOperation::Update);
+                    }
+                    Some(Action::Restart) => {
+                        // Show restart dialog
+                        self.open_operation_dialog(WorkflowOperation::Restart);
                     }
-                    KeyCode::Down | KeyCode::Char('j') => {
+                    Some(Action::Reset) => {
+                        // Show reset dialog for the selected point in history
+                        self.open_operation_dialog(WorkflowOperation::Reset);
+                    }
+                    Some(Action::Down) => {
                         self.workflow_detail_state.select_next();
                     }
-                    KeyCode::Up | KeyCode::Char('k') => {
+                    Some(Action::Up) => {
                         self.workflow_detail_state.select_previous();
                     }
-                    KeyCode::Enter => {
+                    Some(Action::GroupActivities) => {
+                        // Group activity attempts into collapsible rows
+                        self.workflow_detail_state.toggle_grouping();
+                    }
+                    Some(Action::ToggleWorkflowTasks) => {
+                        self.workflow_detail_state.toggle_workflow_tasks();
+                    }
+                    Some(Action::ExpandMetadata) if self.workflow_detail_state.workflow.is_some() => {
+                        self.workflow_detail_state.show_metadata = true;
+                        self.workflow_detail_state.metadata_scroll = 0;
+                    }
+                    Some(Action::Follow) => {
+                        self.toggle_follow();
+                    }
+                    Some(Action::DiffRuns) => {
+                        self.open_run_diff();
+                    }
+                    Some(Action::OpenContinuation) => {
+                        self.open_continuation();
+                    }
+                    Some(Action::Select) if self.workflow_detail_state.toggle_selected_group() => {
+                        // Expanded or collapsed an activity group
+                    }
+                    Some(Action::Select) => {
                         // Show event detail modal
-                        if self.workflow_detail_state.selected_event().is_some() {
-                            self.workflow_detail_state.event_detail_scroll_offset = 0;
-                            self.workflow_detail_state.show_event_detail = true;
-                        }
+                        self.workflow_detail_state.open_event_detail();
                     }
-                    _ => {}
+                    Some(action) => self.handle_global_action(action),
+                    None => {}
                 }
             }
+            Screen::Setup => match key {
+                KeyCode::Esc => {
+                    self.running = false;
+                }
+                KeyCode::Tab | KeyCode::Down => {
+                    self.setup_state.focus_next();
+                }
+                KeyCode::BackTab | KeyCode::Up => {
+                    self.setup_state.focus_previous();
+                }
+                KeyCode::Enter => {
+                    // Connection happens in the run loop since it needs to await
+                    self.setup_state.error = None;
+                    self.setup_state.submitted = true;
+                    self.connection_status = ConnectionStatus::Connecting;
+                }
+                KeyCode::Backspace => {
+                    self.setup_state.focused_value_mut().pop();
+                }
+                KeyCode::Char(c) => {
+                    self.setup_state.focused_value_mut().push(c);
+                }
+                _ => {}
+            },
             Screen::Help => match key {
                 KeyCode::Char('q') | KeyCode::Esc | KeyCode::Char('?') => {
-                    self.current_screen = Screen::Workflows;
+                    self.current_screen = self.help_state.return_to;
+                }
+                KeyCode::Char('i') => {
+                    self.show_connection_info = true;
+                }
+                KeyCode::Char(':') | KeyCode::Char('n') => {
+                    self.open_namespace_switcher();
                 }
                 KeyCode::Down | KeyCode::Char('j') => {
                     self.help_state.scroll_down(1);
@@ -1105,4 +5132,279 @@ impl App {
     }
 }
 
+/// Write text to the system clipboard with the OSC 52 escape sequence. The
+/// terminal does the copying, so this can't tell whether it was allowed.
+fn copy_to_clipboard(text: &str) -> std::io::Result<()> {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+    let mut stdout = std::io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", encoded)?;
+    stdout.flush()
+}
+
+/// Fill in a reason template's `{user}` (from `$USER`/`$USERNAME`) and `{date}` (today, UTC)
+fn expand_reason_template(template: &str) -> String {
+    let user = std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .unwrap_or_else(|_| "unknown".to_string());
+    template
+        .replace("{user}", &user)
+        .replace("{date}", &Utc::now().format("%Y-%m-%d").to_string())
+}
+
+/// Quote a value for a POSIX shell unless it only has safe characters
+fn shell_quote(value: &str) -> String {
+    let safe = |c: char| c.is_ascii_alphanumeric() || "-_./:@".contains(c);
+    if !value.is_empty() && value.chars().all(safe) {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+}
+
 // Note: App is no longer Clone since it owns channels and moves into run()
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::temporal::api::{
+        common::v1::WorkflowExecution,
+        operatorservice::v1::ListNexusEndpointsResponse,
+        workflowservice::v1::{
+            DescribeTaskQueueResponse, DescribeWorkflowExecutionResponse, GetWorkflowExecutionHistoryResponse,
+            ListArchivedWorkflowExecutionsResponse, ListBatchOperationsResponse, ListNamespacesResponse,
+            ListWorkflowExecutionsResponse, UpdateWorkflowExecutionResponse,
+        },
+    };
+    use anyhow::anyhow;
+
+    /// Serves canned workflow list pages keyed by page token; calls the tests don't need fail
+    #[derive(Clone, Default)]
+    struct FakeClient {
+        namespace: String,
+        system_info: GetSystemInfoResponse,
+        pages: HashMap<Vec<u8>, ListWorkflowExecutionsResponse>,
+    }
+
+    fn unsupported<T>(call: &str) -> Result<T> {
+        Err(anyhow!("{} is not faked", call))
+    }
+
+    impl TemporalApi for FakeClient {
+        async fn connect(_profile: &ConnectionProfile) -> Result<Self> {
+            unsupported("connect")
+        }
+
+        fn system_info(&self) -> &GetSystemInfoResponse {
+            &self.system_info
+        }
+
+        fn namespace(&self) -> &str {
+            &self.namespace
+        }
+
+        fn set_namespace(&mut self, namespace: String) {
+            self.namespace = namespace;
+        }
+
+        async fn list_workflow_executions(
+            &mut self,
+            _page_size: i32,
+            next_page_token: Vec<u8>,
+            _query: String,
+        ) -> Result<ListWorkflowExecutionsResponse> {
+            self.pages
+                .get(&next_page_token)
+                .cloned()
+                .ok_or_else(|| anyhow!("unknown page token {:?}", next_page_token))
+        }
+
+        async fn list_archived_workflow_executions(
+            &mut self,
+            _page_size: i32,
+            _next_page_token: Vec<u8>,
+            _query: String,
+        ) -> Result<ListArchivedWorkflowExecutionsResponse> {
+            unsupported("list_archived_workflow_executions")
+        }
+
+        async fn count_workflow_executions(&mut self, _query: String) -> Result<i64> {
+            Ok(self.pages.values().map(|page| page.executions.len() as i64).sum())
+        }
+
+        async fn count_workflow_executions_by_status(&mut self, _query: String) -> Result<Vec<(String, i64)>> {
+            unsupported("count_workflow_executions_by_status")
+        }
+
+        async fn describe_workflow_execution(
+            &mut self,
+            _workflow_id: String,
+            _run_id: String,
+        ) -> Result<DescribeWorkflowExecutionResponse> {
+            unsupported("describe_workflow_execution")
+        }
+
+        async fn describe_task_queue(&mut self, _task_queue: String) -> Result<DescribeTaskQueueResponse> {
+            unsupported("describe_task_queue")
+        }
+
+        async fn last_event(&mut self, _workflow_id: String, _run_id: String) -> Result<Option<HistoryEvent>> {
+            Ok(None)
+        }
+
+        async fn get_workflow_execution_history(
+            &mut self,
+            _workflow_id: String,
+            _run_id: String,
+            _page_size: i32,
+            _next_page_token: Vec<u8>,
+        ) -> Result<GetWorkflowExecutionHistoryResponse> {
+            unsupported("get_workflow_execution_history")
+        }
+
+        async fn poll_workflow_history(
+            &mut self,
+            _workflow_id: String,
+            _run_id: String,
+            _next_page_token: Vec<u8>,
+        ) -> Result<GetWorkflowExecutionHistoryResponse> {
+            unsupported("poll_workflow_history")
+        }
+
+        async fn list_namespaces(&mut self, _page_size: i32, _next_page_token: Vec<u8>) -> Result<ListNamespacesResponse> {
+            unsupported("list_namespaces")
+        }
+
+        async fn describe_namespace(&mut self, _namespace: String) -> Result<DescribeNamespaceResponse> {
+            unsupported("describe_namespace")
+        }
+
+        async fn list_batch_operations(&mut self, _page_size: i32) -> Result<ListBatchOperationsResponse> {
+            unsupported("list_batch_operations")
+        }
+
+        async fn describe_batch_operation(&mut self, _job_id: String) -> Result<DescribeBatchOperationResponse> {
+            unsupported("describe_batch_operation")
+        }
+
+        async fn list_nexus_endpoints(&mut self, _page_size: i32) -> Result<ListNexusEndpointsResponse> {
+            unsupported("list_nexus_endpoints")
+        }
+
+        async fn register_namespace(&mut self, _name: String, _description: String, _retention_days: Option<u32>) -> Result<()> {
+            unsupported("register_namespace")
+        }
+
+        async fn update_namespace(&mut self, _name: String, _description: String, _retention_days: Option<u32>) -> Result<()> {
+            unsupported("update_namespace")
+        }
+
+        async fn terminate_workflow(&mut self, _workflow_id: String, _run_id: String, _reason: String) -> Result<()> {
+            unsupported("terminate_workflow")
+        }
+
+        async fn cancel_workflow(&mut self, _workflow_id: String, _run_id: String) -> Result<()> {
+            unsupported("cancel_workflow")
+        }
+
+        async fn signal_workflow(
+            &mut self,
+            _workflow_id: String,
+            _run_id: String,
+            _signal_name: String,
+            _args: String,
+        ) -> Result<()> {
+            unsupported("signal_workflow")
+        }
+
+        async fn update_workflow(
+            &mut self,
+            _workflow_id: String,
+            _run_id: String,
+            _update_name: String,
+            _args: String,
+        ) -> Result<UpdateWorkflowExecutionResponse> {
+            unsupported("update_workflow")
+        }
+
+        async fn restart_workflow(&mut self, _workflow_id: String, _run_id: String, _terminate: bool) -> Result<String> {
+            unsupported("restart_workflow")
+        }
+
+        async fn reset_workflow(
+            &mut self,
+            _workflow_id: String,
+            _run_id: String,
+            _workflow_task_finish_event_id: i64,
+            _reason: String,
+            _reapply_type: i32,
+        ) -> Result<String> {
+            unsupported("reset_workflow")
+        }
+    }
+
+    fn page(workflow_ids: &[&str], next_page_token: &[u8]) -> ListWorkflowExecutionsResponse {
+        ListWorkflowExecutionsResponse {
+            executions: workflow_ids
+                .iter()
+                .map(|id| WorkflowExecutionInfo {
+                    execution: Some(WorkflowExecution {
+                        workflow_id: id.to_string(),
+                        run_id: format!("{}-run", id),
+                    }),
+                    ..Default::default()
+                })
+                .collect(),
+            next_page_token: next_page_token.to_vec(),
+        }
+    }
+
+    fn loaded_ids(app: &App) -> Vec<String> {
+        app.workflow_list_state
+            .items
+            .iter()
+            .filter_map(|w| w.execution.as_ref().map(|e| e.workflow_id.clone()))
+            .collect()
+    }
+
+    /// Apply results until the workflow list stops loading
+    async fn wait_for_workflows(app: &mut App) {
+        for _ in 0..200 {
+            app.process_results();
+            if !app.workflow_list_state.loading {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        panic!("workflow list never finished loading");
+    }
+
+    #[tokio::test]
+    async fn workflows_loaded_fills_the_list_and_pages_forward() {
+        let client = FakeClient {
+            namespace: "default".to_string(),
+            pages: HashMap::from([
+                (Vec::new(), page(&["order-1", "order-2"], b"page-2")),
+                (b"page-2".to_vec(), page(&["order-3"], b"")),
+            ]),
+            ..Default::default()
+        };
+        let mut app = App::with_config(Config::default(), false, false);
+        app.attach_client(client);
+
+        let query = app.workflow_list_state.get_query();
+        app.dispatch_workflow_refresh(query);
+        wait_for_workflows(&mut app).await;
+
+        assert_eq!(loaded_ids(&app), ["order-1", "order-2"]);
+        assert_eq!(app.workflow_list_state.next_page_token, b"page-2");
+        assert_eq!(app.workflow_list_state.table_state.selected(), Some(0));
+        assert!(app.workflow_list_state.error.is_none());
+
+        app.handle_key(KeyCode::Char('n')).unwrap();
+        wait_for_workflows(&mut app).await;
+
+        assert_eq!(loaded_ids(&app), ["order-3"]);
+        assert_eq!(app.workflow_list_state.current_page, 2);
+        assert!(app.workflow_list_state.next_page_token.is_empty());
+    }
+}
@@ -0,0 +1,41 @@
+use clap::{Parser, Subcommand};
+use tracing::Level;
+
+/// Command-line arguments
+#[derive(Debug, Clone, Parser)]
+#[command(version, about)]
+pub struct Cli {
+    /// Disable all operations that modify workflows (terminate, cancel, signal, update)
+    #[arg(long)]
+    pub read_only: bool,
+
+    /// Log each gRPC request and response status to the log file
+    #[arg(long)]
+    pub debug_grpc: bool,
+
+    /// Open this workflow's detail view right after connecting
+    #[arg(long, value_name = "ID")]
+    pub workflow: Option<String>,
+
+    /// Run of `--workflow` to open (defaults to the latest run)
+    #[arg(long, value_name = "RUN_ID", requires = "workflow")]
+    pub run_id: Option<String>,
+
+    /// Minimum level written to ~/.tuiporal/tuiporal.log (error, warn, info, debug, trace)
+    #[arg(long, default_value = "info")]
+    pub log_level: Level,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Subcommands that run without the TUI
+#[derive(Debug, Clone, Subcommand)]
+pub enum Command {
+    /// Connect with a profile and report the server's response time; exits nonzero on failure
+    Check {
+        /// Profile to check (defaults to the active profile)
+        #[arg(long)]
+        profile: Option<String>,
+    },
+}
@@ -1,12 +1,98 @@
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use tonic::metadata::{Ascii, MetadataKey, MetadataValue};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     pub profiles: Vec<ConnectionProfile>,
     #[serde(default)]
     pub active_profile: Option<String>,
+    /// Use a denser layout with fewer borders (toggle at runtime with `z`)
+    #[serde(default)]
+    pub compact: bool,
+    /// Disable terminate/cancel/signal/update (also enabled by `--read-only`)
+    #[serde(default)]
+    pub read_only: bool,
+    /// Jump to the workflow list after switching namespace; false stays on the namespaces screen
+    #[serde(default = "default_true")]
+    pub switch_returns_to_workflows: bool,
+    /// Enter on the namespaces screen shows the namespace's details; only `s` switches to it
+    #[serde(default)]
+    pub enter_views_namespace: bool,
+    /// Pause auto-refresh after this many minutes without a keypress (0 never pauses)
+    #[serde(default = "default_idle_minutes")]
+    pub auto_refresh_idle_minutes: u64,
+    /// Log every gRPC request and response status to the log file (also enabled by `--debug-grpc`)
+    #[serde(default)]
+    pub debug_grpc: bool,
+    /// Stop paging in a workflow's history after this many events, so huge histories can't exhaust memory
+    #[serde(default = "default_max_history_events")]
+    pub max_history_events: usize,
+    /// Loads (lists, counts, describes) allowed in flight at once; 1 runs them one at a time
+    #[serde(default = "default_max_concurrent_requests")]
+    pub max_concurrent_requests: usize,
+    /// Refresh the workflow list on returning to it when it is at least this many seconds old (0 disables)
+    #[serde(default)]
+    pub refresh_on_focus_secs: u64,
+    /// Flag a running workflow as stalled when its latest event is older than this (0 disables)
+    #[serde(default = "default_stall_minutes")]
+    pub stall_minutes: u64,
+    /// Pre-filled terminate reason, e.g. "INCIDENT-: cleanup by {user} on {date}"; editable before confirming
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminate_reason_template: Option<String>,
+    /// Signals offered as numbered one-key shortcuts in the signal dialog
+    #[serde(default)]
+    pub quick_signals: Vec<QuickSignal>,
+    /// Leave WorkflowTaskScheduled/Started/Completed out of the detail history (reveal them with `W`)
+    #[serde(default)]
+    pub hide_workflow_task_events: bool,
+    /// Namespaces the all-namespaces view (`M` on the workflow list) runs the current query against
+    #[serde(default)]
+    pub aggregate_namespaces: Vec<String>,
+    /// Namespaces listed first on the Namespaces screen, in this order, with a pin marker
+    #[serde(default)]
+    pub pinned_namespaces: Vec<String>,
+    /// Loading spinner frames; `ascii` for terminals or fonts that show boxes for the others
+    #[serde(default)]
+    pub spinner_style: SpinnerStyle,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpinnerStyle {
+    #[default]
+    Braille,
+    Dots,
+    Line,
+    Ascii,
+}
+
+impl SpinnerStyle {
+    pub fn frames(self) -> &'static [&'static str] {
+        match self {
+            SpinnerStyle::Braille => &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+            SpinnerStyle::Dots => &["●∙∙", "∙●∙", "∙∙●", "∙●∙"],
+            SpinnerStyle::Line => &["─", "╲", "│", "╱"],
+            SpinnerStyle::Ascii => &["|", "/", "-", "\\"],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuickSignal {
+    pub name: String,
+    /// Sent as the signal's single JSON argument; omit to send none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload: Option<serde_json::Value>,
+}
+
+impl QuickSignal {
+    /// The payload as JSON text, empty when there is none
+    pub fn payload_json(&self) -> String {
+        self.payload.as_ref().map(|p| p.to_string()).unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,6 +104,18 @@ pub struct ConnectionProfile {
     pub tls: Option<TlsConfig>,
     #[serde(default)]
     pub api_key: Option<String>,
+    /// Search attribute keys to show as extra columns in the workflow list
+    #[serde(default)]
+    pub columns: Vec<String>,
+    /// Show only the last `/`-separated segment of workflow IDs in the list (toggle with `w`)
+    #[serde(default)]
+    pub short_workflow_ids: bool,
+    /// Extra gRPC metadata sent with every request, e.g. routing or tenant headers for a gateway
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub headers: HashMap<String, String>,
+    /// Screen to open on launch: `workflows` (the default) or `namespaces`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_screen: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,14 +125,204 @@ pub struct TlsConfig {
     pub ca_path: Option<PathBuf>,
     #[serde(default = "default_true")]
     pub enabled: bool,
+    /// Name to send for SNI and to verify the certificate against, when it differs from the address host
+    #[serde(default)]
+    pub server_name: Option<String>,
+    /// Skip server certificate verification entirely. Development only!
+    #[serde(default)]
+    pub insecure_skip_verify: bool,
+}
+
+/// File names tried in each config directory, in order (YAML stays the default for new files)
+const CONFIG_FILE_NAMES: [&str; 3] = ["config.yaml", "config.yml", "config.toml"];
+
+fn is_toml(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "toml")
 }
 
 fn default_true() -> bool {
     true
 }
 
+fn default_idle_minutes() -> u64 {
+    30
+}
+
+fn default_stall_minutes() -> u64 {
+    10
+}
+
+fn default_max_history_events() -> usize {
+    5000
+}
+
+fn default_max_concurrent_requests() -> usize {
+    4
+}
+
+/// Replace each `${VAR}` in a config value with that environment variable.
+/// Values without references are returned unchanged.
+fn interpolate_env(value: &str, profile: &str, field: &str) -> Result<String> {
+    let mut resolved = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        let Some(len) = rest[start + 2..].find('}') else {
+            break; // An unterminated reference is kept as written
+        };
+        let name = &rest[start + 2..start + 2 + len];
+        let Ok(var) = std::env::var(name) else {
+            bail!(
+                "Profile '{}': {} references ${{{}}}, but that environment variable is not set",
+                profile,
+                field,
+                name
+            );
+        };
+        resolved.push_str(&rest[..start]);
+        resolved.push_str(&var);
+        rest = &rest[start + 2 + len + 1..];
+    }
+    resolved.push_str(rest);
+    Ok(resolved)
+}
+
+impl ConnectionProfile {
+    /// Resolve `${VAR}` references in the address, API key, header values and TLS paths.
+    /// Returns a message for each unset variable; those values are left as written.
+    fn interpolate_env(&mut self) -> Vec<String> {
+        let profile = self.name.clone();
+        let mut problems = Vec::new();
+        let mut resolve = |value: &mut String, field: &str| match interpolate_env(value, &profile, field) {
+            Ok(resolved) => *value = resolved,
+            Err(e) => problems.push(e.to_string()),
+        };
+        resolve(&mut self.address, "address");
+        if let Some(api_key) = &mut self.api_key {
+            resolve(api_key, "api_key");
+        }
+        for (name, value) in self.headers.iter_mut() {
+            resolve(value, &format!("headers.{}", name));
+        }
+        if let Some(tls) = &mut self.tls {
+            for (path, field) in [
+                (&mut tls.cert_path, "tls.cert_path"),
+                (&mut tls.key_path, "tls.key_path"),
+                (&mut tls.ca_path, "tls.ca_path"),
+            ] {
+                // Non-UTF-8 paths can't contain a reference
+                if let Some(mut value) = path.as_ref().and_then(|p| p.to_str()).map(str::to_string) {
+                    resolve(&mut value, field);
+                    *path = Some(PathBuf::from(value));
+                }
+            }
+        }
+        problems
+    }
+
+    /// Headers gRPC can't carry, so a typo fails at startup rather than on every request
+    fn header_problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        for (name, value) in &self.headers {
+            if MetadataKey::<Ascii>::from_bytes(name.as_bytes()).is_err() || name.ends_with("-bin") {
+                problems.push(format!(
+                    "Profile '{}': header name '{}' is not a valid ASCII gRPC metadata key",
+                    self.name, name
+                ));
+            } else if MetadataValue::<Ascii>::try_from(value.as_str()).is_err() {
+                problems.push(format!(
+                    "Profile '{}': header '{}' has a value with characters gRPC metadata can't carry",
+                    self.name, name
+                ));
+            }
+        }
+        problems
+    }
+
+    /// Everything wrong with the profile's fields, headers and TLS files, one fixable message each
+    fn problems(&self) -> Vec<String> {
+        let label = if self.name.is_empty() { "(unnamed)" } else { self.name.as_str() };
+        let mut problems = Vec::new();
+        if self.name.trim().is_empty() {
+            problems.push(format!("A profile with address '{}' has an empty name", self.address));
+        }
+        if self.address.trim().is_empty() {
+            problems.push(format!("Profile '{}': address is empty (expected host:port or unix:///path)", label));
+        }
+        if self.namespace.trim().is_empty() {
+            problems.push(format!("Profile '{}': namespace is empty (use 'default' if unsure)", label));
+        }
+        if let Some(tls) = self.tls.as_ref().filter(|tls| tls.enabled) {
+            // A path still holding `${` has an unset variable, which is reported already
+            let unresolved = |path: &PathBuf| path.to_str().is_some_and(|p| p.contains("${"));
+            for (field, path) in [("cert_path", &tls.cert_path), ("key_path", &tls.key_path), ("ca_path", &tls.ca_path)] {
+                if let Some(path) = path.as_ref().filter(|path| !unresolved(path) && !path.exists()) {
+                    problems.push(format!("Profile '{}': tls.{} {} does not exist", label, field, path.display()));
+                }
+            }
+            if tls.cert_path.is_some() != tls.key_path.is_some() {
+                problems.push(format!(
+                    "Profile '{}': mTLS needs both tls.cert_path and tls.key_path, but only one is set",
+                    label
+                ));
+            }
+        }
+        problems.extend(self.header_problems());
+        problems.extend(self.default_screen_problem());
+        problems
+    }
+
+    /// A landing screen the app doesn't know, rather than silently ignoring it
+    fn default_screen_problem(&self) -> Option<String> {
+        match self.default_screen.as_deref() {
+            None | Some("workflows") | Some("namespaces") => None,
+            Some(other) => Some(format!(
+                "Profile '{}': default_screen '{}' is not one of 'workflows' or 'namespaces'",
+                self.name, other
+            )),
+        }
+    }
+}
+
 impl Config {
+    /// Load the config file with `${VAR}` references resolved from the environment
     pub fn load() -> Result<Self> {
+        let mut config = Self::load_raw()?;
+        let unset: Vec<String> = config.profiles.iter_mut().flat_map(ConnectionProfile::interpolate_env).collect();
+        config.validate(unset)?;
+        Ok(config)
+    }
+
+    /// Check the loaded values, listing every problem (after those already found) at once
+    /// instead of stopping at the first
+    fn validate(&self, mut problems: Vec<String>) -> Result<()> {
+        problems.extend(self.profiles.iter().flat_map(ConnectionProfile::problems));
+
+        let mut seen = HashSet::new();
+        for profile in &self.profiles {
+            if !profile.name.is_empty() && !seen.insert(profile.name.as_str()) {
+                problems.push(format!("Profile name '{}' is used more than once; names must be unique", profile.name));
+            }
+        }
+        if let Some(active) = &self.active_profile {
+            if !self.profiles.is_empty() && !self.profiles.iter().any(|p| &p.name == active) {
+                let names: Vec<&str> = self.profiles.iter().map(|p| p.name.as_str()).collect();
+                problems.push(format!(
+                    "active_profile '{}' does not match any profile (defined: {})",
+                    active,
+                    names.join(", ")
+                ));
+            }
+        }
+
+        if problems.is_empty() {
+            return Ok(());
+        }
+        let path = Self::get_config_path().map(|p| p.display().to_string()).unwrap_or_default();
+        bail!("Invalid config {}:\n  - {}", path, problems.join("\n  - "))
+    }
+
+    /// Load the config file as written, leaving `${VAR}` references in place
+    pub fn load_raw() -> Result<Self> {
         let config_path = Self::get_config_path()?;
 
         if !config_path.exists() {
@@ -44,21 +332,95 @@ impl Config {
 
         tracing::info!("Loading config from {:?}", config_path);
         let contents = std::fs::read_to_string(&config_path)?;
-        let config: Self = serde_yaml::from_str(&contents)?;
+        // The parser's message names the line and field; say which file it was in
+        let config: Self = if is_toml(&config_path) {
+            toml::from_str(&contents).with_context(|| format!("Failed to parse {}", config_path.display()))?
+        } else {
+            serde_yaml::from_str(&contents).with_context(|| format!("Failed to parse {}", config_path.display()))?
+        };
 
         Ok(config)
     }
 
-    fn get_config_path() -> Result<PathBuf> {
+    /// Whether a config file has been written yet (false on first run)
+    pub fn exists() -> bool {
+        Self::get_config_path().map(|p| p.exists()).unwrap_or(false)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let config_path = Self::get_config_path()?;
+        if let Some(dir) = config_path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        // Keep whichever format the user chose
+        tracing::info!("Saving config to {:?}", config_path);
+        let contents = if is_toml(&config_path) {
+            toml::to_string_pretty(self)?
+        } else {
+            serde_yaml::to_string(self)?
+        };
+        std::fs::write(&config_path, contents)?;
+
+        Ok(())
+    }
+
+    /// The first existing config file in lookup order, or where a new one should be written:
+    /// the XDG directory when `XDG_CONFIG_HOME` is set, otherwise the legacy `~/.tuiporal`
+    pub fn get_config_path() -> Result<PathBuf> {
+        let candidates = Self::config_candidates()?;
+        for path in &candidates {
+            if path.exists() {
+                tracing::debug!("Using config file {:?}", path);
+                return Ok(path.clone());
+            }
+            tracing::debug!("No config file at {:?}", path);
+        }
+
+        match Self::xdg_config_dir() {
+            Some(dir) => Ok(dir.join("tuiporal").join("config.yaml")),
+            None => Ok(Self::get_legacy_dir()?.join("config.yaml")),
+        }
+    }
+
+    /// Config file locations in lookup order: `$XDG_CONFIG_HOME/tuiporal`, `~/.config/tuiporal`,
+    /// then `~/.tuiporal`, trying each of `CONFIG_FILE_NAMES` in every directory
+    fn config_candidates() -> Result<Vec<PathBuf>> {
+        let home = Self::get_home_dir()?;
+        let mut dirs = Vec::new();
+        if let Some(xdg) = Self::xdg_config_dir() {
+            dirs.push(xdg.join("tuiporal"));
+        }
+        dirs.push(home.join(".config").join("tuiporal"));
+        dirs.push(home.join(".tuiporal"));
+
+        Ok(dirs
+            .iter()
+            .flat_map(|dir| CONFIG_FILE_NAMES.iter().map(move |name| dir.join(name)))
+            .collect())
+    }
+
+    fn xdg_config_dir() -> Option<PathBuf> {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+    }
+
+    /// Where log output goes when it is written to a file
+    pub fn get_log_path() -> Result<PathBuf> {
+        Ok(Self::get_legacy_dir()?.join("tuiporal.log"))
+    }
+
+    fn get_legacy_dir() -> Result<PathBuf> {
+        Ok(Self::get_home_dir()?.join(".tuiporal"))
+    }
+
+    fn get_home_dir() -> Result<PathBuf> {
         let home = std::env::var("HOME")
             .or_else(|_| std::env::var("USERPROFILE"))
             .map_err(|_| anyhow::anyhow!("Could not determine home directory"))?;
 
-        let mut path = PathBuf::from(home);
-        path.push(".tuiporal");
-        path.push("config.yaml");
-
-        Ok(path)
+        Ok(PathBuf::from(home))
     }
 
     pub fn get_active_profile(&self) -> Option<&ConnectionProfile> {
@@ -79,8 +441,28 @@ impl Default for Config {
                 namespace: "default".to_string(),
                 tls: None,
                 api_key: None,
+                columns: Vec::new(),
+                short_workflow_ids: false,
+                headers: HashMap::new(),
+                default_screen: None,
             }],
             active_profile: Some("local".to_string()),
+            compact: false,
+            read_only: false,
+            switch_returns_to_workflows: true,
+            enter_views_namespace: false,
+            auto_refresh_idle_minutes: default_idle_minutes(),
+            debug_grpc: false,
+            max_history_events: default_max_history_events(),
+            max_concurrent_requests: default_max_concurrent_requests(),
+            refresh_on_focus_secs: 0,
+            stall_minutes: default_stall_minutes(),
+            terminate_reason_template: None,
+            quick_signals: Vec::new(),
+            hide_workflow_task_events: false,
+            aggregate_namespaces: Vec::new(),
+            pinned_namespaces: Vec::new(),
+            spinner_style: SpinnerStyle::default(),
         }
     }
 }
@@ -3,6 +3,8 @@ use std::time::Duration;
 
 pub enum Event {
     Key(KeyEvent),
+    Paste(String),
+    Resize(u16, u16), // width, height
     Tick,
 }
 
@@ -18,6 +20,8 @@ impl EventHandler {
         if event::poll(Duration::from_millis(100))? {
             match event::read()? {
                 CrosstermEvent::Key(key) => Ok(Event::Key(key)),
+                CrosstermEvent::Paste(text) => Ok(Event::Paste(text)),
+                CrosstermEvent::Resize(width, height) => Ok(Event::Resize(width, height)),
                 _ => Ok(Event::Tick),
             }
         } else {
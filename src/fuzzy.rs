@@ -0,0 +1,41 @@
+/// Score how well `query` fuzzy-matches `candidate` (case-insensitive).
+///
+/// Every query character must appear in order in the candidate. Consecutive
+/// matches and matches at the start of a word score higher, so "prod" ranks
+/// "production" above "payments-reconciliation-dev". Returns `None` when the
+/// query is not a subsequence of the candidate.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let mut score = 0;
+    let mut query_index = 0;
+    let mut previous_matched = false;
+    let mut previous_char: Option<char> = None;
+
+    for c in candidate.to_lowercase().chars() {
+        if query_index < query.len() && c == query[query_index] {
+            score += 1;
+            if previous_matched {
+                score += 5;
+            }
+            if previous_char.is_none_or(|p| matches!(p, '-' | '_' | '.' | '/' | ' ')) {
+                score += 10;
+            }
+            query_index += 1;
+            previous_matched = true;
+        } else {
+            previous_matched = false;
+        }
+        previous_char = Some(c);
+    }
+
+    if query_index == query.len() {
+        // Prefer shorter names when the match quality is otherwise equal
+        Some(score * 100 - candidate.len() as i32)
+    } else {
+        None
+    }
+}
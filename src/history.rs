@@ -0,0 +1,273 @@
+use crate::generated::temporal::api::enums::v1::EventType;
+use crate::generated::temporal::api::failure::v1::Failure;
+use crate::generated::temporal::api::history::v1::{history_event::Attributes, HistoryEvent};
+use std::collections::{HashMap, HashSet};
+
+/// An activity's events across all its attempts, linked through `scheduled_event_id`.
+/// A new schedule of the same activity type after a failure or timeout counts as a retry.
+#[derive(Debug, Clone)]
+pub struct ActivityGroup {
+    pub activity_type: String,
+    pub events: Vec<usize>, // Indices into history, in order
+    pub attempts: u32,
+    pub state: EventType, // Latest event type, e.g. ActivityTaskFailed
+}
+
+impl ActivityGroup {
+    /// Event ID of the first scheduled event, stable across history refreshes
+    pub fn key(&self, history: &[HistoryEvent]) -> i64 {
+        history[self.events[0]].event_id
+    }
+}
+
+/// One line of the event history table
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HistoryRow {
+    Event(usize),  // An event outside any group
+    Group(usize),  // Index into the groups, shown collapsed or as the header of its members
+    Member(usize), // An event listed under its expanded group
+}
+
+/// Group activity events by attempt
+pub fn group_activities(history: &[HistoryEvent]) -> Vec<ActivityGroup> {
+    let mut groups: Vec<ActivityGroup> = Vec::new();
+    let mut by_scheduled_id: HashMap<i64, usize> = HashMap::new();
+    // Groups whose latest attempt failed, so a new schedule of that type is a retry
+    let mut retrying: HashMap<String, usize> = HashMap::new();
+
+    for (i, event) in history.iter().enumerate() {
+        let state = EventType::try_from(event.event_type).unwrap_or(EventType::Unspecified);
+        let scheduled_id = match &event.attributes {
+            Some(Attributes::ActivityTaskScheduledEventAttributes(attrs)) => {
+                let activity_type = attrs.activity_type.as_ref().map(|t| t.name.clone()).unwrap_or_default();
+                let index = match retrying.remove(&activity_type) {
+                    Some(index) => index,
+                    None => {
+                        groups.push(ActivityGroup {
+                            activity_type,
+                            events: Vec::new(),
+                            attempts: 0,
+                            state,
+                        });
+                        groups.len() - 1
+                    }
+                };
+                groups[index].events.push(i);
+                groups[index].attempts += 1;
+                groups[index].state = state;
+                by_scheduled_id.insert(event.event_id, index);
+                continue;
+            }
+            Some(Attributes::ActivityTaskStartedEventAttributes(attrs)) => {
+                // Server-side retries only surface as the attempt number on the final start
+                if let Some(&index) = by_scheduled_id.get(&attrs.scheduled_event_id) {
+                    groups[index].attempts += (attrs.attempt.max(1) - 1) as u32;
+                }
+                attrs.scheduled_event_id
+            }
+            Some(Attributes::ActivityTaskCompletedEventAttributes(attrs)) => attrs.scheduled_event_id,
+            Some(Attributes::ActivityTaskFailedEventAttributes(attrs)) => attrs.scheduled_event_id,
+            Some(Attributes::ActivityTaskTimedOutEventAttributes(attrs)) => attrs.scheduled_event_id,
+            Some(Attributes::ActivityTaskCancelRequestedEventAttributes(attrs)) => attrs.scheduled_event_id,
+            Some(Attributes::ActivityTaskCanceledEventAttributes(attrs)) => attrs.scheduled_event_id,
+            _ => continue,
+        };
+
+        let Some(&index) = by_scheduled_id.get(&scheduled_id) else {
+            continue;
+        };
+        let group = &mut groups[index];
+        group.events.push(i);
+        group.state = state;
+        match state {
+            EventType::ActivityTaskFailed | EventType::ActivityTaskTimedOut => {
+                retrying.insert(group.activity_type.clone(), index);
+            }
+            EventType::ActivityTaskCompleted | EventType::ActivityTaskCanceled
+                if retrying.get(&group.activity_type) == Some(&index) =>
+            {
+                retrying.remove(&group.activity_type);
+            }
+            _ => {}
+        }
+    }
+
+    groups
+}
+
+/// Events an event's attributes point back to, e.g. `scheduled_event_id` on an activity start,
+/// as (field name, event ID) in attribute order. References into another workflow's history
+/// (a parent's initiated event, an external cancel request) are left out.
+pub fn event_references(event: &HistoryEvent) -> Vec<(String, i64)> {
+    const WORKFLOW_TASK: &str = "workflow_task_completed_event_id";
+    const SCHEDULED: &str = "scheduled_event_id";
+    const STARTED: &str = "started_event_id";
+    const INITIATED: &str = "initiated_event_id";
+    let Some(attributes) = &event.attributes else {
+        return Vec::new();
+    };
+    let fields: Vec<(&str, i64)> = match attributes {
+        Attributes::WorkflowExecutionCompletedEventAttributes(attrs) => {
+            vec![(WORKFLOW_TASK, attrs.workflow_task_completed_event_id)]
+        }
+        Attributes::WorkflowExecutionFailedEventAttributes(attrs) => {
+            vec![(WORKFLOW_TASK, attrs.workflow_task_completed_event_id)]
+        }
+        Attributes::WorkflowExecutionContinuedAsNewEventAttributes(attrs) => {
+            vec![(WORKFLOW_TASK, attrs.workflow_task_completed_event_id)]
+        }
+        Attributes::WorkflowExecutionCanceledEventAttributes(attrs) => {
+            vec![(WORKFLOW_TASK, attrs.workflow_task_completed_event_id)]
+        }
+        Attributes::WorkflowTaskStartedEventAttributes(attrs) => vec![(SCHEDULED, attrs.scheduled_event_id)],
+        Attributes::WorkflowTaskCompletedEventAttributes(attrs) => {
+            vec![(SCHEDULED, attrs.scheduled_event_id), (STARTED, attrs.started_event_id)]
+        }
+        Attributes::WorkflowTaskTimedOutEventAttributes(attrs) => {
+            vec![(SCHEDULED, attrs.scheduled_event_id), (STARTED, attrs.started_event_id)]
+        }
+        Attributes::WorkflowTaskFailedEventAttributes(attrs) => {
+            vec![(SCHEDULED, attrs.scheduled_event_id), (STARTED, attrs.started_event_id)]
+        }
+        Attributes::ActivityTaskScheduledEventAttributes(attrs) => {
+            vec![(WORKFLOW_TASK, attrs.workflow_task_completed_event_id)]
+        }
+        Attributes::ActivityTaskStartedEventAttributes(attrs) => vec![(SCHEDULED, attrs.scheduled_event_id)],
+        Attributes::ActivityTaskCompletedEventAttributes(attrs) => {
+            vec![(SCHEDULED, attrs.scheduled_event_id), (STARTED, attrs.started_event_id)]
+        }
+        Attributes::ActivityTaskFailedEventAttributes(attrs) => {
+            vec![(SCHEDULED, attrs.scheduled_event_id), (STARTED, attrs.started_event_id)]
+        }
+        Attributes::ActivityTaskTimedOutEventAttributes(attrs) => {
+            vec![(SCHEDULED, attrs.scheduled_event_id), (STARTED, attrs.started_event_id)]
+        }
+        Attributes::ActivityTaskCancelRequestedEventAttributes(attrs) => {
+            vec![(SCHEDULED, attrs.scheduled_event_id), (WORKFLOW_TASK, attrs.workflow_task_completed_event_id)]
+        }
+        Attributes::ActivityTaskCanceledEventAttributes(attrs) => vec![
+            ("latest_cancel_requested_event_id", attrs.latest_cancel_requested_event_id),
+            (SCHEDULED, attrs.scheduled_event_id),
+            (STARTED, attrs.started_event_id),
+        ],
+        Attributes::TimerStartedEventAttributes(attrs) => vec![(WORKFLOW_TASK, attrs.workflow_task_completed_event_id)],
+        Attributes::TimerFiredEventAttributes(attrs) => vec![(STARTED, attrs.started_event_id)],
+        Attributes::TimerCanceledEventAttributes(attrs) => {
+            vec![(STARTED, attrs.started_event_id), (WORKFLOW_TASK, attrs.workflow_task_completed_event_id)]
+        }
+        Attributes::MarkerRecordedEventAttributes(attrs) => {
+            vec![(WORKFLOW_TASK, attrs.workflow_task_completed_event_id)]
+        }
+        Attributes::StartChildWorkflowExecutionInitiatedEventAttributes(attrs) => {
+            vec![(WORKFLOW_TASK, attrs.workflow_task_completed_event_id)]
+        }
+        Attributes::StartChildWorkflowExecutionFailedEventAttributes(attrs) => {
+            vec![(INITIATED, attrs.initiated_event_id), (WORKFLOW_TASK, attrs.workflow_task_completed_event_id)]
+        }
+        Attributes::ChildWorkflowExecutionStartedEventAttributes(attrs) => vec![(INITIATED, attrs.initiated_event_id)],
+        Attributes::ChildWorkflowExecutionCompletedEventAttributes(attrs) => {
+            vec![(INITIATED, attrs.initiated_event_id), (STARTED, attrs.started_event_id)]
+        }
+        Attributes::ChildWorkflowExecutionFailedEventAttributes(attrs) => {
+            vec![(INITIATED, attrs.initiated_event_id), (STARTED, attrs.started_event_id)]
+        }
+        Attributes::ChildWorkflowExecutionCanceledEventAttributes(attrs) => {
+            vec![(INITIATED, attrs.initiated_event_id), (STARTED, attrs.started_event_id)]
+        }
+        Attributes::ChildWorkflowExecutionTimedOutEventAttributes(attrs) => {
+            vec![(INITIATED, attrs.initiated_event_id), (STARTED, attrs.started_event_id)]
+        }
+        Attributes::ChildWorkflowExecutionTerminatedEventAttributes(attrs) => {
+            vec![(INITIATED, attrs.initiated_event_id), (STARTED, attrs.started_event_id)]
+        }
+        Attributes::SignalExternalWorkflowExecutionInitiatedEventAttributes(attrs) => {
+            vec![(WORKFLOW_TASK, attrs.workflow_task_completed_event_id)]
+        }
+        Attributes::SignalExternalWorkflowExecutionFailedEventAttributes(attrs) => {
+            vec![(WORKFLOW_TASK, attrs.workflow_task_completed_event_id), (INITIATED, attrs.initiated_event_id)]
+        }
+        Attributes::ExternalWorkflowExecutionSignaledEventAttributes(attrs) => {
+            vec![(INITIATED, attrs.initiated_event_id)]
+        }
+        Attributes::RequestCancelExternalWorkflowExecutionInitiatedEventAttributes(attrs) => {
+            vec![(WORKFLOW_TASK, attrs.workflow_task_completed_event_id)]
+        }
+        Attributes::RequestCancelExternalWorkflowExecutionFailedEventAttributes(attrs) => {
+            vec![(WORKFLOW_TASK, attrs.workflow_task_completed_event_id), (INITIATED, attrs.initiated_event_id)]
+        }
+        Attributes::ExternalWorkflowExecutionCancelRequestedEventAttributes(attrs) => {
+            vec![(INITIATED, attrs.initiated_event_id)]
+        }
+        Attributes::UpsertWorkflowSearchAttributesEventAttributes(attrs) => {
+            vec![(WORKFLOW_TASK, attrs.workflow_task_completed_event_id)]
+        }
+        Attributes::WorkflowPropertiesModifiedEventAttributes(attrs) => {
+            vec![(WORKFLOW_TASK, attrs.workflow_task_completed_event_id)]
+        }
+        Attributes::WorkflowExecutionUpdateAcceptedEventAttributes(attrs) => {
+            vec![("accepted_request_sequencing_event_id", attrs.accepted_request_sequencing_event_id)]
+        }
+        Attributes::WorkflowExecutionUpdateCompletedEventAttributes(attrs) => {
+            vec![("accepted_event_id", attrs.accepted_event_id)]
+        }
+        _ => Vec::new(),
+    };
+    fields
+        .into_iter()
+        // Unset references are zero
+        .filter(|(_, id)| *id > 0 && *id != event.event_id)
+        .map(|(name, id)| (name.to_string(), id))
+        .collect()
+}
+
+/// The failure an event reports, for events that end something unsuccessfully
+pub fn event_failure(event: &HistoryEvent) -> Option<&Failure> {
+    match event.attributes.as_ref()? {
+        Attributes::WorkflowExecutionFailedEventAttributes(attrs) => attrs.failure.as_ref(),
+        Attributes::WorkflowExecutionContinuedAsNewEventAttributes(attrs) => attrs.failure.as_ref(),
+        Attributes::WorkflowTaskFailedEventAttributes(attrs) => attrs.failure.as_ref(),
+        Attributes::ActivityTaskFailedEventAttributes(attrs) => attrs.failure.as_ref(),
+        Attributes::ActivityTaskTimedOutEventAttributes(attrs) => attrs.failure.as_ref(),
+        Attributes::ChildWorkflowExecutionFailedEventAttributes(attrs) => attrs.failure.as_ref(),
+        _ => None,
+    }
+}
+
+/// WorkflowTaskScheduled/Started/Completed: bookkeeping between every workflow step
+pub fn is_workflow_task_event(event: &HistoryEvent) -> bool {
+    event.event_type == EventType::WorkflowTaskScheduled as i32
+        || event.event_type == EventType::WorkflowTaskStarted as i32
+        || event.event_type == EventType::WorkflowTaskCompleted as i32
+}
+
+/// Table rows for the history: each group collapses into one row at its first event,
+/// followed by its members when expanded. Workflow task events get no row when hidden.
+pub fn build_rows(
+    history: &[HistoryEvent],
+    groups: &[ActivityGroup],
+    expanded: &HashSet<i64>,
+    hide_workflow_tasks: bool,
+) -> Vec<HistoryRow> {
+    let mut group_of = vec![None; history.len()];
+    for (index, group) in groups.iter().enumerate() {
+        for &event in &group.events {
+            group_of[event] = Some(index);
+        }
+    }
+
+    let mut rows = Vec::with_capacity(history.len());
+    for (i, group) in group_of.iter().enumerate() {
+        match group {
+            None if hide_workflow_tasks && is_workflow_task_event(&history[i]) => {}
+            None => rows.push(HistoryRow::Event(i)),
+            Some(index) if groups[*index].events[0] == i => {
+                rows.push(HistoryRow::Group(*index));
+                if expanded.contains(&groups[*index].key(history)) {
+                    rows.extend(groups[*index].events.iter().map(|&e| HistoryRow::Member(e)));
+                }
+            }
+            Some(_) => {}
+        }
+    }
+    rows
+}
@@ -0,0 +1,203 @@
+use crate::app::Screen;
+use crossterm::event::KeyCode;
+
+/// What a key does on a screen; `App::handle_key` dispatches on these
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Action {
+    // Available on every list/detail screen
+    ShowWorkflows,
+    ShowNamespaces,
+    ShowBatches,
+    ShowNexus,
+    Help,
+    ConnectionInfo,
+    NamespaceSwitcher,
+    ErrorDetails,
+    ToggleCompact,
+    EditConfig,
+    Back,
+    Down,
+    Up,
+    Select,
+    // Shared by several screens with screen-specific meaning
+    Search,
+    CycleFilter,
+    Refresh,
+    // Workflows
+    CycleTimeRange,
+    CycleOrder,
+    ToggleArchived,
+    ClearFilters,
+    RerunLastSearch,
+    ToggleAutoRefresh,
+    HoldAutoRefresh,
+    ToggleShortIds,
+    ToggleStatusChart,
+    GroupByType,
+    CopyQuery,
+    CopyTable,
+    ToggleMark,
+    MarkAll,
+    ClearMarks,
+    InvertMarks,
+    AllNamespaces,
+    NextPage,
+    PreviousPage,
+    // Workflow detail
+    NextMatch,
+    PreviousMatch,
+    GroupActivities,
+    ToggleWorkflowTasks,
+    ExpandMetadata,
+    Follow,
+    DiffRuns,
+    OpenContinuation,
+    Terminate,
+    Cancel,
+    Signal,
+    Update,
+    Restart,
+    Reset,
+    // Namespaces
+    CreateNamespace,
+    UpdateNamespace,
+    SwitchNamespace,
+    ViewNamespace,
+}
+
+pub struct Binding {
+    pub screen: Option<Screen>, // None applies to Workflows, Namespaces, Batches, Nexus, AllNamespaces and WorkflowDetail
+    pub keys: &'static [KeyCode],
+    pub action: Action,
+    pub description: &'static str,
+    pub mutating: bool, // Hidden from help in read-only mode
+}
+
+const fn bind(
+    screen: Option<Screen>,
+    keys: &'static [KeyCode],
+    action: Action,
+    description: &'static str,
+) -> Binding {
+    Binding {
+        screen,
+        keys,
+        action,
+        description,
+        mutating: false,
+    }
+}
+
+const fn mutating(binding: Binding) -> Binding {
+    Binding {
+        mutating: true,
+        ..binding
+    }
+}
+
+const WORKFLOWS: Option<Screen> = Some(Screen::Workflows);
+const DETAIL: Option<Screen> = Some(Screen::WorkflowDetail);
+const NAMESPACES: Option<Screen> = Some(Screen::Namespaces);
+const BATCHES: Option<Screen> = Some(Screen::Batches);
+const NEXUS: Option<Screen> = Some(Screen::Nexus);
+const ALL_NAMESPACES: Option<Screen> = Some(Screen::AllNamespaces);
+
+/// Every key binding, in the order the help screen lists them.
+/// Screen-specific bindings take precedence over global ones.
+pub const BINDINGS: &[Binding] = &[
+    bind(None, &[KeyCode::Char('1')], Action::ShowWorkflows, "Switch to Workflows screen"),
+    bind(None, &[KeyCode::Char('2')], Action::ShowNamespaces, "Switch to Namespaces screen"),
+    bind(None, &[KeyCode::Char('3')], Action::ShowBatches, "Switch to Batch Operations screen"),
+    bind(None, &[KeyCode::Char('4')], Action::ShowNexus, "Switch to Nexus Endpoints screen (servers with Nexus)"),
+    bind(None, &[KeyCode::Char('?')], Action::Help, "Show this help screen"),
+    bind(None, &[KeyCode::Char('i')], Action::ConnectionInfo, "Show connection info and server capabilities"),
+    bind(None, &[KeyCode::Char(':')], Action::NamespaceSwitcher, "Jump to namespace (fuzzy match)"),
+    bind(None, &[KeyCode::Char('e')], Action::ErrorDetails, "Show/hide raw details of the current error"),
+    bind(None, &[KeyCode::Char('z')], Action::ToggleCompact, "Toggle compact layout"),
+    bind(None, &[KeyCode::Char(',')], Action::EditConfig, "Edit the config file in $EDITOR, then reload and reconnect"),
+    bind(None, &[KeyCode::Down, KeyCode::Char('j')], Action::Down, "Move down"),
+    bind(None, &[KeyCode::Up, KeyCode::Char('k')], Action::Up, "Move up"),
+    bind(None, &[KeyCode::Char('q'), KeyCode::Esc], Action::Back, "Go back (quits from Workflows)"),
+    bind(WORKFLOWS, &[KeyCode::Enter], Action::Select, "View workflow details (expands/collapses a type group)"),
+    bind(WORKFLOWS, &[KeyCode::Char('/')], Action::Search, "Search workflows (Temporal visibility query)"),
+    bind(WORKFLOWS, &[KeyCode::Char('f')], Action::CycleFilter, "Cycle through status filters (Running/Completed/Failed/etc)"),
+    bind(WORKFLOWS, &[KeyCode::Char('t')], Action::CycleTimeRange, "Cycle start time range (last 1h/24h/7d)"),
+    bind(WORKFLOWS, &[KeyCode::Char('o')], Action::CycleOrder, "Cycle server-side order (default/StartTime DESC/StartTime ASC)"),
+    bind(WORKFLOWS, &[KeyCode::Char('v')], Action::ToggleArchived, "Toggle archived mode (closed runs from archival visibility)"),
+    bind(WORKFLOWS, &[KeyCode::Char('c')], Action::ClearFilters, "Clear search and filters"),
+    bind(WORKFLOWS, &[KeyCode::Char('.')], Action::RerunLastSearch, "Re-run the most recent search"),
+    bind(WORKFLOWS, &[KeyCode::Char('r')], Action::Refresh, "Refresh workflow list"),
+    bind(WORKFLOWS, &[KeyCode::Char('a')], Action::ToggleAutoRefresh, "Toggle auto-refresh"),
+    bind(WORKFLOWS, &[KeyCode::Char('h')], Action::HoldAutoRefresh, "Hold/resume auto-refresh while it stays on (moving in the list resumes)"),
+    bind(WORKFLOWS, &[KeyCode::Char('w')], Action::ToggleShortIds, "Toggle short workflow IDs (last path segment only)"),
+    bind(WORKFLOWS, &[KeyCode::Char('b')], Action::ToggleStatusChart, "Toggle status distribution chart"),
+    bind(WORKFLOWS, &[KeyCode::Char('g')], Action::GroupByType, "Group this page by workflow type with running counts"),
+    bind(WORKFLOWS, &[KeyCode::Char('y')], Action::CopyQuery, "Copy the current view as a temporal CLI command (namespace + query)"),
+    bind(WORKFLOWS, &[KeyCode::Char('Y')], Action::CopyTable, "Copy the visible rows as tab-separated text"),
+    bind(WORKFLOWS, &[KeyCode::Char(' ')], Action::ToggleMark, "Mark/unmark the selected workflow"),
+    bind(WORKFLOWS, &[KeyCode::Char('A')], Action::MarkAll, "Mark every workflow on this page"),
+    bind(WORKFLOWS, &[KeyCode::Char('X')], Action::ClearMarks, "Clear all marks"),
+    bind(WORKFLOWS, &[KeyCode::Char('*')], Action::InvertMarks, "Invert the marks on this page"),
+    bind(WORKFLOWS, &[KeyCode::Char('M')], Action::AllNamespaces, "Run the current filters/search across the configured aggregate namespaces"),
+    bind(WORKFLOWS, &[KeyCode::Right, KeyCode::Char('n')], Action::NextPage, "Next page (if available)"),
+    bind(WORKFLOWS, &[KeyCode::Left, KeyCode::Char('p')], Action::PreviousPage, "Previous page (if available)"),
+    bind(DETAIL, &[KeyCode::Enter], Action::Select, "View event details (expands/collapses an activity group)"),
+    bind(DETAIL, &[KeyCode::Char('/')], Action::Search, "Search event types and attributes (ESC clears)"),
+    bind(DETAIL, &[KeyCode::Char('n')], Action::NextMatch, "Next search match"),
+    bind(DETAIL, &[KeyCode::Char('N')], Action::PreviousMatch, "Previous search match"),
+    bind(DETAIL, &[KeyCode::Char('g')], Action::GroupActivities, "Group each activity's attempts into one row"),
+    bind(DETAIL, &[KeyCode::Char('W')], Action::ToggleWorkflowTasks, "Show/hide WorkflowTaskScheduled/Started/Completed events"),
+    bind(DETAIL, &[KeyCode::Char('m')], Action::ExpandMetadata, "Show all metadata, search attributes and memo full screen"),
+    bind(DETAIL, &[KeyCode::Char('F')], Action::Follow, "Follow new events as they happen (live tail; stops when you leave)"),
+    bind(DETAIL, &[KeyCode::Char('C')], Action::OpenContinuation, "Open the run this one continued as (after ContinuedAsNew)"),
+    bind(DETAIL, &[KeyCode::Char('D')], Action::DiffRuns, "Compare inputs with the next/previous run of a continue-as-new chain (←/→ walk it)"),
+    bind(DETAIL, &[KeyCode::Char('r')], Action::Refresh, "Reload the workflow's metadata and history, keeping your place (retries after an error)"),
+    mutating(bind(DETAIL, &[KeyCode::Char('t')], Action::Terminate, "Terminate workflow")),
+    mutating(bind(DETAIL, &[KeyCode::Char('x')], Action::Cancel, "Cancel workflow")),
+    mutating(bind(DETAIL, &[KeyCode::Char('s')], Action::Signal, "Signal workflow")),
+    mutating(bind(DETAIL, &[KeyCode::Char('u')], Action::Update, "Update workflow (invoke an update handler with JSON args)")),
+    mutating(bind(DETAIL, &[KeyCode::Char('R')], Action::Restart, "Restart workflow (new run with the same input)")),
+    mutating(bind(DETAIL, &[KeyCode::Char('E')], Action::Reset, "Reset workflow to the workflow task at or before the selected event")),
+    bind(NAMESPACES, &[KeyCode::Enter], Action::Select, "Switch to selected namespace (view it with enter_views_namespace)"),
+    bind(NAMESPACES, &[KeyCode::Char('s')], Action::SwitchNamespace, "Switch to selected namespace"),
+    bind(NAMESPACES, &[KeyCode::Char('v')], Action::ViewNamespace, "View the selected namespace's details"),
+    bind(NAMESPACES, &[KeyCode::Char('n')], Action::NamespaceSwitcher, "Jump to namespace (fuzzy match)"),
+    bind(NAMESPACES, &[KeyCode::Char('f')], Action::CycleFilter, "Cycle state filter (All/Registered/Not Deleted)"),
+    bind(NAMESPACES, &[KeyCode::Char('r')], Action::Refresh, "Refresh namespace list"),
+    mutating(bind(NAMESPACES, &[KeyCode::Char('c')], Action::CreateNamespace, "Register a new namespace")),
+    mutating(bind(NAMESPACES, &[KeyCode::Char('u')], Action::UpdateNamespace, "Update description/retention of the selected namespace")),
+    bind(BATCHES, &[KeyCode::Char('f')], Action::CycleFilter, "Cycle state filter (All/Running/Completed/Failed)"),
+    bind(BATCHES, &[KeyCode::Char('r')], Action::Refresh, "Refresh batch operations"),
+    bind(NEXUS, &[KeyCode::Char('r')], Action::Refresh, "Refresh Nexus endpoints"),
+    bind(ALL_NAMESPACES, &[KeyCode::Enter], Action::Select, "View workflow details (switches to its namespace)"),
+    bind(ALL_NAMESPACES, &[KeyCode::Char('r')], Action::Refresh, "Re-run the query in every namespace"),
+];
+
+/// The action a key triggers on a screen, if any
+pub fn action_for(screen: Screen, key: KeyCode) -> Option<Action> {
+    let lookup = |scope: Option<Screen>| {
+        BINDINGS
+            .iter()
+            .find(|binding| binding.screen == scope && binding.keys.contains(&key))
+            .map(|binding| binding.action)
+    };
+    lookup(Some(screen)).or_else(|| lookup(None))
+}
+
+/// Display form of a binding's keys, e.g. "↓/j"
+pub fn key_label(keys: &[KeyCode]) -> String {
+    keys.iter()
+        .map(|key| match key {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::Enter => "Enter".to_string(),
+            KeyCode::Esc => "ESC".to_string(),
+            other => format!("{:?}", other),
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
@@ -0,0 +1,45 @@
+use crate::config::Config;
+use crate::temporal::client::GRPC_LOG_TARGET;
+use anyhow::{Context, Result};
+use std::fs::OpenOptions;
+use std::sync::Mutex;
+use tracing::Level;
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::prelude::*;
+
+/// Set up the tracing subscriber to append to `~/.tuiporal/tuiporal.log`.
+///
+/// Logs must never reach stdout/stderr: the TUI owns the terminal while the
+/// alternate screen is active and stray lines would smear across it. With gRPC
+/// debugging on, per-call request/response logs are included at debug level.
+pub fn init(level: Level, debug_grpc: bool) -> Result<()> {
+    let path = Config::get_log_path()?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open log file {:?}", path))?;
+
+    let mut filter = Targets::new().with_default(level);
+    if debug_grpc {
+        filter = filter.with_target(GRPC_LOG_TARGET, Level::DEBUG);
+    }
+
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(Mutex::new(file))
+                .with_ansi(false),
+        )
+        .with(filter)
+        .init();
+
+    tracing::info!("Logging at {} to {:?}", level, path);
+    if debug_grpc {
+        tracing::info!("gRPC debug logging enabled");
+    }
+    Ok(())
+}
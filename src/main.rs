@@ -1,35 +1,54 @@
 mod app;
+mod cli;
 mod config;
 mod events;
+mod fuzzy;
 mod generated;
+mod history;
+mod keymap;
+mod logging;
 mod temporal;
 mod ui;
 
 use anyhow::Result;
 use app::App;
+use clap::Parser;
+use cli::{Cli, Command};
+use config::Config;
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
-use tracing_subscriber;
+use std::time::Instant;
+use temporal::TemporalClient;
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let cli = Cli::parse();
+
     // Initialize logging
-    tracing_subscriber::fmt::init();
+    let debug_grpc = cli.debug_grpc || Config::load().map(|c| c.debug_grpc).unwrap_or(false);
+    logging::init(cli.log_level, debug_grpc)?;
+
+    // Health check for scripts and monitoring: no TUI, and the exit code tells the outcome
+    if let Some(Command::Check { profile }) = &cli.command {
+        return check_profile(profile.as_deref()).await;
+    }
+
+    // Create the app before taking over the terminal so startup errors print normally
+    let app = App::new(&cli).await?;
 
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture, EnableBracketedPaste)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    // Create app and run
-    let app = App::new().await?;
+    // Run
     let res = app.run(&mut terminal).await;
 
     // Restore terminal
@@ -37,7 +56,8 @@ async fn main() -> Result<()> {
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste
     )?;
     terminal.show_cursor()?;
 
@@ -47,3 +67,31 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+/// Connect with a profile (which runs the GetSystemInfo health check) and print how long it took.
+/// Errors are returned, so the process exits nonzero with the reason
+async fn check_profile(name: Option<&str>) -> Result<()> {
+    let config = Config::load()?;
+    let profile = match name {
+        Some(name) => config.profiles.iter().find(|p| p.name == name),
+        None => config.get_active_profile(),
+    };
+    let Some(profile) = profile else {
+        anyhow::bail!("No profile named '{}' in {}", name.unwrap_or_default(), Config::get_config_path()?.display());
+    };
+
+    // Connecting already calls GetSystemInfo, so this times the handshake plus one round trip
+    let started = Instant::now();
+    let client = TemporalClient::from_profile(profile).await?;
+    let connected = started.elapsed();
+
+    println!(
+        "OK {} ({}, namespace {}): server {}, connected and answered GetSystemInfo in {} ms",
+        profile.name,
+        profile.address,
+        profile.namespace,
+        client.system_info().server_version,
+        connected.as_millis()
+    );
+    Ok(())
+}
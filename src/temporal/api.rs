@@ -0,0 +1,313 @@
+use crate::config::ConnectionProfile;
+use crate::generated::temporal::api::history::v1::HistoryEvent;
+use crate::generated::temporal::api::operatorservice::v1::ListNexusEndpointsResponse;
+use crate::generated::temporal::api::workflowservice::v1::{
+    DescribeBatchOperationResponse, DescribeNamespaceResponse, DescribeTaskQueueResponse, DescribeWorkflowExecutionResponse,
+    GetSystemInfoResponse, GetWorkflowExecutionHistoryResponse, ListArchivedWorkflowExecutionsResponse,
+    ListBatchOperationsResponse, ListNamespacesResponse, ListWorkflowExecutionsResponse,
+    UpdateWorkflowExecutionResponse,
+};
+use crate::temporal::client::TemporalClient;
+use crate::temporal::time::to_datetime;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::future::Future;
+
+/// The Temporal calls the app's task handler makes. `TemporalClient` is the real
+/// implementation; tests drive the app with a fake one instead of a live server.
+pub trait TemporalApi: Clone + Send + Sync + 'static {
+    /// Connect with a profile, replacing the client after a reconnect
+    fn connect(profile: &ConnectionProfile) -> impl Future<Output = Result<Self>> + Send;
+
+    fn system_info(&self) -> &GetSystemInfoResponse;
+    fn namespace(&self) -> &str;
+    fn set_namespace(&mut self, namespace: String);
+
+    fn list_workflow_executions(
+        &mut self,
+        page_size: i32,
+        next_page_token: Vec<u8>,
+        query: String,
+    ) -> impl Future<Output = Result<ListWorkflowExecutionsResponse>> + Send;
+
+    fn list_archived_workflow_executions(
+        &mut self,
+        page_size: i32,
+        next_page_token: Vec<u8>,
+        query: String,
+    ) -> impl Future<Output = Result<ListArchivedWorkflowExecutionsResponse>> + Send;
+
+    fn count_workflow_executions(&mut self, query: String) -> impl Future<Output = Result<i64>> + Send;
+
+    fn count_workflow_executions_by_status(
+        &mut self,
+        query: String,
+    ) -> impl Future<Output = Result<Vec<(String, i64)>>> + Send;
+
+    fn describe_workflow_execution(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+    ) -> impl Future<Output = Result<DescribeWorkflowExecutionResponse>> + Send;
+
+    fn describe_task_queue(&mut self, task_queue: String) -> impl Future<Output = Result<DescribeTaskQueueResponse>> + Send;
+
+    fn last_event(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+    ) -> impl Future<Output = Result<Option<HistoryEvent>>> + Send;
+
+    /// Time of a run's most recent history event
+    fn last_event_time(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+    ) -> impl Future<Output = Result<Option<DateTime<Utc>>>> + Send {
+        async move {
+            let event = self.last_event(workflow_id, run_id).await?;
+            Ok(event.and_then(|event| event.event_time).and_then(|t| to_datetime(&t)))
+        }
+    }
+
+    fn get_workflow_execution_history(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+        page_size: i32,
+        next_page_token: Vec<u8>,
+    ) -> impl Future<Output = Result<GetWorkflowExecutionHistoryResponse>> + Send;
+
+    fn poll_workflow_history(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+        next_page_token: Vec<u8>,
+    ) -> impl Future<Output = Result<GetWorkflowExecutionHistoryResponse>> + Send;
+
+    fn list_namespaces(
+        &mut self,
+        page_size: i32,
+        next_page_token: Vec<u8>,
+    ) -> impl Future<Output = Result<ListNamespacesResponse>> + Send;
+
+    fn describe_namespace(&mut self, namespace: String) -> impl Future<Output = Result<DescribeNamespaceResponse>> + Send;
+
+    fn list_batch_operations(&mut self, page_size: i32) -> impl Future<Output = Result<ListBatchOperationsResponse>> + Send;
+
+    fn describe_batch_operation(
+        &mut self,
+        job_id: String,
+    ) -> impl Future<Output = Result<DescribeBatchOperationResponse>> + Send;
+
+    fn list_nexus_endpoints(&mut self, page_size: i32) -> impl Future<Output = Result<ListNexusEndpointsResponse>> + Send;
+
+    fn register_namespace(
+        &mut self,
+        name: String,
+        description: String,
+        retention_days: Option<u32>,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    fn update_namespace(
+        &mut self,
+        name: String,
+        description: String,
+        retention_days: Option<u32>,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    fn terminate_workflow(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+        reason: String,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    fn cancel_workflow(&mut self, workflow_id: String, run_id: String) -> impl Future<Output = Result<()>> + Send;
+
+    fn signal_workflow(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+        signal_name: String,
+        args: String,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    fn update_workflow(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+        update_name: String,
+        args: String,
+    ) -> impl Future<Output = Result<UpdateWorkflowExecutionResponse>> + Send;
+
+    /// Start a new run with the same type, task queue and input; returns the new run ID
+    fn restart_workflow(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+        terminate: bool,
+    ) -> impl Future<Output = Result<String>> + Send;
+
+    /// Reset to a workflow task completion event; returns the new run ID
+    fn reset_workflow(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+        workflow_task_finish_event_id: i64,
+        reason: String,
+        reapply_type: i32,
+    ) -> impl Future<Output = Result<String>> + Send;
+}
+
+// Each method forwards to the inherent one of the same name, which takes precedence
+impl TemporalApi for TemporalClient {
+    async fn connect(profile: &ConnectionProfile) -> Result<Self> {
+        TemporalClient::from_profile(profile).await
+    }
+
+    fn system_info(&self) -> &GetSystemInfoResponse {
+        self.system_info()
+    }
+
+    fn namespace(&self) -> &str {
+        self.namespace()
+    }
+
+    fn set_namespace(&mut self, namespace: String) {
+        self.set_namespace(namespace)
+    }
+
+    async fn list_workflow_executions(
+        &mut self,
+        page_size: i32,
+        next_page_token: Vec<u8>,
+        query: String,
+    ) -> Result<ListWorkflowExecutionsResponse> {
+        self.list_workflow_executions(page_size, next_page_token, query).await
+    }
+
+    async fn list_archived_workflow_executions(
+        &mut self,
+        page_size: i32,
+        next_page_token: Vec<u8>,
+        query: String,
+    ) -> Result<ListArchivedWorkflowExecutionsResponse> {
+        self.list_archived_workflow_executions(page_size, next_page_token, query).await
+    }
+
+    async fn count_workflow_executions(&mut self, query: String) -> Result<i64> {
+        self.count_workflow_executions(query).await
+    }
+
+    async fn count_workflow_executions_by_status(&mut self, query: String) -> Result<Vec<(String, i64)>> {
+        self.count_workflow_executions_by_status(query).await
+    }
+
+    async fn describe_workflow_execution(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+    ) -> Result<DescribeWorkflowExecutionResponse> {
+        self.describe_workflow_execution(workflow_id, run_id).await
+    }
+
+    async fn describe_task_queue(&mut self, task_queue: String) -> Result<DescribeTaskQueueResponse> {
+        self.describe_task_queue(task_queue).await
+    }
+
+    async fn last_event(&mut self, workflow_id: String, run_id: String) -> Result<Option<HistoryEvent>> {
+        self.last_event(workflow_id, run_id).await
+    }
+
+    async fn get_workflow_execution_history(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+        page_size: i32,
+        next_page_token: Vec<u8>,
+    ) -> Result<GetWorkflowExecutionHistoryResponse> {
+        self.get_workflow_execution_history(workflow_id, run_id, page_size, next_page_token).await
+    }
+
+    async fn poll_workflow_history(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+        next_page_token: Vec<u8>,
+    ) -> Result<GetWorkflowExecutionHistoryResponse> {
+        self.poll_workflow_history(workflow_id, run_id, next_page_token).await
+    }
+
+    async fn list_namespaces(&mut self, page_size: i32, next_page_token: Vec<u8>) -> Result<ListNamespacesResponse> {
+        self.list_namespaces(page_size, next_page_token).await
+    }
+
+    async fn describe_namespace(&mut self, namespace: String) -> Result<DescribeNamespaceResponse> {
+        self.describe_namespace(namespace).await
+    }
+
+    async fn list_batch_operations(&mut self, page_size: i32) -> Result<ListBatchOperationsResponse> {
+        self.list_batch_operations(page_size).await
+    }
+
+    async fn describe_batch_operation(&mut self, job_id: String) -> Result<DescribeBatchOperationResponse> {
+        self.describe_batch_operation(job_id).await
+    }
+
+    async fn list_nexus_endpoints(&mut self, page_size: i32) -> Result<ListNexusEndpointsResponse> {
+        self.list_nexus_endpoints(page_size).await
+    }
+
+    async fn register_namespace(&mut self, name: String, description: String, retention_days: Option<u32>) -> Result<()> {
+        self.register_namespace(name, description, retention_days).await
+    }
+
+    async fn update_namespace(&mut self, name: String, description: String, retention_days: Option<u32>) -> Result<()> {
+        self.update_namespace(name, description, retention_days).await
+    }
+
+    async fn terminate_workflow(&mut self, workflow_id: String, run_id: String, reason: String) -> Result<()> {
+        self.terminate_workflow(workflow_id, run_id, reason).await
+    }
+
+    async fn cancel_workflow(&mut self, workflow_id: String, run_id: String) -> Result<()> {
+        self.cancel_workflow(workflow_id, run_id).await
+    }
+
+    async fn signal_workflow(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+        signal_name: String,
+        args: String,
+    ) -> Result<()> {
+        self.signal_workflow(workflow_id, run_id, signal_name, args).await
+    }
+
+    async fn update_workflow(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+        update_name: String,
+        args: String,
+    ) -> Result<UpdateWorkflowExecutionResponse> {
+        self.update_workflow(workflow_id, run_id, update_name, args).await
+    }
+
+    async fn restart_workflow(&mut self, workflow_id: String, run_id: String, terminate: bool) -> Result<String> {
+        self.restart_workflow(workflow_id, run_id, terminate).await
+    }
+
+    async fn reset_workflow(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+        workflow_task_finish_event_id: i64,
+        reason: String,
+        reapply_type: i32,
+    ) -> Result<String> {
+        self.reset_workflow(workflow_id, run_id, workflow_task_finish_event_id, reason, reapply_type)
+            .await
+    }
+}
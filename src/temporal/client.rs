@@ -1,20 +1,53 @@
 use crate::config::{ConnectionProfile, TlsConfig};
+use crate::generated::temporal::api::operatorservice::v1::{
+    operator_service_client::OperatorServiceClient, ListNexusEndpointsRequest, ListNexusEndpointsResponse,
+};
 use crate::generated::temporal::api::workflowservice::v1::{
-    workflow_service_client::WorkflowServiceClient, GetSystemInfoRequest,
-    GetWorkflowExecutionHistoryRequest, ListNamespacesRequest, ListWorkflowExecutionsRequest,
+    workflow_service_client::WorkflowServiceClient, CountWorkflowExecutionsRequest, DescribeBatchOperationRequest,
+    DescribeBatchOperationResponse, DescribeTaskQueueRequest, DescribeTaskQueueResponse,
+    DescribeWorkflowExecutionRequest, DescribeWorkflowExecutionResponse,
+    GetSystemInfoRequest, ListBatchOperationsRequest, ListBatchOperationsResponse, GetSystemInfoResponse,
+    GetWorkflowExecutionHistoryRequest, GetWorkflowExecutionHistoryReverseRequest, ListArchivedWorkflowExecutionsRequest, ListArchivedWorkflowExecutionsResponse, ListNamespacesRequest, ListWorkflowExecutionsRequest, DescribeNamespaceRequest, DescribeNamespaceResponse,
+    RegisterNamespaceRequest, ResetWorkflowExecutionRequest, UpdateNamespaceRequest, StartWorkflowExecutionRequest,
     TerminateWorkflowExecutionRequest, RequestCancelWorkflowExecutionRequest,
-    SignalWorkflowExecutionRequest,
+    SignalWorkflowExecutionRequest, UpdateWorkflowExecutionRequest, UpdateWorkflowExecutionResponse,
 };
 use crate::generated::temporal::api::{common::v1::WorkflowExecution, enums::v1::HistoryEventFilterType};
+use crate::generated::temporal::api::enums::v1::{
+    TaskQueueKind, TaskQueueType, UpdateWorkflowExecutionLifecycleStage, WorkflowIdReusePolicy,
+};
+use crate::generated::temporal::api::history::v1::{history_event, HistoryEvent};
+use crate::generated::temporal::api::namespace::v1::{NamespaceConfig, UpdateNamespaceInfo};
+use crate::generated::temporal::api::taskqueue::v1::TaskQueue;
+use crate::generated::temporal::api::update::v1::{Input as UpdateInput, Meta as UpdateMeta, Request as UpdateRequest, WaitPolicy};
+use crate::temporal::payload::encode_json_payloads;
+use crate::temporal::{tls, unix};
 use anyhow::{Context, Result};
+use prost::Message;
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::{Duration, Instant};
 use tonic::transport::{Channel, ClientTlsConfig, Endpoint};
-use tonic::metadata::MetadataValue;
+use tonic::metadata::{Ascii, MetadataKey, MetadataValue};
+
+/// Tracing target for per-call request/response logs, enabled by `--debug-grpc`
+pub const GRPC_LOG_TARGET: &str = "tuiporal::grpc";
+
+/// Times a read is re-sent on the same channel after a transient error
+const TRANSIENT_RETRIES: u32 = 2;
 
-/// Temporal gRPC client wrapper
+/// Wait before the first retry, growing with each one
+const TRANSIENT_RETRY_DELAY: Duration = Duration::from_millis(250);
+
+/// Temporal gRPC client wrapper. Clones share the underlying connection.
+#[derive(Clone)]
 pub struct TemporalClient {
     client: WorkflowServiceClient<Channel>,
+    operator: OperatorServiceClient<Channel>, // Cluster-level calls, sharing the connection
     namespace: String,
     api_key: Option<String>,
+    headers: Vec<(MetadataKey<Ascii>, MetadataValue<Ascii>)>, // Profile headers, sent with every request
+    system_info: GetSystemInfoResponse,
 }
 
 impl TemporalClient {
@@ -25,6 +58,7 @@ impl TemporalClient {
             profile.namespace.clone(),
             profile.tls.as_ref(),
             profile.api_key.clone(),
+            &profile.headers,
         )
         .await
     }
@@ -35,12 +69,60 @@ impl TemporalClient {
         namespace: String,
         tls_config: Option<&TlsConfig>,
         api_key: Option<String>,
+        headers: &HashMap<String, String>,
     ) -> Result<Self> {
         tracing::info!("Connecting to Temporal at {} (namespace: {})", address, namespace);
 
-        // Determine if we should use TLS
+        let channel = match address.strip_prefix("unix://") {
+            Some(path) => Self::connect_unix(path, tls_config).await?,
+            None => Self::connect_tcp(&address, tls_config).await?,
+        };
+
+        // Create clients
+        let client = WorkflowServiceClient::new(channel.clone());
+        let operator = OperatorServiceClient::new(channel);
+
+        if api_key.is_some() {
+            tracing::info!("Using API key authentication");
+        }
+
+        let headers = headers
+            .iter()
+            .map(|(name, value)| {
+                let key = MetadataKey::from_bytes(name.as_bytes()).with_context(|| format!("Invalid header name '{}'", name))?;
+                let value = MetadataValue::try_from(value.as_str()).with_context(|| format!("Invalid value for header '{}'", name))?;
+                Ok((key, value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if !headers.is_empty() {
+            tracing::info!("Sending {} custom header(s) with every request", headers.len());
+        }
+
+        let mut temporal = Self {
+            client,
+            operator,
+            namespace,
+            api_key,
+            headers,
+            system_info: GetSystemInfoResponse::default(),
+        };
+
+        // Verify connection with a health check, sent with the same headers as every later request
+        temporal.get_system_info().await
+            .context("Health check failed - unable to connect to Temporal")?;
+
+        tracing::info!("Successfully connected to Temporal (server version: {})", temporal.system_info.server_version);
+
+        Ok(temporal)
+    }
+
+    /// Connect over TCP to a `host:port` address, with TLS when configured
+    async fn connect_tcp(address: &str, tls_config: Option<&TlsConfig>) -> Result<Channel> {
+        // Determine if we should use TLS. The insecure path does its own TLS
+        // underneath tonic, so tonic itself sees a plain connection.
         let use_tls = tls_config.map(|t| t.enabled).unwrap_or(false);
-        let scheme = if use_tls { "https" } else { "http" };
+        let insecure_tls = use_tls && tls_config.map(|t| t.insecure_skip_verify).unwrap_or(false);
+        let scheme = if use_tls && !insecure_tls { "https" } else { "http" };
 
         // Build the endpoint
         let mut endpoint = Endpoint::from_shared(format!("{}://{}", scheme, address))?
@@ -49,9 +131,15 @@ impl TemporalClient {
 
         // Configure TLS if enabled
         if let Some(tls) = tls_config {
-            if tls.enabled {
+            if tls.enabled && !tls.insecure_skip_verify {
                 let mut tls_config = ClientTlsConfig::new();
 
+                // Verify against a different name than the address host (internal hostnames)
+                if let Some(server_name) = &tls.server_name {
+                    tracing::info!("Using TLS server name: {}", server_name);
+                    tls_config = tls_config.domain_name(server_name.clone());
+                }
+
                 // Load client certificates if provided (mTLS)
                 if let (Some(cert_path), Some(key_path)) = (&tls.cert_path, &tls.key_path) {
                     tracing::info!("Configuring mTLS with cert: {:?}", cert_path);
@@ -78,57 +166,100 @@ impl TemporalClient {
         }
 
         // Connect to the server
-        let channel = endpoint.connect().await
-            .context("Failed to connect to Temporal server")?;
-
-        // Create client
-        let mut client = WorkflowServiceClient::new(channel);
+        let channel = match tls_config {
+            Some(tls) if insecure_tls => {
+                tracing::warn!("TLS certificate verification is DISABLED for {} (insecure_skip_verify)", address);
+                tls::connect_insecure(endpoint, address, tls).await
+                    .context("Failed to connect to Temporal server")?
+            }
+            _ => endpoint.connect().await
+                .context("Failed to connect to Temporal server")?,
+        };
 
-        if api_key.is_some() {
-            tracing::info!("Using API key authentication");
-        }
+        Ok(channel)
+    }
 
-        // Verify connection with a health check
-        let mut health_request = tonic::Request::new(GetSystemInfoRequest {});
-        if let Some(ref key) = api_key {
-            let key_value = format!("Bearer {}", key);
-            if let Ok(value) = MetadataValue::try_from(&key_value) {
-                health_request.metadata_mut().insert("authorization", value);
-            }
+    /// Connect to a `unix://` socket path. TLS settings don't apply to a local socket.
+    async fn connect_unix(path: &str, tls_config: Option<&TlsConfig>) -> Result<Channel> {
+        if tls_config.is_some_and(|t| t.enabled) {
+            tracing::warn!("Ignoring TLS settings for Unix socket {}", path);
         }
-        client.get_system_info(health_request).await
-            .context("Health check failed - unable to connect to Temporal")?;
 
-        tracing::info!("Successfully connected to Temporal");
+        // The URI only names the HTTP/2 authority; the connector dials the socket
+        let endpoint = Endpoint::from_static("http://localhost")
+            .timeout(std::time::Duration::from_secs(30))
+            .connect_timeout(std::time::Duration::from_secs(10));
+        let channel = unix::connect(endpoint, path)
+            .await
+            .context("Failed to connect to Temporal server over Unix socket")?;
 
-        Ok(Self {
-            client,
-            namespace,
-            api_key,
-        })
+        Ok(channel)
     }
 
-    /// Helper method to add API key to requests
-    fn add_api_key<T>(&self, mut request: tonic::Request<T>) -> tonic::Request<T> {
+    /// Attach the API key and the profile's custom headers to a request
+    fn add_headers<T>(&self, mut request: tonic::Request<T>) -> tonic::Request<T> {
         if let Some(ref key) = self.api_key {
             let key_value = format!("Bearer {}", key);
             if let Ok(value) = MetadataValue::try_from(&key_value) {
                 request.metadata_mut().insert("authorization", value);
             }
         }
+        for (key, value) in &self.headers {
+            request.metadata_mut().insert(key.clone(), value.clone());
+        }
         request
     }
 
+    /// Send an idempotent read, retrying it on the same channel a couple of times when the
+    /// server blips, so a transient error doesn't cost a full reconnect. Writes never go
+    /// through here: a retried terminate or signal could be applied twice.
+    async fn read<S, M, T, F, Fut>(&self, method: &str, service: &S, message: M, call: F) -> Result<tonic::Response<T>>
+    where
+        S: Clone,
+        M: Clone + std::fmt::Debug,
+        T: Message,
+        F: Fn(S, tonic::Request<M>) -> Fut,
+        Fut: Future<Output = Result<tonic::Response<T>, tonic::Status>>,
+    {
+        let mut attempt = 0;
+        loop {
+            let request = self.add_headers(tonic::Request::new(message.clone()));
+            let started = log_request(method, &request);
+            match call(service.clone(), request).await {
+                Err(status) if is_transient(status.code()) && attempt < TRANSIENT_RETRIES => {
+                    attempt += 1;
+                    tracing::warn!(
+                        "{} failed with {:?} ({}); retry {} of {}",
+                        method,
+                        status.code(),
+                        status.message(),
+                        attempt,
+                        TRANSIENT_RETRIES
+                    );
+                    tokio::time::sleep(TRANSIENT_RETRY_DELAY * attempt).await;
+                }
+                result => return log_response(method, started, result),
+            }
+        }
+    }
+
     /// Get system information (health check)
     pub async fn get_system_info(&mut self) -> Result<()> {
-        let request = self.add_api_key(tonic::Request::new(GetSystemInfoRequest {}));
-        let response = self.client.get_system_info(request).await?;
+        let request = self.add_headers(tonic::Request::new(GetSystemInfoRequest {}));
+        let started = log_request("GetSystemInfo", &request);
+        let response = log_response("GetSystemInfo", started, self.client.get_system_info(request).await)?;
         let info = response.into_inner();
 
         tracing::debug!("Server version: {:?}", info.server_version);
+        self.system_info = info;
         Ok(())
     }
 
+    /// Server version and capabilities reported by the last health check
+    pub fn system_info(&self) -> &GetSystemInfoResponse {
+        &self.system_info
+    }
+
     /// List workflow executions in the current namespace
     pub async fn list_workflow_executions(
         &mut self,
@@ -137,17 +268,153 @@ impl TemporalClient {
         query: String,
     ) -> Result<crate::generated::temporal::api::workflowservice::v1::ListWorkflowExecutionsResponse>
     {
-        let request = self.add_api_key(tonic::Request::new(ListWorkflowExecutionsRequest {
+        let request = ListWorkflowExecutionsRequest {
             namespace: self.namespace.clone(),
             page_size,
             next_page_token,
             query,
-        }));
+        };
+        let response = self
+            .read("ListWorkflowExecutions", &self.client, request, |mut client, request| async move {
+                client.list_workflow_executions(request).await
+            })
+            .await?;
+        Ok(response.into_inner())
+    }
+
+    /// List closed executions from the namespace's archival visibility store. The query
+    /// syntax depends on the archiver, and archived runs are never counted or ordered.
+    pub async fn list_archived_workflow_executions(
+        &mut self,
+        page_size: i32,
+        next_page_token: Vec<u8>,
+        query: String,
+    ) -> Result<ListArchivedWorkflowExecutionsResponse> {
+        let request = ListArchivedWorkflowExecutionsRequest {
+            namespace: self.namespace.clone(),
+            page_size,
+            next_page_token,
+            query,
+        };
+        let response = self
+            .read("ListArchivedWorkflowExecutions", &self.client, request, |mut client, request| async move {
+                client.list_archived_workflow_executions(request).await
+            })
+            .await?;
+        Ok(response.into_inner())
+    }
 
-        let response = self.client.list_workflow_executions(request).await?;
+    /// Count workflow executions matching a visibility query
+    pub async fn count_workflow_executions(&mut self, query: String) -> Result<i64> {
+        let request = CountWorkflowExecutionsRequest {
+            namespace: self.namespace.clone(),
+            query,
+        };
+        let response = self
+            .read("CountWorkflowExecutions", &self.client, request, |mut client, request| async move {
+                client.count_workflow_executions(request).await
+            })
+            .await?;
+        Ok(response.into_inner().count)
+    }
+
+    /// Count workflow executions matching a query per `ExecutionStatus` value, e.g. ("Running", 12)
+    pub async fn count_workflow_executions_by_status(&mut self, query: String) -> Result<Vec<(String, i64)>> {
+        let query = format!("{} GROUP BY ExecutionStatus", query).trim_start().to_string();
+        let request = CountWorkflowExecutionsRequest {
+            namespace: self.namespace.clone(),
+            query,
+        };
+        let response = self
+            .read("CountWorkflowExecutions", &self.client, request, |mut client, request| async move {
+                client.count_workflow_executions(request).await
+            })
+            .await?;
+        let counts = response
+            .into_inner()
+            .groups
+            .into_iter()
+            .map(|group| {
+                // Group values are JSON-encoded payloads such as "Running"
+                let status = group
+                    .group_values
+                    .first()
+                    .map(|payload| {
+                        serde_json::from_slice::<String>(&payload.data)
+                            .unwrap_or_else(|_| String::from_utf8_lossy(&payload.data).into_owned())
+                    })
+                    .unwrap_or_default();
+                (status, group.count)
+            })
+            .collect();
+        Ok(counts)
+    }
+
+    /// Describe a workflow execution; an empty run ID resolves to the latest run
+    pub async fn describe_workflow_execution(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+    ) -> Result<DescribeWorkflowExecutionResponse> {
+        let request = DescribeWorkflowExecutionRequest {
+            namespace: self.namespace.clone(),
+            execution: Some(WorkflowExecution {
+                workflow_id,
+                run_id,
+            }),
+        };
+        let response = self
+            .read("DescribeWorkflowExecution", &self.client, request, |mut client, request| async move {
+                client.describe_workflow_execution(request).await
+            })
+            .await?;
         Ok(response.into_inner())
     }
 
+    /// Pollers and backlog statistics of a workflow task queue. Servers that predate
+    /// task queue stats leave `stats` unset
+    pub async fn describe_task_queue(&mut self, task_queue: String) -> Result<DescribeTaskQueueResponse> {
+        let request = DescribeTaskQueueRequest {
+            namespace: self.namespace.clone(),
+            task_queue: Some(TaskQueue {
+                name: task_queue,
+                kind: TaskQueueKind::Normal as i32,
+                ..Default::default()
+            }),
+            task_queue_type: TaskQueueType::Workflow as i32,
+            report_stats: true,
+            ..Default::default()
+        };
+        let response = self
+            .read("DescribeTaskQueue", &self.client, request, |mut client, request| async move {
+                client.describe_task_queue(request).await
+            })
+            .await?;
+        Ok(response.into_inner())
+    }
+
+    /// A run's most recent history event, read as a one-event page of reverse history
+    pub async fn last_event(&mut self, workflow_id: String, run_id: String) -> Result<Option<HistoryEvent>> {
+        let request = GetWorkflowExecutionHistoryReverseRequest {
+            namespace: self.namespace.clone(),
+            execution: Some(WorkflowExecution {
+                workflow_id,
+                run_id,
+            }),
+            maximum_page_size: 1,
+            next_page_token: vec![],
+        };
+        let response = self
+            .read("GetWorkflowExecutionHistoryReverse", &self.client, request, |mut client, request| async move {
+                client.get_workflow_execution_history_reverse(request).await
+            })
+            .await?;
+        Ok(response
+            .into_inner()
+            .history
+            .and_then(|history| history.events.into_iter().next()))
+    }
+
     /// Get workflow execution history
     pub async fn get_workflow_execution_history(
         &mut self,
@@ -157,7 +424,7 @@ impl TemporalClient {
         next_page_token: Vec<u8>,
     ) -> Result<crate::generated::temporal::api::workflowservice::v1::GetWorkflowExecutionHistoryResponse>
     {
-        let request = self.add_api_key(tonic::Request::new(GetWorkflowExecutionHistoryRequest {
+        let request = GetWorkflowExecutionHistoryRequest {
             namespace: self.namespace.clone(),
             execution: Some(WorkflowExecution {
                 workflow_id,
@@ -168,9 +435,39 @@ impl TemporalClient {
             wait_new_event: false,
             history_event_filter_type: HistoryEventFilterType::AllEvent as i32,
             skip_archival: false,
+        };
+        let response = self
+            .read("GetWorkflowExecutionHistory", &self.client, request, |mut client, request| async move {
+                client.get_workflow_execution_history(request).await
+            })
+            .await?;
+        Ok(response.into_inner())
+    }
+
+    /// Long-poll for history after `next_page_token`: returns as soon as events past it exist,
+    /// or empty when the server's poll expires. An empty token in the response means the run closed.
+    pub async fn poll_workflow_history(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+        next_page_token: Vec<u8>,
+    ) -> Result<crate::generated::temporal::api::workflowservice::v1::GetWorkflowExecutionHistoryResponse>
+    {
+        let request = self.add_headers(tonic::Request::new(GetWorkflowExecutionHistoryRequest {
+            namespace: self.namespace.clone(),
+            execution: Some(WorkflowExecution {
+                workflow_id,
+                run_id,
+            }),
+            maximum_page_size: 1000,
+            next_page_token,
+            wait_new_event: true,
+            history_event_filter_type: HistoryEventFilterType::AllEvent as i32,
+            skip_archival: true,
         }));
 
-        let response = self.client.get_workflow_execution_history(request).await?;
+        let started = log_request("GetWorkflowExecutionHistory", &request);
+        let response = log_response("GetWorkflowExecutionHistory", started, self.client.get_workflow_execution_history(request).await)?;
         Ok(response.into_inner())
     }
 
@@ -180,16 +477,120 @@ impl TemporalClient {
         page_size: i32,
         next_page_token: Vec<u8>,
     ) -> Result<crate::generated::temporal::api::workflowservice::v1::ListNamespacesResponse> {
-        let request = self.add_api_key(tonic::Request::new(ListNamespacesRequest {
+        let request = ListNamespacesRequest {
             page_size,
             next_page_token,
             ..Default::default()
-        }));
+        };
+        let response = self
+            .read("ListNamespaces", &self.client, request, |mut client, request| async move {
+                client.list_namespaces(request).await
+            })
+            .await?;
+        Ok(response.into_inner())
+    }
 
-        let response = self.client.list_namespaces(request).await?;
+    /// Look up a namespace by name, e.g. to check it exists and is readable before switching to it
+    pub async fn describe_namespace(&mut self, namespace: String) -> Result<DescribeNamespaceResponse> {
+        let request = DescribeNamespaceRequest {
+            namespace,
+            ..Default::default()
+        };
+        let response = self
+            .read("DescribeNamespace", &self.client, request, |mut client, request| async move {
+                client.describe_namespace(request).await
+            })
+            .await?;
+        Ok(response.into_inner())
+    }
+
+    /// List batch operations in the current namespace, most recent first
+    pub async fn list_batch_operations(&mut self, page_size: i32) -> Result<ListBatchOperationsResponse> {
+        let request = ListBatchOperationsRequest {
+            namespace: self.namespace.clone(),
+            page_size,
+            next_page_token: vec![],
+        };
+        let response = self
+            .read("ListBatchOperations", &self.client, request, |mut client, request| async move {
+                client.list_batch_operations(request).await
+            })
+            .await?;
+        Ok(response.into_inner())
+    }
+
+    /// Describe a batch operation, including its type, reason and progress counts
+    pub async fn describe_batch_operation(&mut self, job_id: String) -> Result<DescribeBatchOperationResponse> {
+        let request = DescribeBatchOperationRequest {
+            namespace: self.namespace.clone(),
+            job_id,
+        };
+        let response = self
+            .read("DescribeBatchOperation", &self.client, request, |mut client, request| async move {
+                client.describe_batch_operation(request).await
+            })
+            .await?;
         Ok(response.into_inner())
     }
 
+    /// List the cluster's Nexus endpoints. Only servers reporting the Nexus capability serve this
+    pub async fn list_nexus_endpoints(&mut self, page_size: i32) -> Result<ListNexusEndpointsResponse> {
+        let request = ListNexusEndpointsRequest {
+            page_size,
+            ..Default::default()
+        };
+        let response = self
+            .read("ListNexusEndpoints", &self.operator, request, |mut operator, request| async move {
+                operator.list_nexus_endpoints(request).await
+            })
+            .await?;
+        Ok(response.into_inner())
+    }
+
+    /// Register a new namespace
+    pub async fn register_namespace(
+        &mut self,
+        name: String,
+        description: String,
+        retention_days: Option<u32>,
+    ) -> Result<()> {
+        let request = self.add_headers(tonic::Request::new(RegisterNamespaceRequest {
+            namespace: name,
+            description,
+            workflow_execution_retention_period: retention_days.map(retention_duration),
+            ..Default::default()
+        }));
+
+        let started = log_request("RegisterNamespace", &request);
+        log_response("RegisterNamespace", started, self.client.register_namespace(request).await)?;
+        Ok(())
+    }
+
+    /// Update a namespace's description and/or retention; empty values are left unchanged
+    pub async fn update_namespace(
+        &mut self,
+        name: String,
+        description: String,
+        retention_days: Option<u32>,
+    ) -> Result<()> {
+        let request = self.add_headers(tonic::Request::new(UpdateNamespaceRequest {
+            namespace: name,
+            update_info: Some(UpdateNamespaceInfo {
+                description,
+                ..Default::default()
+            }),
+            config: retention_days.map(|days| NamespaceConfig {
+                workflow_execution_retention_ttl: Some(retention_duration(days)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }));
+
+        let started = log_request("UpdateNamespace", &request);
+        log_response("UpdateNamespace", started, self.client.update_namespace(request).await)?;
+        Ok(())
+    }
+
     /// Get the current namespace
     pub fn namespace(&self) -> &str {
         &self.namespace
@@ -207,7 +608,7 @@ impl TemporalClient {
         run_id: String,
         reason: String,
     ) -> Result<()> {
-        let request = self.add_api_key(tonic::Request::new(TerminateWorkflowExecutionRequest {
+        let request = self.add_headers(tonic::Request::new(TerminateWorkflowExecutionRequest {
             namespace: self.namespace.clone(),
             workflow_execution: Some(WorkflowExecution {
                 workflow_id,
@@ -217,13 +618,14 @@ impl TemporalClient {
             ..Default::default()
         }));
 
-        self.client.terminate_workflow_execution(request).await?;
+        let started = log_request("TerminateWorkflowExecution", &request);
+        log_response("TerminateWorkflowExecution", started, self.client.terminate_workflow_execution(request).await)?;
         Ok(())
     }
 
     /// Request cancellation of a workflow execution
     pub async fn cancel_workflow(&mut self, workflow_id: String, run_id: String) -> Result<()> {
-        let request = self.add_api_key(tonic::Request::new(RequestCancelWorkflowExecutionRequest {
+        let request = self.add_headers(tonic::Request::new(RequestCancelWorkflowExecutionRequest {
             namespace: self.namespace.clone(),
             workflow_execution: Some(WorkflowExecution {
                 workflow_id,
@@ -232,9 +634,8 @@ impl TemporalClient {
             ..Default::default()
         }));
 
-        self.client
-            .request_cancel_workflow_execution(request)
-            .await?;
+        let started = log_request("RequestCancelWorkflowExecution", &request);
+        log_response("RequestCancelWorkflowExecution", started, self.client.request_cancel_workflow_execution(request).await)?;
         Ok(())
     }
 
@@ -244,18 +645,206 @@ impl TemporalClient {
         workflow_id: String,
         run_id: String,
         signal_name: String,
+        args: String,
     ) -> Result<()> {
-        let request = self.add_api_key(tonic::Request::new(SignalWorkflowExecutionRequest {
+        let input = encode_json_payloads(&args)?;
+        let request = self.add_headers(tonic::Request::new(SignalWorkflowExecutionRequest {
             namespace: self.namespace.clone(),
             workflow_execution: Some(WorkflowExecution {
                 workflow_id,
                 run_id,
             }),
             signal_name,
+            input,
             ..Default::default()
         }));
 
-        self.client.signal_workflow_execution(request).await?;
+        let started = log_request("SignalWorkflowExecution", &request);
+        log_response("SignalWorkflowExecution", started, self.client.signal_workflow_execution(request).await)?;
         Ok(())
     }
+
+    /// Start a new run of a workflow with the type, task queue and input of the given run,
+    /// optionally terminating that run first. Returns the new run ID.
+    pub async fn restart_workflow(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+        terminate: bool,
+    ) -> Result<String> {
+        // The first history event carries everything the run was started with
+        let history = self
+            .get_workflow_execution_history(workflow_id.clone(), run_id.clone(), 1, vec![])
+            .await?;
+        let started = match history.history.and_then(|h| h.events.into_iter().next()).and_then(|e| e.attributes) {
+            Some(history_event::Attributes::WorkflowExecutionStartedEventAttributes(attrs)) => attrs,
+            _ => anyhow::bail!("No WorkflowExecutionStarted event found for {}", workflow_id),
+        };
+
+        if terminate {
+            self.terminate_workflow(workflow_id.clone(), run_id, "Restarted by user".to_string())
+                .await
+                .context("Failed to terminate the current run")?;
+        }
+
+        let request_id = format!(
+            "tuiporal-restart-{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_nanos())
+                .unwrap_or_default()
+        );
+
+        let request = self.add_headers(tonic::Request::new(StartWorkflowExecutionRequest {
+            namespace: self.namespace.clone(),
+            workflow_id,
+            workflow_type: started.workflow_type,
+            task_queue: started.task_queue,
+            input: started.input,
+            workflow_execution_timeout: started.workflow_execution_timeout,
+            workflow_run_timeout: started.workflow_run_timeout,
+            workflow_task_timeout: started.workflow_task_timeout,
+            identity: "tuiporal".to_string(),
+            request_id,
+            workflow_id_reuse_policy: WorkflowIdReusePolicy::AllowDuplicate as i32,
+            retry_policy: started.retry_policy,
+            memo: started.memo,
+            search_attributes: started.search_attributes,
+            header: started.header,
+            ..Default::default()
+        }));
+
+        let started = log_request("StartWorkflowExecution", &request);
+        let response = log_response("StartWorkflowExecution", started, self.client.start_workflow_execution(request).await)?;
+        Ok(response.into_inner().run_id)
+    }
+
+    /// Reset a run to the point after a completed workflow task, reapplying later events
+    /// per `reapply_type` (a `ResetReapplyType`). Returns the new run ID.
+    pub async fn reset_workflow(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+        workflow_task_finish_event_id: i64,
+        reason: String,
+        reapply_type: i32,
+    ) -> Result<String> {
+        let request_id = format!(
+            "tuiporal-reset-{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_nanos())
+                .unwrap_or_default()
+        );
+
+        let request = self.add_headers(tonic::Request::new(ResetWorkflowExecutionRequest {
+            namespace: self.namespace.clone(),
+            workflow_execution: Some(WorkflowExecution {
+                workflow_id,
+                run_id,
+            }),
+            reason,
+            workflow_task_finish_event_id,
+            request_id,
+            reset_reapply_type: reapply_type,
+            identity: "tuiporal".to_string(),
+            ..Default::default()
+        }));
+
+        let started = log_request("ResetWorkflowExecution", &request);
+        let response = log_response("ResetWorkflowExecution", started, self.client.reset_workflow_execution(request).await)?;
+        Ok(response.into_inner().run_id)
+    }
+
+    /// Invoke an update handler on a workflow and wait for its outcome
+    pub async fn update_workflow(
+        &mut self,
+        workflow_id: String,
+        run_id: String,
+        update_name: String,
+        args: String,
+    ) -> Result<UpdateWorkflowExecutionResponse> {
+        let args = encode_json_payloads(&args)?;
+
+        // Update IDs only need to be unique per workflow; a timestamp is sufficient here
+        let update_id = format!(
+            "tuiporal-{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_nanos())
+                .unwrap_or_default()
+        );
+
+        let request = self.add_headers(tonic::Request::new(UpdateWorkflowExecutionRequest {
+            namespace: self.namespace.clone(),
+            workflow_execution: Some(WorkflowExecution {
+                workflow_id,
+                run_id,
+            }),
+            wait_policy: Some(WaitPolicy {
+                lifecycle_stage: UpdateWorkflowExecutionLifecycleStage::Completed as i32,
+            }),
+            request: Some(UpdateRequest {
+                meta: Some(UpdateMeta {
+                    update_id,
+                    identity: "tuiporal".to_string(),
+                }),
+                input: Some(UpdateInput {
+                    name: update_name,
+                    args,
+                    ..Default::default()
+                }),
+            }),
+            ..Default::default()
+        }));
+
+        let started = log_request("UpdateWorkflowExecution", &request);
+        let response = log_response("UpdateWorkflowExecution", started, self.client.update_workflow_execution(request).await)?;
+        Ok(response.into_inner())
+    }
+}
+
+/// Status codes worth re-sending the same request for: the server or a proxy was briefly unreachable or slow
+fn is_transient(code: tonic::Code) -> bool {
+    matches!(code, tonic::Code::Unavailable | tonic::Code::DeadlineExceeded)
+}
+
+/// Log an outgoing request's fields (never its metadata, which carries the API key)
+fn log_request<T: std::fmt::Debug>(method: &str, request: &tonic::Request<T>) -> Instant {
+    tracing::debug!(target: GRPC_LOG_TARGET, "-> {} {:?}", method, request.get_ref());
+    Instant::now()
+}
+
+/// Log the status and size of a call's response, passing it through unchanged
+fn log_response<T: Message>(
+    method: &str,
+    started: Instant,
+    result: Result<tonic::Response<T>, tonic::Status>,
+) -> Result<tonic::Response<T>> {
+    match &result {
+        Ok(response) => tracing::debug!(
+            target: GRPC_LOG_TARGET,
+            "<- {} OK ({} bytes) in {:?}",
+            method,
+            response.get_ref().encoded_len(),
+            started.elapsed()
+        ),
+        Err(status) => tracing::debug!(
+            target: GRPC_LOG_TARGET,
+            "<- {} {:?}: {} in {:?}",
+            method,
+            status.code(),
+            status.message(),
+            started.elapsed()
+        ),
+    }
+    Ok(result?)
+}
+
+/// Workflow execution retention expressed as a protobuf duration
+fn retention_duration(days: u32) -> prost_types::Duration {
+    prost_types::Duration {
+        seconds: i64::from(days) * 24 * 60 * 60,
+        nanos: 0,
+    }
 }
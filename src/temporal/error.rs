@@ -0,0 +1,149 @@
+use std::fmt;
+use tonic::Code;
+
+/// A failed request or rejected input, as results carry it back to the UI.
+///
+/// Keeps the gRPC status code so the app can tell an outage from a bad credential
+/// or a missing resource, instead of matching on message text.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AppError {
+    /// The server answered with a gRPC status
+    Status {
+        operation: String, // What was attempted, e.g. "Failed to load workflows"
+        code: Code,
+        message: String, // The status message as the server sent it
+    },
+    /// Anything else: transport setup, decoding, or input rejected before sending
+    Other { operation: String, message: String },
+}
+
+/// How the UI and recovery logic treat an error
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ErrorKind {
+    Unavailable, // Server unreachable; worth reconnecting or retrying
+    Auth, // Credentials rejected or lacking access
+    NotFound, // The namespace, workflow or run doesn't exist (any more)
+    Invalid, // The request itself was rejected, e.g. a bad query
+    Other,
+}
+
+impl AppError {
+    /// Capture a client error, keeping its gRPC status when there is one
+    pub fn from_error(operation: &str, error: &anyhow::Error) -> Self {
+        match grpc_status(error) {
+            Some(status) => AppError::Status {
+                operation: operation.to_string(),
+                code: status.code(),
+                message: status.message().to_string(),
+            },
+            None => AppError::Other {
+                operation: operation.to_string(),
+                message: format!("{:#}", error),
+            },
+        }
+    }
+
+    /// An error raised by the app itself, shown as-is
+    pub fn message(message: impl Into<String>) -> Self {
+        AppError::Other {
+            operation: message.into(),
+            message: String::new(),
+        }
+    }
+
+    pub fn code(&self) -> Option<Code> {
+        match self {
+            AppError::Status { code, .. } => Some(*code),
+            AppError::Other { .. } => None,
+        }
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        match self.code() {
+            Some(Code::Unavailable | Code::DeadlineExceeded) => ErrorKind::Unavailable,
+            Some(Code::Unauthenticated | Code::PermissionDenied) => ErrorKind::Auth,
+            Some(Code::NotFound) => ErrorKind::NotFound,
+            Some(Code::InvalidArgument | Code::FailedPrecondition | Code::Unimplemented) => ErrorKind::Invalid,
+            _ => ErrorKind::Other,
+        }
+    }
+
+    /// Whether the server could not be reached, so the connection should be re-established
+    pub fn is_connection_lost(&self) -> bool {
+        self.code() == Some(Code::Unavailable)
+    }
+
+    /// Whether a request failed because the namespace is gone or off-limits,
+    /// e.g. it was deleted while being viewed
+    pub fn is_namespace_unavailable(&self) -> bool {
+        matches!(self.code(), Some(Code::NotFound | Code::PermissionDenied))
+    }
+
+    /// The first line: a short, actionable summary
+    pub fn summary(&self) -> String {
+        match self {
+            AppError::Status { operation, code, message } => {
+                format!("{}: {}", operation, status_hint(*code, message))
+            }
+            AppError::Other { operation, message } if message.is_empty() => operation.clone(),
+            AppError::Other { operation, message } => format!("{}: {}", operation, message),
+        }
+    }
+
+    /// For a gRPC status, the raw code and message, which the UI offers behind an expand toggle
+    pub fn details(&self) -> Vec<String> {
+        match self {
+            AppError::Status { code, message, .. } => {
+                vec![format!("code: {:?}", code), format!("message: {}", message)]
+            }
+            AppError::Other { .. } => Vec::new(),
+        }
+    }
+}
+
+/// The summary, then the details on following lines
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.summary())?;
+        for line in self.details() {
+            write!(f, "\n{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+/// Build a user-facing error message from a client error, for results that only carry text
+pub fn describe_error(context: &str, error: &anyhow::Error) -> String {
+    AppError::from_error(context, error).to_string()
+}
+
+fn grpc_status(error: &anyhow::Error) -> Option<&tonic::Status> {
+    error.chain().find_map(|cause| cause.downcast_ref::<tonic::Status>())
+}
+
+/// Whether a list was rejected for its `ORDER BY` clause, which only some visibility stores support
+pub fn order_by_rejected(error: &anyhow::Error) -> bool {
+    grpc_status(error).is_some_and(|status| {
+        matches!(status.code(), Code::InvalidArgument | Code::Unimplemented)
+            && status.message().to_lowercase().contains("order by")
+    })
+}
+
+/// Map common gRPC status codes to guidance the user can act on
+fn status_hint(code: Code, message: &str) -> String {
+    match code {
+        Code::Unauthenticated => "Authentication failed - check your API key".to_string(),
+        Code::PermissionDenied => {
+            "Permission denied - your identity lacks access to this namespace".to_string()
+        }
+        Code::Unavailable => {
+            "Server unreachable - check the address and network, then press 'r' to retry".to_string()
+        }
+        Code::DeadlineExceeded => "Request timed out - the server may be overloaded".to_string(),
+        Code::NotFound => format!("Not found - {}", message),
+        Code::InvalidArgument => format!("Invalid request - {}", message),
+        Code::ResourceExhausted => "Rate limited by the server - try again shortly".to_string(),
+        Code::Unimplemented => "Not supported by this server version".to_string(),
+        _ => message.to_string(),
+    }
+}
@@ -1,7 +1,13 @@
 // Temporal client integration module
 // This will be expanded in Phase 2
 
+pub mod api;
 pub mod client;
+pub mod error;
+pub mod payload;
+pub mod time;
+pub mod tls;
+pub mod unix;
 
 // Unused for now, will be used in Phase 2
 #[allow(unused_imports)]
@@ -0,0 +1,121 @@
+use crate::generated::temporal::api::common::v1::{Payload, Payloads};
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+
+/// Encode user-supplied JSON text as a single `json/plain` payload.
+/// Empty input yields no payloads at all.
+pub fn encode_json_payloads(json: &str) -> Result<Option<Payloads>> {
+    let json = json.trim();
+    if json.is_empty() {
+        return Ok(None);
+    }
+
+    // Validate before sending so the user gets a parse error instead of a worker failure
+    serde_json::from_str::<serde_json::Value>(json).context("Arguments are not valid JSON")?;
+
+    let mut metadata = HashMap::new();
+    metadata.insert("encoding".to_string(), b"json/plain".to_vec());
+
+    Ok(Some(Payloads {
+        payloads: vec![Payload {
+            metadata,
+            data: json.as_bytes().to_vec(),
+            ..Default::default()
+        }],
+    }))
+}
+
+/// Render a payload as display text, decoding JSON/plain-text encodings
+pub fn format_payload(payload: &Payload) -> String {
+    let encoding = payload
+        .metadata
+        .get("encoding")
+        .map(|e| String::from_utf8_lossy(e).to_string())
+        .unwrap_or_default();
+
+    match encoding.as_str() {
+        "json/plain" | "" => String::from_utf8_lossy(&payload.data).to_string(),
+        "binary/null" => "null".to_string(),
+        other => format!("<{} payload, {} bytes>", other, payload.data.len()),
+    }
+}
+
+/// Render a search attribute value, unquoting plain JSON strings
+pub fn format_search_attribute(payload: &Payload) -> String {
+    let text = format_payload(payload);
+    match serde_json::from_str::<serde_json::Value>(&text) {
+        Ok(serde_json::Value::String(value)) => value,
+        Ok(serde_json::Value::Array(values)) => values
+            .iter()
+            .map(|v| v.as_str().map(str::to_string).unwrap_or_else(|| v.to_string()))
+            .collect::<Vec<_>>()
+            .join(", "),
+        _ => text,
+    }
+}
+
+/// Render all payloads, one per line
+pub fn format_payloads(payloads: &Payloads) -> String {
+    payloads
+        .payloads
+        .iter()
+        .map(format_payload)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Payloads as display lines, JSON pretty-printed so runs can be compared line by line.
+/// Multiple arguments are each introduced by an `# arg N` line.
+pub fn payload_lines(payloads: Option<&Payloads>) -> Vec<String> {
+    let payloads = match payloads {
+        Some(payloads) if !payloads.payloads.is_empty() => &payloads.payloads,
+        _ => return vec!["(no input)".to_string()],
+    };
+    let mut lines = Vec::new();
+    for (index, payload) in payloads.iter().enumerate() {
+        if payloads.len() > 1 {
+            lines.push(format!("# arg {}", index + 1));
+        }
+        let text = format_payload(payload);
+        let text = serde_json::from_str::<serde_json::Value>(&text)
+            .ok()
+            .and_then(|value| serde_json::to_string_pretty(&value).ok())
+            .unwrap_or(text);
+        lines.extend(text.lines().map(str::to_string));
+    }
+    lines
+}
+
+/// Replace the `[104, 105, ...]` byte lists in a prost `Debug` dump with the
+/// (lossily) decoded text so payload contents become readable and searchable
+pub fn decode_debug_bytes(debug: &str) -> String {
+    let mut out = String::with_capacity(debug.len());
+    let mut rest = debug;
+
+    while let Some(start) = rest.find('[') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let decoded = after.find(']').and_then(|end| {
+            let bytes = after[..end]
+                .split(',')
+                .map(|b| b.trim().parse::<u8>())
+                .collect::<Result<Vec<u8>, _>>()
+                .ok()?;
+            Some((end, bytes))
+        });
+
+        match decoded {
+            Some((end, bytes)) => {
+                out.push_str(&format!("{:?}", String::from_utf8_lossy(&bytes)));
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push('[');
+                rest = after;
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use prost_types::Timestamp;
+
+/// Latest time shown, 9999-12-31 23:59:59 UTC; anything later is treated as corrupt
+const MAX_SECONDS: i64 = 253_402_300_799;
+
+/// Convert a protobuf timestamp, or None when it can't be a real Temporal time.
+///
+/// Zero and negative seconds are rejected too: the server leaves unset times at the
+/// epoch, and nothing in a Temporal history predates 1970.
+pub fn to_datetime(timestamp: &Timestamp) -> Option<DateTime<Utc>> {
+    if !(1..=MAX_SECONDS).contains(&timestamp.seconds) || !(0..1_000_000_000).contains(&timestamp.nanos) {
+        return None;
+    }
+    DateTime::from_timestamp(timestamp.seconds, timestamp.nanos as u32)
+}
+
+/// A timestamp as "2024-01-02 15:04:05 UTC", or `fallback` when missing or out of range
+pub fn format_timestamp(timestamp: Option<&Timestamp>, fallback: &str) -> String {
+    format_with(timestamp, "%Y-%m-%d %H:%M:%S UTC", fallback)
+}
+
+/// As `format_timestamp` without the zone suffix, for narrow table columns
+pub fn format_timestamp_short(timestamp: Option<&Timestamp>, fallback: &str) -> String {
+    format_with(timestamp, "%Y-%m-%d %H:%M:%S", fallback)
+}
+
+fn format_with(timestamp: Option<&Timestamp>, pattern: &str, fallback: &str) -> String {
+    match timestamp.and_then(to_datetime) {
+        Some(time) => time.format(pattern).to_string(),
+        None => fallback.to_string(),
+    }
+}
@@ -0,0 +1,102 @@
+use crate::config::TlsConfig;
+use anyhow::{Context, Result};
+use hyper_util::rt::TokioIo;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::{ring, verify_tls12_signature, verify_tls13_signature, CryptoProvider};
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
+use rustls::{ClientConfig, DigitallySignedStruct, SignatureScheme};
+use std::sync::Arc;
+use tokio::net::TcpStream;
+use tokio_rustls::TlsConnector;
+use tonic::transport::{Channel, Endpoint, Uri};
+
+/// Connect over TLS without verifying the server certificate.
+///
+/// tonic's built-in TLS always verifies, so this drives rustls directly and
+/// hands the encrypted stream to tonic as a plain connection. Only meant for
+/// development servers with self-signed certificates.
+pub async fn connect_insecure(endpoint: Endpoint, address: &str, tls: &TlsConfig) -> Result<Channel> {
+    let provider = Arc::new(ring::default_provider());
+    let builder = ClientConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(NoCertificateVerification(provider)));
+
+    // Still present a client certificate when one is configured (mTLS)
+    let mut config = if let (Some(cert_path), Some(key_path)) = (&tls.cert_path, &tls.key_path) {
+        let cert_pem = std::fs::read(cert_path).context("Failed to read TLS certificate")?;
+        let key_pem = std::fs::read(key_path).context("Failed to read TLS key")?;
+
+        let certs = rustls_pemfile::certs(&mut cert_pem.as_slice())
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to parse TLS certificate")?;
+        let key: PrivateKeyDer = rustls_pemfile::private_key(&mut key_pem.as_slice())
+            .context("Failed to parse TLS key")?
+            .context("No private key found in TLS key file")?;
+        builder.with_client_auth_cert(certs, key)?
+    } else {
+        builder.with_no_client_auth()
+    };
+    // gRPC requires HTTP/2
+    config.alpn_protocols = vec![b"h2".to_vec()];
+
+    let host = address.rsplit_once(':').map(|(host, _)| host).unwrap_or(address);
+    let server_name = ServerName::try_from(tls.server_name.clone().unwrap_or_else(|| host.to_string()))
+        .context("Invalid TLS server name")?;
+    let connector = TlsConnector::from(Arc::new(config));
+    let address = address.to_string();
+
+    let channel = endpoint
+        .connect_with_connector(tower::service_fn(move |_: Uri| {
+            let connector = connector.clone();
+            let server_name = server_name.clone();
+            let address = address.clone();
+            async move {
+                let tcp = TcpStream::connect(address).await?;
+                let stream = connector.connect(server_name, tcp).await?;
+                Ok::<_, std::io::Error>(TokioIo::new(stream))
+            }
+        }))
+        .await?;
+
+    Ok(channel)
+}
+
+/// Accepts any server certificate while still checking handshake signatures
+#[derive(Debug)]
+struct NoCertificateVerification(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for NoCertificateVerification {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(message, cert, dss, &self.0.signature_verification_algorithms)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(message, cert, dss, &self.0.signature_verification_algorithms)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
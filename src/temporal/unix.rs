@@ -0,0 +1,31 @@
+use anyhow::Result;
+use tonic::transport::{Channel, Endpoint};
+
+/// Connect to a frontend listening on a Unix domain socket.
+///
+/// The endpoint's URI only supplies the HTTP/2 authority; every connection
+/// goes to `path` instead.
+#[cfg(unix)]
+pub async fn connect(endpoint: Endpoint, path: &str) -> Result<Channel> {
+    use hyper_util::rt::TokioIo;
+    use tokio::net::UnixStream;
+    use tonic::transport::Uri;
+
+    let path = path.to_string();
+    let channel = endpoint
+        .connect_with_connector(tower::service_fn(move |_: Uri| {
+            let path = path.clone();
+            async move {
+                let stream = UnixStream::connect(path).await?;
+                Ok::<_, std::io::Error>(TokioIo::new(stream))
+            }
+        }))
+        .await?;
+
+    Ok(channel)
+}
+
+#[cfg(not(unix))]
+pub async fn connect(_endpoint: Endpoint, path: &str) -> Result<Channel> {
+    anyhow::bail!("Unix domain sockets are not supported on this platform ({})", path)
+}
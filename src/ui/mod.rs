@@ -1,19 +1,35 @@
 pub mod screens;
 
 use crate::app::{App, Screen};
+use crate::generated::temporal::api::enums::v1::WorkflowExecutionStatus;
+use crate::temporal::error::{AppError, ErrorKind};
+use std::time::Duration;
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Tabs},
     Frame,
 };
 
+/// Workflow list or history loads slower than this are highlighted in the footer
+const SLOW_LOAD_THRESHOLD: Duration = Duration::from_millis(1000);
+
+/// Height of the header bar for the current layout mode
+fn header_height(app: &App) -> u16 {
+    if app.compact { 1 } else { 3 }
+}
+
+/// Height of the content area between the header and the footer
+pub fn content_height(app: &App, terminal_height: u16) -> u16 {
+    terminal_height.saturating_sub(header_height(app) + 1)
+}
+
 pub fn render(app: &App, frame: &mut Frame) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3), // Header
+            Constraint::Length(header_height(app)), // Header
             Constraint::Min(0),     // Content
             Constraint::Length(1),  // Footer
         ])
@@ -26,8 +42,37 @@ pub fn render(app: &App, frame: &mut Frame) {
     match app.current_screen {
         Screen::Workflows => screens::workflows::render(app, frame, chunks[1]),
         Screen::Namespaces => screens::namespaces::render(app, frame, chunks[1]),
+        Screen::Batches => screens::batches::render(app, frame, chunks[1]),
+        Screen::Nexus => screens::nexus::render(app, frame, chunks[1]),
+        Screen::AllNamespaces => screens::all_namespaces::render(app, frame, chunks[1]),
         Screen::WorkflowDetail => screens::workflow_detail::render(app, frame, chunks[1]),
         Screen::Help => screens::help::render(app, frame, chunks[1]),
+        Screen::Setup => screens::setup::render(app, frame, chunks[1]),
+    }
+
+    // Render connection info modal above any screen
+    if app.show_connection_info {
+        screens::connection_info::render(app, frame, chunks[1]);
+    }
+
+    // Prompt to leave a namespace that can no longer be used
+    if let Some(namespace) = &app.namespace_fallback {
+        screens::namespace_fallback::render(app, namespace, frame, chunks[1]);
+    }
+
+    // Feedback while a namespace switch is checked with the server
+    if let Some(namespace) = &app.switching_namespace {
+        screens::namespace_switcher::render_progress(app, namespace, frame, chunks[1]);
+    }
+
+    // Render namespace quick switcher above any screen
+    if app.namespace_switcher.is_some() {
+        screens::namespace_switcher::render(app, frame, chunks[1]);
+    }
+
+    // Confirm quitting before an operation's outcome is known
+    if app.confirm_quit {
+        screens::quit_confirm::render(app, frame, chunks[1]);
     }
 
     // Render footer
@@ -35,12 +80,21 @@ pub fn render(app: &App, frame: &mut Frame) {
 }
 
 fn render_header(app: &App, frame: &mut Frame, area: ratatui::layout::Rect) {
-    let titles = vec!["Workflows (1)", "Namespaces (2)", "Help (?)"];
+    let mut titles = vec!["Workflows (1)", "Namespaces (2)", "Batches (3)"];
+    // The Nexus tab only appears on servers that support it
+    if app.supports_nexus() {
+        titles.push("Nexus (4)");
+    }
+    titles.push("Help (?)");
     let index = match app.current_screen {
         Screen::Workflows => 0,
         Screen::Namespaces => 1,
+        Screen::Batches => 2,
+        Screen::Nexus => 3,
+        Screen::AllNamespaces => 0,
         Screen::WorkflowDetail => 0, // Keep Workflows highlighted when in detail view
-        Screen::Help => 2,
+        Screen::Help => titles.len() - 1,
+        Screen::Setup => 0,
     };
 
     // Build title with connection status indicator
@@ -51,7 +105,31 @@ fn render_header(app: &App, frame: &mut Frame, area: ratatui::layout::Rect) {
         crate::app::ConnectionStatus::Error(_) => ("●", Color::Red),
     };
 
-    let title = format!("Tuiporal {} | ns: {}", status_icon, app.current_namespace);
+    let mut title = format!("Tuiporal {} | ns: {}", status_icon, app.current_namespace);
+    if app.read_only {
+        title.push_str(" | READ-ONLY");
+    }
+
+    // Compact mode: a single unbordered line with the status followed by the tab titles
+    if app.compact {
+        let mut spans = vec![
+            Span::styled(title, Style::default().fg(status_color)),
+            Span::raw(" | "),
+        ];
+        for (i, tab) in titles.iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::raw(" "));
+            }
+            let style = if i == index {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            spans.push(Span::styled(*tab, style));
+        }
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+        return;
+    }
 
     let tabs = Tabs::new(titles)
         .block(Block::default().borders(Borders::ALL).title(Span::styled(title, Style::default().fg(status_color))))
@@ -65,12 +143,128 @@ fn render_header(app: &App, frame: &mut Frame, area: ratatui::layout::Rect) {
     frame.render_widget(tabs, area);
 }
 
+/// Glyph shown before a workflow status so it can be told apart without color
+pub fn status_icon(status: WorkflowExecutionStatus) -> &'static str {
+    match status {
+        WorkflowExecutionStatus::Running => "⟳",
+        WorkflowExecutionStatus::Completed => "✓",
+        WorkflowExecutionStatus::Failed => "✗",
+        WorkflowExecutionStatus::Canceled => "⊘",
+        WorkflowExecutionStatus::Terminated => "■",
+        WorkflowExecutionStatus::ContinuedAsNew => "↪",
+        WorkflowExecutionStatus::TimedOut => "⏱",
+        _ => "?",
+    }
+}
+
+/// Lines for an error panel: the summary, plus the raw detail when expanded
+pub fn error_message_lines(error: &AppError, show_details: bool) -> Vec<Line<'static>> {
+    // An outage usually passes; a rejected credential needs the config fixed
+    let summary_color = match error.kind() {
+        ErrorKind::Unavailable => Color::Yellow,
+        _ => Color::White,
+    };
+    let mut lines = vec![Line::from(Span::styled(error.summary(), Style::default().fg(summary_color)))];
+    if error.kind() == ErrorKind::Auth {
+        lines.push(Line::from(Span::styled(
+            "Press ',' to edit the profile's API key or TLS settings",
+            Style::default().fg(Color::Cyan),
+        )));
+    }
+
+    let details = error.details();
+    if !details.is_empty() {
+        lines.push(Line::from(""));
+        if show_details {
+            lines.extend(
+                details
+                    .into_iter()
+                    .map(|d| Line::from(Span::styled(d, Style::default().fg(Color::DarkGray)))),
+            );
+        } else {
+            lines.push(Line::from(Span::styled(
+                "Press 'e' to show details",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+    }
+
+    lines
+}
+
+/// Narrowest and widest an auto-fitted table column gets
+const MIN_COLUMN_WIDTH: u16 = 6;
+const MAX_COLUMN_WIDTH: u16 = 60;
+
+/// Widen each column's measured width to fit one row's cell texts
+pub fn measure_row(widths: &mut [u16], cells: &[&str]) {
+    for (width, text) in widths.iter_mut().zip(cells) {
+        let text_width = u16::try_from(Span::raw(*text).width()).unwrap_or(u16::MAX);
+        *width = (*width).max(text_width);
+    }
+}
+
+/// Column widths proportional to the measured content of the visible rows, each clamped
+/// to a min/max, filling a bordered table in `area`. Falls back to `fallback` when the
+/// area is too narrow to give every column its minimum.
+pub fn fit_widths(area: Rect, measured: &[u16], fallback: Vec<Constraint>) -> Vec<Constraint> {
+    let columns = measured.len() as u16;
+    // Borders plus the table's default one-cell column spacing
+    let available = area.width.saturating_sub(2 + columns.saturating_sub(1));
+    if columns == 0 || available < MIN_COLUMN_WIDTH * columns {
+        return fallback;
+    }
+
+    let desired: Vec<u32> = measured
+        .iter()
+        .map(|width| u32::from((*width).clamp(MIN_COLUMN_WIDTH, MAX_COLUMN_WIDTH)))
+        .collect();
+    let total: u32 = desired.iter().sum();
+    let last = desired.len() - 1;
+    desired
+        .iter()
+        .enumerate()
+        .map(|(i, width)| {
+            let share = ((width * u32::from(available) / total) as u16).max(MIN_COLUMN_WIDTH);
+            // The last column takes whatever rounding left over
+            if i == last {
+                Constraint::Min(share)
+            } else {
+                Constraint::Length(share)
+            }
+        })
+        .collect()
+}
+
 fn render_footer(app: &App, frame: &mut Frame, area: ratatui::layout::Rect) {
-    let help_text = match app.current_screen {
+    // The quick switcher captures all input, so its hints replace the screen's
+    if app.namespace_switcher.is_some() {
+        let hints = Line::from(vec![
+            Span::styled("Type to filter | ", Style::default().fg(Color::White)),
+            Span::styled("↑/↓", Style::default().fg(Color::Yellow)),
+            Span::raw(" select | "),
+            Span::styled("Enter", Style::default().fg(Color::Yellow)),
+            Span::raw(" switch | "),
+            Span::styled("ESC", Style::default().fg(Color::Yellow)),
+            Span::raw(" cancel"),
+        ]);
+        frame.render_widget(Paragraph::new(hints), area);
+        return;
+    }
+
+    if let Some(notice) = &app.footer_notice {
+        let line = Line::from(Span::styled(notice.as_str(), Style::default().fg(Color::Red)));
+        frame.render_widget(Paragraph::new(line), area);
+        return;
+    }
+
+    let mut help_text = match app.current_screen {
         Screen::Workflows => {
             if app.workflow_list_state.input_mode {
                 Line::from(vec![
                     Span::styled("Type to search | ", Style::default().fg(Color::White)),
+                    Span::styled("←/→ Home/End", Style::default().fg(Color::Yellow)),
+                    Span::raw(" move | "),
                     Span::styled("Enter", Style::default().fg(Color::Yellow)),
                     Span::raw(" confirm | "),
                     Span::styled("ESC", Style::default().fg(Color::Yellow)),
@@ -88,6 +282,8 @@ fn render_footer(app: &App, frame: &mut Frame, area: ratatui::layout::Rect) {
                     Span::raw(" search | "),
                     Span::styled("f", Style::default().fg(Color::Yellow)),
                     Span::raw(" filter | "),
+                    Span::styled("t", Style::default().fg(Color::Yellow)),
+                    Span::raw(" time | "),
                     Span::styled("c", Style::default().fg(Color::Yellow)),
                     Span::raw(" clear | "),
                     Span::styled("a", Style::default().fg(Color::Yellow)),
@@ -116,13 +312,92 @@ fn render_footer(app: &App, frame: &mut Frame, area: ratatui::layout::Rect) {
                 Line::from(help_spans)
             }
         }
-        Screen::Namespaces => Line::from(vec![
+        Screen::Namespaces if app.namespace_list_state.dialog.is_some() => Line::from(vec![
+            Span::styled("Type input | ", Style::default().fg(Color::White)),
+            Span::styled("Tab", Style::default().fg(Color::Yellow)),
+            Span::raw(" next field | "),
+            Span::styled("Enter", Style::default().fg(Color::Yellow)),
+            Span::raw(" save | "),
+            Span::styled("ESC", Style::default().fg(Color::Yellow)),
+            Span::raw(" cancel"),
+        ]),
+        Screen::Namespaces => {
+            // Create/update hints are greyed out when running read-only
+            let (op_key, op_label) = if app.read_only {
+                (Style::default().fg(Color::DarkGray), Style::default().fg(Color::DarkGray))
+            } else {
+                (Style::default().fg(Color::Yellow), Style::default())
+            };
+            // Whichever of switch/view Enter doesn't do is on its own key
+            let (enter_label, other_key, other_label) = if app.config.enter_views_namespace {
+                (" view | ", "s", " switch | ")
+            } else {
+                (" switch | ", "v", " view | ")
+            };
+            Line::from(vec![
+                Span::styled("↑/k", Style::default().fg(Color::Yellow)),
+                Span::raw("/"),
+                Span::styled("↓/j", Style::default().fg(Color::Yellow)),
+                Span::raw(" nav | "),
+                Span::styled("Enter", Style::default().fg(Color::Yellow)),
+                Span::raw(enter_label),
+                Span::styled(other_key, Style::default().fg(Color::Yellow)),
+                Span::raw(other_label),
+                Span::styled("f", Style::default().fg(Color::Yellow)),
+                Span::raw(" filter | "),
+                Span::styled("c", op_key),
+                Span::styled(" create", op_label),
+                Span::raw(" | "),
+                Span::styled("u", op_key),
+                Span::styled(" update", op_label),
+                Span::raw(" | "),
+                Span::styled("r", Style::default().fg(Color::Yellow)),
+                Span::raw(" refresh | "),
+                Span::styled("?", Style::default().fg(Color::Yellow)),
+                Span::raw(" help | "),
+                Span::styled("ESC", Style::default().fg(Color::Yellow)),
+                Span::raw(" back | "),
+                Span::styled("q", Style::default().fg(Color::Yellow)),
+                Span::raw(" quit"),
+            ])
+        }
+        Screen::AllNamespaces => Line::from(vec![
             Span::styled("↑/k", Style::default().fg(Color::Yellow)),
             Span::raw("/"),
             Span::styled("↓/j", Style::default().fg(Color::Yellow)),
             Span::raw(" nav | "),
             Span::styled("Enter", Style::default().fg(Color::Yellow)),
-            Span::raw(" switch | "),
+            Span::raw(" view | "),
+            Span::styled("r", Style::default().fg(Color::Yellow)),
+            Span::raw(" refresh | "),
+            Span::styled("?", Style::default().fg(Color::Yellow)),
+            Span::raw(" help | "),
+            Span::styled("ESC", Style::default().fg(Color::Yellow)),
+            Span::raw(" back | "),
+            Span::styled("q", Style::default().fg(Color::Yellow)),
+            Span::raw(" quit"),
+        ]),
+        Screen::Batches => Line::from(vec![
+            Span::styled("↑/k", Style::default().fg(Color::Yellow)),
+            Span::raw("/"),
+            Span::styled("↓/j", Style::default().fg(Color::Yellow)),
+            Span::raw(" nav | "),
+            Span::styled("f", Style::default().fg(Color::Yellow)),
+            Span::raw(" filter | "),
+            Span::styled("r", Style::default().fg(Color::Yellow)),
+            Span::raw(" refresh | "),
+            Span::styled("?", Style::default().fg(Color::Yellow)),
+            Span::raw(" help | "),
+            Span::styled("ESC", Style::default().fg(Color::Yellow)),
+            Span::raw(" back | "),
+            Span::styled("q", Style::default().fg(Color::Yellow)),
+            Span::raw(" quit"),
+        ]),
+        Screen::Nexus => Line::from(vec![
+            Span::styled("↑/k", Style::default().fg(Color::Yellow)),
+            Span::raw("/"),
+            Span::styled("↓/j", Style::default().fg(Color::Yellow)),
+            Span::raw(" nav | "),
             Span::styled("r", Style::default().fg(Color::Yellow)),
             Span::raw(" refresh | "),
             Span::styled("?", Style::default().fg(Color::Yellow)),
@@ -141,22 +416,49 @@ fn render_footer(app: &App, frame: &mut Frame, area: ratatui::layout::Rect) {
                     Span::raw(" scroll | "),
                     Span::styled("PgUp/PgDn", Style::default().fg(Color::Yellow)),
                     Span::raw(" page | "),
+                    Span::styled("Tab", Style::default().fg(Color::Yellow)),
+                    Span::raw("/"),
+                    Span::styled("Enter", Style::default().fg(Color::Yellow)),
+                    Span::raw(" follow reference | "),
+                    Span::styled("w", Style::default().fg(Color::Yellow)),
+                    Span::raw(" wrap | "),
+                    Span::styled("y", Style::default().fg(Color::Yellow)),
+                    Span::raw(" copy | "),
                     Span::styled("ESC/q", Style::default().fg(Color::Yellow)),
                     Span::raw(" close"),
                 ])
             } else if app.workflow_detail_state.show_dialog.is_some() {
                 Line::from(vec![
                     Span::styled("Type input | ", Style::default().fg(Color::White)),
+                    Span::styled("Tab", Style::default().fg(Color::Yellow)),
+                    Span::raw(" next field | "),
                     Span::styled("Enter", Style::default().fg(Color::Yellow)),
                     Span::raw(" confirm | "),
                     Span::styled("ESC", Style::default().fg(Color::Yellow)),
                     Span::raw(" cancel"),
                 ])
-            } else if app.workflow_detail_state.success_message.is_some() {
+            } else if app.workflow_detail_state.search_mode {
+                Line::from(vec![
+                    Span::styled("Search event types and attributes | ", Style::default().fg(Color::White)),
+                    Span::styled("Enter", Style::default().fg(Color::Yellow)),
+                    Span::raw(" confirm | "),
+                    Span::styled("ESC", Style::default().fg(Color::Yellow)),
+                    Span::raw(" cancel"),
+                ])
+            } else if app.workflow_detail_state.success_message.is_some()
+                || app.workflow_detail_state.notice.is_some()
+                || app.workflow_detail_state.update_outcome.is_some()
+            {
                 Line::from(vec![
                     Span::raw("Press any key to continue"),
                 ])
             } else {
+                // Operation hints are greyed out when running read-only
+                let (op_key, op_label) = if app.read_only {
+                    (Style::default().fg(Color::DarkGray), Style::default().fg(Color::DarkGray))
+                } else {
+                    (Style::default().fg(Color::Yellow), Style::default())
+                };
                 Line::from(vec![
                     Span::styled("↑/k", Style::default().fg(Color::Yellow)),
                     Span::raw("/"),
@@ -164,12 +466,34 @@ fn render_footer(app: &App, frame: &mut Frame, area: ratatui::layout::Rect) {
                     Span::raw(" nav | "),
                     Span::styled("Enter", Style::default().fg(Color::Yellow)),
                     Span::raw(" view | "),
-                    Span::styled("t", Style::default().fg(Color::Yellow)),
-                    Span::raw(" terminate | "),
-                    Span::styled("x", Style::default().fg(Color::Yellow)),
-                    Span::raw(" cancel | "),
-                    Span::styled("s", Style::default().fg(Color::Yellow)),
-                    Span::raw(" signal | "),
+                    Span::styled("/", Style::default().fg(Color::Yellow)),
+                    Span::raw(" search | "),
+                    Span::styled("n/N", Style::default().fg(Color::Yellow)),
+                    Span::raw(" next/prev match | "),
+                    Span::styled("g", Style::default().fg(Color::Yellow)),
+                    Span::raw(" group activities | "),
+                    Span::styled("r", Style::default().fg(Color::Yellow)),
+                    Span::raw(" reload | "),
+                    Span::styled("F", Style::default().fg(Color::Yellow)),
+                    Span::raw(" follow | "),
+                    Span::styled("t", op_key),
+                    Span::styled(" terminate", op_label),
+                    Span::raw(" | "),
+                    Span::styled("x", op_key),
+                    Span::styled(" cancel", op_label),
+                    Span::raw(" | "),
+                    Span::styled("s", op_key),
+                    Span::styled(" signal", op_label),
+                    Span::raw(" | "),
+                    Span::styled("u", op_key),
+                    Span::styled(" update", op_label),
+                    Span::raw(" | "),
+                    Span::styled("R", op_key),
+                    Span::styled(" restart", op_label),
+                    Span::raw(" | "),
+                    Span::styled("E", op_key),
+                    Span::styled(" reset", op_label),
+                    Span::raw(" | "),
                     Span::styled("?", Style::default().fg(Color::Yellow)),
                     Span::raw(" help | "),
                     Span::styled("ESC", Style::default().fg(Color::Yellow)),
@@ -179,6 +503,15 @@ fn render_footer(app: &App, frame: &mut Frame, area: ratatui::layout::Rect) {
                 ])
             }
         }
+        Screen::Setup => Line::from(vec![
+            Span::styled("Type to edit | ", Style::default().fg(Color::White)),
+            Span::styled("Tab/↑↓", Style::default().fg(Color::Yellow)),
+            Span::raw(" next field | "),
+            Span::styled("Enter", Style::default().fg(Color::Yellow)),
+            Span::raw(" save & connect | "),
+            Span::styled("ESC", Style::default().fg(Color::Yellow)),
+            Span::raw(" quit"),
+        ]),
         Screen::Help => Line::from(vec![
             Span::styled("↑/k", Style::default().fg(Color::Yellow)),
             Span::raw("/"),
@@ -195,6 +528,16 @@ fn render_footer(app: &App, frame: &mut Frame, area: ratatui::layout::Rect) {
         ]),
     };
 
+    // Shows whether slowness comes from the server rather than the UI
+    if let Some(latency) = app.last_load_latency {
+        let color = if latency >= SLOW_LOAD_THRESHOLD { Color::Red } else { Color::DarkGray };
+        help_text.spans.push(Span::raw(" | "));
+        help_text.spans.push(Span::styled(
+            format!("last load {}ms", latency.as_millis()),
+            Style::default().fg(color),
+        ));
+    }
+
     let footer = Paragraph::new(help_text);
     frame.render_widget(footer, area);
 }
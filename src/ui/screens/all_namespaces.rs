@@ -0,0 +1,182 @@
+use super::workflows::{get_workflow_id, get_workflow_start_time, get_workflow_status, get_workflow_type, status_color};
+use crate::app::{App, ConnectionStatus};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    Frame,
+};
+
+/// Failed namespaces listed under the table beyond this are summarised as a count
+const MAX_ERROR_LINES: usize = 5;
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let state = &app.aggregate_state;
+    let namespace_count = app.config.aggregate_namespaces.len();
+
+    // Show loading indicator
+    if state.loading {
+        let loading_text = format!(
+            "{} Loading workflows from {} namespaces... (ESC to cancel)",
+            app.spinner(),
+            namespace_count
+        );
+        let paragraph = Paragraph::new(loading_text)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("All Namespaces")
+                    .style(Style::default().fg(Color::Yellow)),
+            )
+            .style(Style::default().fg(Color::Yellow));
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    // Show connection status if not connected
+    if !matches!(app.connection_status, ConnectionStatus::Connected) {
+        let (status_text, color) = match &app.connection_status {
+            ConnectionStatus::Disconnected => ("Not connected to Temporal".to_string(), Color::Red),
+            ConnectionStatus::Connecting => (format!("{} Connecting to Temporal...", app.spinner()), Color::Yellow),
+            ConnectionStatus::Error(e) => (format!("Connection error: {}", e), Color::Red),
+            ConnectionStatus::Connected => (String::new(), Color::White),
+        };
+        let paragraph = Paragraph::new(status_text)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("All Namespaces")
+                    .style(Style::default().fg(color)),
+            )
+            .style(Style::default().fg(color));
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    // Failed namespaces get a panel below the table; the others still show
+    let table_area = if state.errors.is_empty() {
+        area
+    } else {
+        let error_lines = state.errors.len().min(MAX_ERROR_LINES + 1) as u16;
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(error_lines + 2)])
+            .split(area);
+        render_errors(app, frame, chunks[1]);
+        chunks[0]
+    };
+
+    let mut title = format!(
+        "All Namespaces ({} workflows in {} namespaces)",
+        state.rows.len(),
+        namespace_count - state.errors.len().min(namespace_count)
+    );
+    if !state.query.is_empty() {
+        title = format!("{} [Query: {}]", title, state.query);
+    }
+
+    if state.rows.is_empty() {
+        let lines = vec![
+            Line::from("No workflows found in any namespace"),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Press 'r' to refresh",
+                Style::default().fg(Color::Yellow),
+            )),
+        ];
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .style(Style::default().fg(Color::White)),
+        );
+        frame.render_widget(paragraph, table_area);
+        return;
+    }
+
+    let header_texts = ["Namespace", "Workflow ID", "Type", "Status", "Start Time"];
+    let mut measured = vec![0; header_texts.len()];
+    crate::ui::measure_row(&mut measured, &header_texts);
+    let header = Row::new(header_texts.map(Cell::from))
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> = state
+        .rows
+        .iter()
+        .map(|(namespace, workflow)| {
+            let workflow_id = get_workflow_id(workflow);
+            let workflow_type = get_workflow_type(workflow);
+            let (status, status_text) = get_workflow_status(workflow);
+            let start_time = get_workflow_start_time(workflow);
+            crate::ui::measure_row(
+                &mut measured,
+                &[namespace, &workflow_id, &workflow_type, &status_text, &start_time],
+            );
+            Row::new(vec![
+                Cell::from(namespace.clone()).style(Style::default().fg(Color::Cyan)),
+                Cell::from(workflow_id),
+                Cell::from(workflow_type),
+                Cell::from(status_text).style(Style::default().fg(status_color(status))),
+                Cell::from(start_time),
+            ])
+        })
+        .collect();
+
+    let widths = crate::ui::fit_widths(
+        table_area,
+        &measured,
+        vec![
+            Constraint::Length(20),
+            Constraint::Percentage(30),
+            Constraint::Percentage(20),
+            Constraint::Length(14),
+            Constraint::Min(20),
+        ],
+    );
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .style(Style::default().fg(Color::White)),
+        )
+        .row_highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    frame.render_stateful_widget(table, table_area, &mut state.table_state.clone());
+}
+
+/// One line per failed namespace with the summary line of its error
+fn render_errors(app: &App, frame: &mut Frame, area: Rect) {
+    let errors = &app.aggregate_state.errors;
+    let mut lines: Vec<Line> = errors
+        .iter()
+        .take(MAX_ERROR_LINES)
+        .map(|(namespace, error)| {
+            Line::from(vec![
+                Span::styled(format!("{}: ", namespace), Style::default().fg(Color::Cyan)),
+                Span::raw(error.lines().next().unwrap_or_default().to_string()),
+            ])
+        })
+        .collect();
+    if errors.len() > MAX_ERROR_LINES {
+        lines.push(Line::from(Span::styled(
+            format!("...and {} more", errors.len() - MAX_ERROR_LINES),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Failed namespaces ({})", errors.len()))
+            .style(Style::default().fg(Color::Red)),
+    );
+    frame.render_widget(paragraph, area);
+}
@@ -0,0 +1,191 @@
+use crate::app::{batch_state_label, batch_type_label, App, ConnectionStatus};
+use crate::generated::temporal::api::enums::v1::BatchOperationState;
+use crate::generated::temporal::api::workflowservice::v1::DescribeBatchOperationResponse;
+use crate::temporal::time::format_timestamp_short;
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    Frame,
+};
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let state = &app.batch_list_state;
+
+    // Show error message if there is one
+    if let Some(error) = &state.error {
+        let mut lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                "⚠ An error occurred:",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+        ];
+        lines.extend(crate::ui::error_message_lines(error, app.show_error_details));
+        lines.extend([
+            Line::from(""),
+            Line::from(Span::styled(
+                "Press 'r' to retry or 'ESC' to go back",
+                Style::default().fg(Color::Yellow),
+            )),
+        ]);
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Batch Operations - Error")
+                .style(Style::default().fg(Color::Red)),
+        );
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    // Show loading indicator
+    if state.loading {
+        let loading_text = format!("{} Loading batch operations... (ESC to cancel)", app.spinner());
+        let paragraph = Paragraph::new(loading_text)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Batch Operations")
+                    .style(Style::default().fg(Color::Yellow)),
+            )
+            .style(Style::default().fg(Color::Yellow));
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    // Show connection status if not connected
+    if !matches!(app.connection_status, ConnectionStatus::Connected) {
+        let (status_text, color) = match &app.connection_status {
+            ConnectionStatus::Disconnected => ("Not connected to Temporal".to_string(), Color::Red),
+            ConnectionStatus::Connecting => (format!("{} Connecting to Temporal...", app.spinner()), Color::Yellow),
+            ConnectionStatus::Error(e) => (format!("Connection error: {}", e), Color::Red),
+            ConnectionStatus::Connected => (String::new(), Color::White),
+        };
+        let paragraph = Paragraph::new(status_text)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Batch Operations")
+                    .style(Style::default().fg(color)),
+            )
+            .style(Style::default().fg(color));
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    let visible_items = state.visible_items();
+
+    if visible_items.is_empty() {
+        let message = if state.items.is_empty() {
+            format!("No batch operations in namespace {}", app.current_namespace)
+        } else {
+            format!("No batch operations match filter: {}", state.filter_label())
+        };
+        let lines = vec![
+            Line::from(message),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Press 'r' to refresh",
+                Style::default().fg(Color::Yellow),
+            )),
+        ];
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Batch Operations")
+                .style(Style::default().fg(Color::White)),
+        );
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    let header_texts = ["Job ID", "Type", "State", "Progress", "Started", "Reason"];
+    let mut measured = vec![0; header_texts.len()];
+    crate::ui::measure_row(&mut measured, &header_texts);
+    let header = Row::new(header_texts.map(Cell::from))
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> = visible_items
+        .iter()
+        .map(|op| {
+            let progress = format_progress(op);
+            let started = format_start_time(op);
+            let operation_type = batch_type_label(op.operation_type);
+            let state_label = batch_state_label(op.state);
+            crate::ui::measure_row(
+                &mut measured,
+                &[&op.job_id, operation_type, state_label, &progress, &started, &op.reason],
+            );
+            Row::new(vec![
+                Cell::from(op.job_id.clone()),
+                Cell::from(operation_type),
+                Cell::from(state_label).style(Style::default().fg(state_color(op.state))),
+                Cell::from(progress),
+                Cell::from(started),
+                Cell::from(op.reason.clone()),
+            ])
+        })
+        .collect();
+
+    let widths = crate::ui::fit_widths(
+        area,
+        &measured,
+        vec![
+            Constraint::Percentage(25),
+            Constraint::Length(10),
+            Constraint::Length(10),
+            Constraint::Length(22),
+            Constraint::Length(20),
+            Constraint::Min(10),
+        ],
+    );
+
+    let mut title = format!("Batch Operations ({} items) - ns: {}", state.items.len(), app.current_namespace);
+    if state.state_filter.is_some() {
+        title = format!("{} [Filter: {}]", title, state.filter_label());
+    }
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .style(Style::default().fg(Color::White)),
+        )
+        .row_highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    frame.render_stateful_widget(table, area, &mut state.table_state.clone());
+}
+
+fn state_color(state: i32) -> Color {
+    match BatchOperationState::try_from(state) {
+        Ok(BatchOperationState::Running) => Color::Cyan,
+        Ok(BatchOperationState::Completed) => Color::Green,
+        Ok(BatchOperationState::Failed) => Color::Red,
+        _ => Color::Gray,
+    }
+}
+
+/// "completed/total", plus the failure count when any executions failed
+fn format_progress(op: &DescribeBatchOperationResponse) -> String {
+    if op.total_operation_count == 0 && op.complete_operation_count == 0 {
+        return "-".to_string();
+    }
+    let mut progress = format!("{}/{}", op.complete_operation_count, op.total_operation_count);
+    if op.failure_operation_count > 0 {
+        progress.push_str(&format!(" ({} failed)", op.failure_operation_count));
+    }
+    progress
+}
+
+fn format_start_time(op: &DescribeBatchOperationResponse) -> String {
+    format_timestamp_short(op.start_time.as_ref(), "Unknown")
+}
@@ -0,0 +1,109 @@
+use crate::app::App;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    // Create a centered modal area
+    let modal_width = 64.min(area.width);
+    let modal_height = 22.min(area.height);
+    let modal_x = area.x + (area.width.saturating_sub(modal_width)) / 2;
+    let modal_y = area.y + (area.height.saturating_sub(modal_height)) / 2;
+    let modal_area = Rect::new(modal_x, modal_y, modal_width, modal_height);
+
+    // Clear the area
+    frame.render_widget(Clear, modal_area);
+
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::Cyan));
+    let profile = app.config.get_active_profile();
+
+    let mut lines = vec![
+        Line::from(vec![
+            label("Profile: "),
+            Span::raw(profile.map(|p| p.name.clone()).unwrap_or_else(|| "None".to_string())),
+        ]),
+        Line::from(vec![
+            label("Address: "),
+            Span::raw(profile.map(|p| p.address.clone()).unwrap_or_else(|| "N/A".to_string())),
+        ]),
+        Line::from(vec![
+            label("Namespace: "),
+            Span::raw(app.current_namespace.clone()),
+        ]),
+        Line::from(vec![
+            label("TLS: "),
+            Span::raw(if profile.and_then(|p| p.tls.as_ref()).map(|t| t.enabled).unwrap_or(false) {
+                "Enabled"
+            } else {
+                "Disabled"
+            }),
+            label("  API key: "),
+            Span::raw(if profile.and_then(|p| p.api_key.as_ref()).is_some() { "Yes" } else { "No" }),
+        ]),
+        Line::from(""),
+    ];
+
+    match &app.server_info {
+        Some(info) => {
+            lines.push(Line::from(vec![
+                label("Server version: "),
+                Span::raw(if info.server_version.is_empty() {
+                    "Unknown".to_string()
+                } else {
+                    info.server_version.clone()
+                }),
+            ]));
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "Capabilities:",
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            )));
+
+            match &info.capabilities {
+                Some(caps) => {
+                    let flags = [
+                        ("Signal and query headers", caps.signal_and_query_header),
+                        ("Internal error differentiation", caps.internal_error_differentiation),
+                        ("Activity failure includes heartbeat", caps.activity_failure_include_heartbeat),
+                        ("Schedules", caps.supports_schedules),
+                        ("Encoded failure attributes", caps.encoded_failure_attributes),
+                        ("Build ID based versioning", caps.build_id_based_versioning),
+                        ("Upsert memo", caps.upsert_memo),
+                        ("Eager workflow start", caps.eager_workflow_start),
+                        ("SDK metadata", caps.sdk_metadata),
+                        ("Count group by execution status", caps.count_group_by_execution_status),
+                        ("Nexus", caps.nexus),
+                    ];
+                    for (name, enabled) in flags {
+                        let (icon, color) = if enabled { ("✓", Color::Green) } else { ("✗", Color::DarkGray) };
+                        lines.push(Line::from(vec![
+                            Span::styled(format!("  {} ", icon), Style::default().fg(color)),
+                            Span::raw(name),
+                        ]));
+                    }
+                }
+                None => lines.push(Line::from(Span::styled(
+                    "  Server did not report capabilities",
+                    Style::default().fg(Color::DarkGray),
+                ))),
+            }
+        }
+        None => lines.push(Line::from(Span::styled(
+            "Not connected - no server information available",
+            Style::default().fg(Color::Red),
+        ))),
+    }
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Connection Info | any key to close")
+            .style(Style::default().fg(Color::Cyan)),
+    );
+
+    frame.render_widget(paragraph, modal_area);
+}
@@ -1,4 +1,5 @@
-use crate::app::App;
+use crate::app::{App, Screen};
+use crate::keymap::{key_label, BINDINGS};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -7,8 +8,13 @@ use ratatui::{
     Frame,
 };
 
-pub fn render(app: &App, frame: &mut Frame, area: Rect) {
-    let lines = vec![
+/// Number of lines in the help text, used to clamp scrolling
+pub fn line_count(app: &App) -> u16 {
+    help_lines(app).len() as u16
+}
+
+fn help_lines(app: &App) -> Vec<Line<'static>> {
+    let mut lines = vec![
         Line::from(""),
         Line::from(Span::styled(
             "Tuiporal - Temporal TUI Client",
@@ -16,126 +22,50 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
         )),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Global Navigation",
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        )),
-        Line::from(vec![
-            Span::styled("  1", Style::default().fg(Color::Yellow)),
-            Span::raw("         → Switch to Workflows screen"),
-        ]),
-        Line::from(vec![
-            Span::styled("  2", Style::default().fg(Color::Yellow)),
-            Span::raw("         → Switch to Namespaces screen"),
-        ]),
-        Line::from(vec![
-            Span::styled("  3", Style::default().fg(Color::Yellow)),
-            Span::raw("         → Switch to Workflow Detail screen"),
-        ]),
-        Line::from(vec![
-            Span::styled("  4", Style::default().fg(Color::Yellow)),
-            Span::raw("         → Switch to Settings screen"),
-        ]),
-        Line::from(vec![
-            Span::styled("  ?", Style::default().fg(Color::Yellow)),
-            Span::raw("         → Show this help screen"),
-        ]),
-        Line::from(vec![
-            Span::styled("  q/ESC", Style::default().fg(Color::Yellow)),
-            Span::raw("     → Quit or go back"),
-        ]),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Workflows Screen",
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        )),
-        Line::from(vec![
-            Span::styled("  ↑/k, ↓/j", Style::default().fg(Color::Yellow)),
-            Span::raw("  → Navigate up/down"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Enter", Style::default().fg(Color::Yellow)),
-            Span::raw("      → View workflow details"),
-        ]),
-        Line::from(vec![
-            Span::styled("  /", Style::default().fg(Color::Yellow)),
-            Span::raw("         → Search workflows (Temporal visibility query)"),
-        ]),
-        Line::from(vec![
-            Span::styled("  f", Style::default().fg(Color::Yellow)),
-            Span::raw("         → Cycle through status filters (Running/Completed/Failed/etc)"),
-        ]),
-        Line::from(vec![
-            Span::styled("  c", Style::default().fg(Color::Yellow)),
-            Span::raw("         → Clear search and filters"),
-        ]),
-        Line::from(vec![
-            Span::styled("  r", Style::default().fg(Color::Yellow)),
-            Span::raw("         → Refresh workflow list"),
-        ]),
-        Line::from(vec![
-            Span::styled("  a", Style::default().fg(Color::Yellow)),
-            Span::raw("         → Toggle auto-refresh (5s interval)"),
-        ]),
-        Line::from(vec![
-            Span::styled("  →/n", Style::default().fg(Color::Yellow)),
-            Span::raw("       → Next page (if available)"),
-        ]),
-        Line::from(vec![
-            Span::styled("  ←/p", Style::default().fg(Color::Yellow)),
-            Span::raw("       → Previous page (if available)"),
-        ]),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Workflow Detail Screen",
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        )),
-        Line::from(vec![
-            Span::styled("  ↑/k, ↓/j", Style::default().fg(Color::Yellow)),
-            Span::raw("  → Navigate event history"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Enter", Style::default().fg(Color::Yellow)),
-            Span::raw("      → View event details"),
-        ]),
-        Line::from(vec![
-            Span::styled("  t", Style::default().fg(Color::Yellow)),
-            Span::raw("         → Terminate workflow"),
-        ]),
-        Line::from(vec![
-            Span::styled("  x", Style::default().fg(Color::Yellow)),
-            Span::raw("         → Cancel workflow"),
-        ]),
-        Line::from(vec![
-            Span::styled("  s", Style::default().fg(Color::Yellow)),
-            Span::raw("         → Signal workflow"),
-        ]),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Namespaces Screen",
+    ];
+
+    let sections = [
+        (None, "Global Navigation"),
+        (Some(Screen::Workflows), "Workflows Screen"),
+        (Some(Screen::WorkflowDetail), "Workflow Detail Screen"),
+        (Some(Screen::Namespaces), "Namespaces Screen"),
+        (Some(Screen::Batches), "Batch Operations Screen"),
+        (Some(Screen::Nexus), "Nexus Endpoints Screen"),
+        (Some(Screen::AllNamespaces), "All Namespaces View"),
+    ];
+    for (screen, heading) in sections {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            heading,
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
-        )),
-        Line::from(vec![
-            Span::styled("  ↑/k, ↓/j", Style::default().fg(Color::Yellow)),
-            Span::raw("  → Navigate namespaces"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Enter", Style::default().fg(Color::Yellow)),
-            Span::raw("      → Switch to selected namespace"),
-        ]),
-        Line::from(vec![
-            Span::styled("  r", Style::default().fg(Color::Yellow)),
-            Span::raw("         → Refresh namespace list"),
-        ]),
+        )));
+
+        // Mutating operations are hidden in read-only mode
+        let mut hidden = false;
+        for binding in BINDINGS.iter().filter(|binding| binding.screen == screen) {
+            if binding.mutating && app.read_only {
+                hidden = true;
+                continue;
+            }
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:<10}", key_label(binding.keys)),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::raw(format!("→ {}", binding.description)),
+            ]));
+        }
+        if hidden {
+            lines.push(Line::from(Span::styled(
+                "  Operations that change the server are disabled (read-only mode)",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+    }
+
+    lines.extend([
         Line::from(""),
         Line::from(Span::styled(
             "Tips",
@@ -157,10 +87,15 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
             Span::raw("  • Filters and searches can be combined for precise results"),
         ]),
         Line::from(vec![
-            Span::raw("  • Current namespace is shown in the Settings screen"),
+            Span::raw("  • Current namespace is shown in the title bar"),
         ]),
-    ];
+    ]);
 
+    lines
+}
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let lines = help_lines(app);
     let total_lines = lines.len() as u16;
     let scroll_offset = app.help_state.scroll_offset;
 
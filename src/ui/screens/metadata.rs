@@ -0,0 +1,147 @@
+use crate::app::App;
+use crate::generated::temporal::api::common::v1::{Payload, WorkflowExecution};
+use crate::temporal::payload::{format_payload, format_search_attribute};
+use crate::temporal::time::format_timestamp;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use std::collections::HashMap;
+
+/// Every metadata field of the shown run, with search attributes and memo decoded
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let state = &app.workflow_detail_state;
+
+    // Create a large modal area (90% of screen)
+    let modal_width = (area.width * 9) / 10;
+    let modal_height = (area.height * 9) / 10;
+    let modal_x = (area.width.saturating_sub(modal_width)) / 2;
+    let modal_y = (area.height.saturating_sub(modal_height)) / 2;
+    let modal_area = Rect::new(modal_x, modal_y, modal_width, modal_height);
+
+    // Clear the area
+    frame.render_widget(Clear, modal_area);
+
+    let paragraph = Paragraph::new(metadata_lines(app))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Workflow Metadata")
+                .title_bottom(" ↑/↓ scroll | PgUp/PgDn page | ESC/q/m close ")
+                .style(Style::default().fg(Color::Cyan)),
+        )
+        .wrap(Wrap { trim: false })
+        .scroll((state.metadata_scroll, 0));
+    frame.render_widget(paragraph, modal_area);
+}
+
+/// Number of lines in the modal, used to clamp scrolling
+pub fn metadata_line_count(app: &App) -> u16 {
+    metadata_lines(app).len() as u16
+}
+
+fn metadata_lines(app: &App) -> Vec<Line<'static>> {
+    let state = &app.workflow_detail_state;
+    let Some(workflow) = state.workflow.as_ref() else {
+        return vec![Line::from("No workflow loaded")];
+    };
+
+    let (status, status_label) = super::workflows::get_workflow_status(workflow);
+    let (history_size, history_color) =
+        super::workflow_detail::history_size_label(workflow.history_length, workflow.history_size_bytes);
+    let execution = workflow.execution.clone().unwrap_or_default();
+
+    let mut lines = vec![section("Execution")];
+    lines.push(field("Workflow ID", &execution.workflow_id));
+    lines.push(field("Run ID", &execution.run_id));
+    if !workflow.first_run_id.is_empty() && workflow.first_run_id != execution.run_id {
+        lines.push(field("First Run ID", &workflow.first_run_id));
+    }
+    lines.push(field("Type", &workflow.r#type.as_ref().map(|t| t.name.clone()).unwrap_or_default()));
+    let status_style = Style::default()
+        .fg(super::workflow_detail::status_color(status))
+        .add_modifier(Modifier::BOLD);
+    lines.push(Line::from(vec![label("Status"), Span::styled(status_label, status_style)]));
+    lines.push(field("Task Queue", &workflow.task_queue));
+    lines.push(field("Start Time", &format_timestamp(workflow.start_time.as_ref(), "Unknown")));
+    lines.push(field("Execution Time", &format_timestamp(workflow.execution_time.as_ref(), "N/A")));
+    lines.push(field("Close Time", &format_timestamp(workflow.close_time.as_ref(), "N/A")));
+    lines.push(Line::from(vec![
+        label("History"),
+        Span::styled(history_size, Style::default().fg(history_color)),
+    ]));
+    lines.push(field("State Transitions", &workflow.state_transition_count.to_string()));
+    if let Some(parent) = &workflow.parent_execution {
+        lines.push(field("Parent", &format_execution(parent)));
+    }
+    if let Some(root) = workflow.root_execution.as_ref().filter(|root| root.workflow_id != execution.workflow_id) {
+        lines.push(field("Root", &format_execution(root)));
+    }
+    if let Some(pending_task) = super::workflow_detail::pending_task_spans(state) {
+        lines.push(Line::from(pending_task));
+    }
+    if let Some(backlog) = super::workflow_detail::backlog_spans(state, workflow) {
+        lines.push(Line::from(backlog));
+    }
+    if let Some(run_id) = state.continued_as_run_id() {
+        lines.push(field("Continued As", run_id));
+    }
+
+    let search_attributes = workflow.search_attributes.as_ref().map(|sa| &sa.indexed_fields);
+    lines.push(Line::from(""));
+    lines.push(section("Search Attributes"));
+    lines.extend(payload_map_lines(search_attributes, format_search_attribute));
+
+    let memo = workflow.memo.as_ref().map(|memo| &memo.fields);
+    lines.push(Line::from(""));
+    lines.push(section("Memo"));
+    lines.extend(payload_map_lines(memo, format_payload));
+
+    lines
+}
+
+fn section(title: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        title.to_string(),
+        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+    ))
+}
+
+fn label(name: &str) -> Span<'static> {
+    Span::styled(format!("{}: ", name), Style::default().fg(Color::Cyan))
+}
+
+fn field(name: &str, value: &str) -> Line<'static> {
+    Line::from(vec![label(name), Span::styled(value.to_string(), Style::default().fg(Color::White))])
+}
+
+fn format_execution(execution: &WorkflowExecution) -> String {
+    if execution.run_id.is_empty() {
+        execution.workflow_id.clone()
+    } else {
+        format!("{} / {}", execution.workflow_id, execution.run_id)
+    }
+}
+
+/// One `key: value` line per entry, sorted by key; multi-line values continue indented
+fn payload_map_lines(fields: Option<&HashMap<String, Payload>>, format: fn(&Payload) -> String) -> Vec<Line<'static>> {
+    let Some(fields) = fields.filter(|fields| !fields.is_empty()) else {
+        return vec![Line::from(Span::styled("  (none)", Style::default().fg(Color::DarkGray)))];
+    };
+    let mut keys: Vec<&String> = fields.keys().collect();
+    keys.sort();
+    let mut lines = Vec::new();
+    for key in keys {
+        let value = format(&fields[key]);
+        let mut value_lines = value.lines();
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {}: ", key), Style::default().fg(Color::Cyan)),
+            Span::styled(value_lines.next().unwrap_or_default().to_string(), Style::default().fg(Color::White)),
+        ]));
+        lines.extend(value_lines.map(|line| Line::from(Span::raw(format!("    {}", line)))));
+    }
+    lines
+}
@@ -1,4 +1,14 @@
 pub mod workflows;
 pub mod namespaces;
+pub mod all_namespaces;
+pub mod batches;
+pub mod nexus;
 pub mod workflow_detail;
+pub mod run_diff;
+pub mod metadata;
 pub mod help;
+pub mod connection_info;
+pub mod namespace_switcher;
+pub mod namespace_fallback;
+pub mod quit_confirm;
+pub mod setup;
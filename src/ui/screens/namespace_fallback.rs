@@ -0,0 +1,47 @@
+use crate::app::App;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn render(app: &App, fallback: &str, frame: &mut Frame, area: Rect) {
+    // Create a centered modal area
+    let modal_width = 60.min(area.width);
+    let modal_height = 9.min(area.height);
+    let modal_x = area.x + (area.width.saturating_sub(modal_width)) / 2;
+    let modal_y = area.y + (area.height.saturating_sub(modal_height)) / 2;
+    let modal_area = Rect::new(modal_x, modal_y, modal_width, modal_height);
+
+    // Clear the area
+    frame.render_widget(Clear, modal_area);
+
+    let lines = vec![
+        Line::from(vec![
+            Span::raw("Namespace "),
+            Span::styled(app.current_namespace.clone(), Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" was deleted or is not accessible with the current credentials."),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("Switch to "),
+            Span::styled(fallback.to_string(), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::raw("?"),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "y/Enter: switch | any other key: stay",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Namespace Unavailable")
+            .style(Style::default().fg(Color::Yellow)),
+    );
+    frame.render_widget(paragraph, modal_area);
+}
@@ -0,0 +1,88 @@
+use crate::app::App;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let Some(switcher) = &app.namespace_switcher else {
+        return;
+    };
+
+    // Sit in the upper part of the screen like a command palette
+    let modal_width = 60.min(area.width);
+    let modal_height = 14.min(area.height);
+    let modal_x = area.x + (area.width.saturating_sub(modal_width)) / 2;
+    let modal_y = area.y + area.height.saturating_sub(modal_height) / 4;
+    let modal_area = Rect::new(modal_x, modal_y, modal_width, modal_height);
+
+    frame.render_widget(Clear, modal_area);
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(":", Style::default().fg(Color::Yellow)),
+            Span::raw(switcher.input.clone()),
+            Span::styled("_", Style::default().fg(Color::Yellow)),
+        ]),
+        Line::from(""),
+    ];
+
+    let matches = app.namespace_switcher_matches();
+    let visible = modal_height.saturating_sub(4) as usize; // Borders, input and spacer
+
+    if matches.is_empty() {
+        let message = if app.namespace_list_state.loading {
+            "Loading namespaces...".to_string()
+        } else if switcher.query().is_empty() {
+            "No namespaces loaded".to_string()
+        } else {
+            format!("No match - Enter switches to '{}'", switcher.query())
+        };
+        lines.push(Line::from(Span::styled(message, Style::default().fg(Color::DarkGray))));
+    } else {
+        // Keep the selection in view when there are more matches than rows
+        let start = switcher.selected.saturating_sub(visible.saturating_sub(1));
+        for (i, name) in matches.iter().enumerate().skip(start).take(visible) {
+            let (marker, style) = if i == switcher.selected {
+                ("> ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            } else {
+                ("  ", Style::default())
+            };
+            let mut spans = vec![Span::styled(format!("{}{}", marker, name), style)];
+            if *name == app.current_namespace {
+                spans.push(Span::styled(" (current)", Style::default().fg(Color::DarkGray)));
+            }
+            lines.push(Line::from(spans));
+        }
+    }
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Switch Namespace ({} matches)", matches.len()))
+            .style(Style::default().fg(Color::Cyan)),
+    );
+
+    frame.render_widget(paragraph, modal_area);
+}
+
+/// Small overlay shown from sending a switch until the server confirms or rejects it
+pub fn render_progress(app: &App, namespace: &str, frame: &mut Frame, area: Rect) {
+    let text = format!("{} Switching to {}...", app.spinner(), namespace);
+    let modal_width = (text.chars().count() as u16 + 4).min(area.width);
+    let modal_height = 3.min(area.height);
+    let modal_x = area.x + (area.width.saturating_sub(modal_width)) / 2;
+    let modal_y = area.y + (area.height.saturating_sub(modal_height)) / 2;
+    let modal_area = Rect::new(modal_x, modal_y, modal_width, modal_height);
+
+    frame.render_widget(Clear, modal_area);
+    let paragraph = Paragraph::new(Line::from(Span::styled(text, Style::default().fg(Color::Yellow)))).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Yellow)),
+    );
+    frame.render_widget(paragraph, modal_area);
+}
@@ -1,31 +1,55 @@
-use crate::app::{App, ConnectionStatus};
+use crate::app::{App, ConnectionStatus, NamespaceDialog, NamespaceStateFilter};
+use crate::generated::temporal::api::{enums::v1::ArchivalState, workflowservice::v1::DescribeNamespaceResponse};
 use ratatui::{
     layout::{Constraint, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
     Frame,
 };
 
 pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    render_namespace_list(app, frame, area);
+
+    // Render the selected namespace's details if open
+    if app.namespace_list_state.show_detail {
+        if let Some(namespace) = app.namespace_list_state.selected_namespace() {
+            render_namespace_detail(app, namespace, frame, area);
+        }
+    }
+
+    // Render create/update dialog overlay if needed
+    if let Some(dialog) = &app.namespace_list_state.dialog {
+        render_namespace_dialog(dialog, frame, area);
+    }
+
+    // Render message overlay if needed
+    if let Some(message) = &app.namespace_list_state.message {
+        render_message(message, frame, area);
+    }
+}
+
+fn render_namespace_list(app: &App, frame: &mut Frame, area: Rect) {
     let state = &app.namespace_list_state;
 
     // Show error message if there is one
     if let Some(error) = &state.error {
-        let lines = vec![
+        let mut lines = vec![
             Line::from(""),
             Line::from(Span::styled(
                 "⚠ An error occurred:",
                 Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
             )),
             Line::from(""),
-            Line::from(Span::styled(error, Style::default().fg(Color::White))),
+        ];
+        lines.extend(crate::ui::error_message_lines(error, app.show_error_details));
+        lines.extend([
             Line::from(""),
             Line::from(Span::styled(
                 "Press 'r' to retry or 'ESC' to go back",
                 Style::default().fg(Color::Yellow),
             )),
-        ];
+        ]);
         let paragraph = Paragraph::new(lines)
             .block(
                 Block::default()
@@ -40,7 +64,7 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     // Show loading indicator
     if state.loading {
         let spinner = app.spinner();
-        let loading_text = format!("{} Loading namespaces...", spinner);
+        let loading_text = format!("{} Loading namespaces... (ESC to cancel)", spinner);
         let paragraph = Paragraph::new(loading_text)
             .block(
                 Block::default()
@@ -76,10 +100,17 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
         return;
     }
 
+    let visible_items = state.visible_items();
+
     // Show empty message if no namespaces
-    if state.items.is_empty() {
+    if visible_items.is_empty() {
+        let message = if state.items.is_empty() {
+            "No namespaces found".to_string()
+        } else {
+            format!("No namespaces match filter: {}", state.state_filter.label())
+        };
         let lines = vec![
-            Line::from("No namespaces found"),
+            Line::from(message),
             Line::from(""),
             Line::from(Span::styled(
                 "Press 'r' to refresh",
@@ -97,19 +128,13 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     }
 
     // Build the table
-    let header = Row::new(vec![
-        Cell::from("Namespace"),
-        Cell::from("Description"),
-        Cell::from("State"),
-    ])
-    .style(
-        Style::default()
-            .fg(Color::Yellow)
-            .add_modifier(Modifier::BOLD),
-    );
+    let header_texts = ["Namespace", "Description", "State"];
+    let mut measured = vec![0; header_texts.len()];
+    crate::ui::measure_row(&mut measured, &header_texts);
+    let header = Row::new(header_texts.map(Cell::from))
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
 
-    let rows: Vec<Row> = state
-        .items
+    let rows: Vec<Row> = visible_items
         .iter()
         .map(|ns_response| {
             let (name, description, state_str) = if let Some(info) = &ns_response.namespace_info {
@@ -128,26 +153,41 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
                 Style::default()
             };
 
+            let label = if app.config.pinned_namespaces.contains(&name) {
+                format!("★ {}", name)
+            } else {
+                name
+            };
+            crate::ui::measure_row(&mut measured, &[&label, &description, &state_str]);
+
             Row::new(vec![
-                Cell::from(name).style(style),
+                Cell::from(label).style(style),
                 Cell::from(description),
                 Cell::from(state_str),
             ])
         })
         .collect();
 
-    let widths = [
-        Constraint::Percentage(30),
-        Constraint::Percentage(50),
-        Constraint::Percentage(20),
-    ];
-
-    let title = format!(
-        "Namespaces ({} items) - Current: {}",
-        state.items.len(),
-        app.current_namespace
+    let widths = crate::ui::fit_widths(
+        area,
+        &measured,
+        vec![
+            Constraint::Percentage(30),
+            Constraint::Percentage(50),
+            Constraint::Percentage(20),
+        ],
     );
 
+    let mut title = if state.state_filter == NamespaceStateFilter::All {
+        format!("Namespaces ({} items)", state.items.len())
+    } else {
+        format!("Namespaces ({} of {} items)", visible_items.len(), state.items.len())
+    };
+    title = format!("{} - Current: {}", title, app.current_namespace);
+    if state.state_filter != NamespaceStateFilter::All {
+        title = format!("{} [Filter: {}]", title, state.state_filter.label());
+    }
+
     let table = Table::new(rows, widths)
         .header(header)
         .block(
@@ -165,6 +205,145 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     frame.render_stateful_widget(table, area, &mut state.table_state.clone());
 }
 
+fn render_namespace_dialog(dialog: &NamespaceDialog, frame: &mut Frame, area: Rect) {
+    // Create a centered dialog area
+    let dialog_width = 60.min(area.width);
+    let dialog_height = (dialog.fields().len() as u16 * 2 + 4).min(area.height);
+    let dialog_x = area.x + (area.width.saturating_sub(dialog_width)) / 2;
+    let dialog_y = area.y + (area.height.saturating_sub(dialog_height)) / 2;
+    let dialog_area = Rect::new(dialog_x, dialog_y, dialog_width, dialog_height);
+
+    frame.render_widget(Clear, dialog_area);
+
+    let mut lines = vec![];
+    for (i, field) in dialog.fields().iter().enumerate() {
+        let focused = i == dialog.focused;
+        let label_style = if focused {
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Cyan)
+        };
+        lines.push(Line::from(Span::styled(format!("{}:", field.label()), label_style)));
+        lines.push(Line::from(vec![
+            Span::raw(if focused { "> " } else { "  " }),
+            Span::raw(dialog.value(*field).to_string()),
+            Span::styled(if focused { "_" } else { "" }, Style::default().fg(Color::Yellow)),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Tab next field | Enter save | ESC cancel",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let title = if dialog.is_update {
+        format!("Update Namespace: {}", dialog.name)
+    } else {
+        "Register Namespace".to_string()
+    };
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .style(Style::default().fg(Color::White)),
+    );
+
+    frame.render_widget(paragraph, dialog_area);
+}
+
+fn render_namespace_detail(app: &App, namespace: &DescribeNamespaceResponse, frame: &mut Frame, area: Rect) {
+    let info = namespace.namespace_info.clone().unwrap_or_default();
+    let config = namespace.config.clone().unwrap_or_default();
+    let retention = config
+        .workflow_execution_retention_ttl
+        .as_ref()
+        .map(|ttl| format!("{} days", ttl.seconds / 86400))
+        .unwrap_or_else(|| "N/A".to_string());
+
+    let field = |name: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{}: ", name), Style::default().fg(Color::Cyan)),
+            Span::styled(value, Style::default().fg(Color::White)),
+        ])
+    };
+    let mut lines = vec![
+        field("Name", info.name.clone()),
+        field("ID", info.id.clone()),
+        field("State", get_namespace_state(info.state)),
+        field("Description", info.description.clone()),
+        field("Owner", info.owner_email.clone()),
+        field("Retention", retention),
+        field("History Archival", archival_label(config.history_archival_state, &config.history_archival_uri)),
+        field("Visibility Archival", archival_label(config.visibility_archival_state, &config.visibility_archival_uri)),
+        field("Global", namespace.is_global_namespace.to_string()),
+    ];
+    if namespace.is_global_namespace {
+        lines.push(field("Failover Version", namespace.failover_version.to_string()));
+    }
+    let mut data: Vec<_> = info.data.iter().collect();
+    data.sort();
+    for (key, value) in data {
+        lines.push(field(&format!("Data {}", key), value.clone()));
+    }
+    lines.push(Line::from(""));
+    let hint = if info.name == app.current_namespace {
+        "This is the current namespace | ESC close".to_string()
+    } else {
+        "s switch to this namespace | ESC close".to_string()
+    };
+    lines.push(Line::from(Span::styled(hint, Style::default().fg(Color::DarkGray))));
+
+    let detail_width = 80.min(area.width);
+    let detail_height = (lines.len() as u16 + 2).min(area.height);
+    let detail_x = area.x + (area.width.saturating_sub(detail_width)) / 2;
+    let detail_y = area.y + (area.height.saturating_sub(detail_height)) / 2;
+    let detail_area = Rect::new(detail_x, detail_y, detail_width, detail_height);
+
+    frame.render_widget(Clear, detail_area);
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Namespace: {}", info.name))
+            .style(Style::default().fg(Color::Cyan)),
+    );
+    frame.render_widget(paragraph, detail_area);
+}
+
+/// Whether archival is on, with the archive's URI when it is
+fn archival_label(state: i32, uri: &str) -> String {
+    match ArchivalState::try_from(state) {
+        Ok(ArchivalState::Enabled) if !uri.is_empty() => format!("Enabled ({})", uri),
+        Ok(ArchivalState::Enabled) => "Enabled".to_string(),
+        Ok(ArchivalState::Disabled) => "Disabled".to_string(),
+        _ => "Unspecified".to_string(),
+    }
+}
+
+fn render_message(message: &str, frame: &mut Frame, area: Rect) {
+    let msg_width = 60.min(area.width);
+    let msg_height = 5.min(area.height);
+    let msg_x = area.x + (area.width.saturating_sub(msg_width)) / 2;
+    let msg_y = area.y + (area.height.saturating_sub(msg_height)) / 2;
+    let msg_area = Rect::new(msg_x, msg_y, msg_width, msg_height);
+
+    frame.render_widget(Clear, msg_area);
+
+    let paragraph = Paragraph::new(vec![
+        Line::from(""),
+        Line::from(Span::styled(message.to_string(), Style::default().fg(Color::White))),
+    ])
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Namespaces")
+            .style(Style::default().fg(Color::Yellow)),
+    )
+    .alignment(ratatui::layout::Alignment::Center);
+
+    frame.render_widget(paragraph, msg_area);
+}
+
 fn get_namespace_state(state: i32) -> String {
     // Namespace state enum values
     match state {
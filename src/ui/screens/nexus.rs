@@ -0,0 +1,175 @@
+use crate::app::{App, ConnectionStatus};
+use crate::generated::temporal::api::nexus::v1::{endpoint_target, Endpoint};
+use crate::temporal::payload::format_search_attribute;
+use crate::temporal::time::format_timestamp_short;
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    Frame,
+};
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let state = &app.nexus_list_state;
+
+    // Show error message if there is one
+    if let Some(error) = &state.error {
+        let mut lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                "⚠ An error occurred:",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+        ];
+        lines.extend(crate::ui::error_message_lines(error, app.show_error_details));
+        lines.extend([
+            Line::from(""),
+            Line::from(Span::styled(
+                "Press 'r' to retry or 'ESC' to go back",
+                Style::default().fg(Color::Yellow),
+            )),
+        ]);
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Nexus Endpoints - Error")
+                .style(Style::default().fg(Color::Red)),
+        );
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    // Show loading indicator
+    if state.loading {
+        let loading_text = format!("{} Loading Nexus endpoints... (ESC to cancel)", app.spinner());
+        let paragraph = Paragraph::new(loading_text)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Nexus Endpoints")
+                    .style(Style::default().fg(Color::Yellow)),
+            )
+            .style(Style::default().fg(Color::Yellow));
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    // Show connection status if not connected
+    if !matches!(app.connection_status, ConnectionStatus::Connected) {
+        let (status_text, color) = match &app.connection_status {
+            ConnectionStatus::Disconnected => ("Not connected to Temporal".to_string(), Color::Red),
+            ConnectionStatus::Connecting => (format!("{} Connecting to Temporal...", app.spinner()), Color::Yellow),
+            ConnectionStatus::Error(e) => (format!("Connection error: {}", e), Color::Red),
+            ConnectionStatus::Connected => (String::new(), Color::White),
+        };
+        let paragraph = Paragraph::new(status_text)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Nexus Endpoints")
+                    .style(Style::default().fg(color)),
+            )
+            .style(Style::default().fg(color));
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    if state.items.is_empty() {
+        let lines = vec![
+            Line::from("No Nexus endpoints on this cluster"),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Press 'r' to refresh",
+                Style::default().fg(Color::Yellow),
+            )),
+        ];
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Nexus Endpoints")
+                .style(Style::default().fg(Color::White)),
+        );
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    let header_texts = ["Name", "Target", "Description", "Created", "ID"];
+    let mut measured = vec![0; header_texts.len()];
+    crate::ui::measure_row(&mut measured, &header_texts);
+    let header = Row::new(header_texts.map(Cell::from))
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> = state
+        .items
+        .iter()
+        .map(|endpoint| {
+            let name = endpoint.spec.as_ref().map(|spec| spec.name.clone()).unwrap_or_default();
+            let (target, target_color) = format_target(endpoint);
+            let description = endpoint
+                .spec
+                .as_ref()
+                .and_then(|spec| spec.description.as_ref())
+                .map(format_search_attribute)
+                .unwrap_or_default();
+            let created = format_timestamp_short(endpoint.created_time.as_ref(), "Unknown");
+            crate::ui::measure_row(&mut measured, &[&name, &target, &description, &created, &endpoint.id]);
+            Row::new(vec![
+                Cell::from(name),
+                Cell::from(target).style(Style::default().fg(target_color)),
+                Cell::from(description),
+                Cell::from(created),
+                Cell::from(endpoint.id.clone()).style(Style::default().fg(Color::DarkGray)),
+            ])
+        })
+        .collect();
+
+    let widths = crate::ui::fit_widths(
+        area,
+        &measured,
+        vec![
+            Constraint::Percentage(20),
+            Constraint::Percentage(30),
+            Constraint::Min(10),
+            Constraint::Length(20),
+            Constraint::Length(36),
+        ],
+    );
+
+    let title = format!("Nexus Endpoints ({} items) - cluster-wide", state.items.len());
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .style(Style::default().fg(Color::White)),
+        )
+        .row_highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    frame.render_stateful_widget(table, area, &mut state.table_state.clone());
+}
+
+/// Where an endpoint routes operations: a worker's namespace and task queue, or an external URL
+fn format_target(endpoint: &Endpoint) -> (String, Color) {
+    let variant = endpoint
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.target.as_ref())
+        .and_then(|target| target.variant.as_ref());
+    match variant {
+        Some(endpoint_target::Variant::Worker(worker)) => {
+            (format!("worker: {} / {}", worker.namespace, worker.task_queue), Color::Cyan)
+        }
+        Some(endpoint_target::Variant::External(external)) => {
+            (format!("external: {}", external.url), Color::Magenta)
+        }
+        None => ("-".to_string(), Color::Gray),
+    }
+}
@@ -0,0 +1,46 @@
+use crate::app::App;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    // Create a centered modal area
+    let modal_width = 60.min(area.width);
+    let modal_height = 8.min(area.height);
+    let modal_x = area.x + (area.width.saturating_sub(modal_width)) / 2;
+    let modal_y = area.y + (area.height.saturating_sub(modal_height)) / 2;
+    let modal_area = Rect::new(modal_x, modal_y, modal_width, modal_height);
+
+    // Clear the area
+    frame.render_widget(Clear, modal_area);
+
+    let count = app.pending_operations;
+    let lines = vec![
+        Line::from(vec![
+            Span::styled(
+                format!("{} operation{}", count, if count == 1 { "" } else { "s" }),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" still in progress; quitting now means not knowing whether it took effect."),
+        ]),
+        Line::from(""),
+        Line::from("Quit anyway?"),
+        Line::from(""),
+        Line::from(Span::styled(
+            "y/Enter/q: quit | any other key: wait for the result",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Operation In Progress")
+            .style(Style::default().fg(Color::Yellow)),
+    );
+    frame.render_widget(paragraph, modal_area);
+}
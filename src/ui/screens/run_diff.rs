@@ -0,0 +1,87 @@
+use crate::app::{App, RunDiff};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Side-by-side start inputs of two runs; lines that differ from the other run are highlighted
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let Some(diff) = &app.workflow_detail_state.run_diff else {
+        return;
+    };
+
+    // Create a large modal area (90% of screen)
+    let modal_width = (area.width * 9) / 10;
+    let modal_height = (area.height * 9) / 10;
+    let modal_x = (area.width.saturating_sub(modal_width)) / 2;
+    let modal_y = (area.height.saturating_sub(modal_height)) / 2;
+    let modal_area = Rect::new(modal_x, modal_y, modal_width, modal_height);
+
+    // Clear the area
+    frame.render_widget(Clear, modal_area);
+
+    let mut title = vec![Span::raw(format!("Run input diff: {}", diff.workflow_id))];
+    if let Some(notice) = &diff.notice {
+        title.push(Span::styled(format!(" | {}", notice), Style::default().fg(Color::Yellow)));
+    }
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Line::from(title))
+        .title_bottom(" ←/→ walk the chain | ↑/↓ scroll | ESC close ")
+        .style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(modal_area);
+    frame.render_widget(block, modal_area);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(inner);
+
+    render_run(app, diff, frame, columns[0], true);
+    render_run(app, diff, frame, columns[1], false);
+}
+
+/// One column of the diff: the earlier run on the left, its successor on the right
+fn render_run(app: &App, diff: &RunDiff, frame: &mut Frame, area: Rect, left: bool) {
+    let (run_id, other_run_id, role) = if left {
+        (&diff.left_run_id, &diff.right_run_id, "earlier")
+    } else {
+        (&diff.right_run_id, &diff.left_run_id, "continued as")
+    };
+    let run = diff.runs.get(run_id);
+    let other = diff.runs.get(other_run_id);
+
+    let lines: Vec<Line> = match run {
+        Some(run) => run
+            .input
+            .iter()
+            .enumerate()
+            .map(|(index, line)| {
+                let differs = other.is_some_and(|other| other.input.get(index) != Some(line));
+                let style = if differs {
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::White)
+                };
+                Line::from(Span::styled(line.clone(), style))
+            })
+            .collect(),
+        None => vec![Line::from(Span::styled(
+            format!("{} Loading run input...", app.spinner()),
+            Style::default().fg(Color::Yellow),
+        ))],
+    };
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Run {} ({})", run_id, role))
+                .style(Style::default().fg(Color::White)),
+        )
+        .scroll((diff.scroll, 0));
+    frame.render_widget(paragraph, area);
+}
@@ -0,0 +1,75 @@
+use crate::app::{App, ConnectionStatus, SetupField};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let state = &app.setup_state;
+
+    // Create a centered form area
+    let form_width = 64.min(area.width);
+    let form_height = 16.min(area.height);
+    let form_x = area.x + (area.width.saturating_sub(form_width)) / 2;
+    let form_y = area.y + (area.height.saturating_sub(form_height)) / 2;
+    let form_area = Rect::new(form_x, form_y, form_width, form_height);
+
+    frame.render_widget(Clear, form_area);
+
+    let mut lines = vec![
+        Line::from("No connection profile is configured yet."),
+        Line::from(Span::styled(
+            "Enter your Temporal server details to create a config file",
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::from(""),
+    ];
+
+    for field in SetupField::ALL {
+        let focused = state.focused == field;
+        let value = match field {
+            // Don't echo secrets back to the screen
+            SetupField::ApiKey => "*".repeat(state.api_key.chars().count()),
+            _ => state.value(field).to_string(),
+        };
+        let label_style = if focused {
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Cyan)
+        };
+
+        lines.push(Line::from(Span::styled(format!("{}:", field.label()), label_style)));
+        lines.push(Line::from(vec![
+            Span::raw(if focused { "> " } else { "  " }),
+            Span::raw(value),
+            Span::styled(if focused { "_" } else { "" }, Style::default().fg(Color::Yellow)),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    if app.connection_status == ConnectionStatus::Connecting && state.error.is_none() {
+        lines.push(Line::from(Span::styled(
+            format!("{} Connecting...", app.spinner()),
+            Style::default().fg(Color::Yellow),
+        )));
+    } else if let Some(error) = &state.error {
+        lines.push(Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red))));
+    } else {
+        lines.push(Line::from(Span::styled(
+            "An API key also enables TLS (Temporal Cloud)",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Welcome to Tuiporal - Setup")
+            .style(Style::default().fg(Color::White)),
+    );
+
+    frame.render_widget(paragraph, form_area);
+}
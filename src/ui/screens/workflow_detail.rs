@@ -1,6 +1,14 @@
-use crate::app::{App, WorkflowOperation};
-use crate::generated::temporal::api::enums::v1::WorkflowExecutionStatus;
-use chrono::{DateTime, Utc};
+use crate::app::{App, WorkflowDetailState, WorkflowOperation, FOLLOW_RATE_WINDOW};
+use crate::generated::temporal::api::enums::v1::{
+    EventType, PendingWorkflowTaskState, ResetReapplyType, WorkflowExecutionStatus,
+};
+use crate::generated::temporal::api::failure::v1::Failure;
+use crate::generated::temporal::api::history::v1::HistoryEvent;
+use crate::generated::temporal::api::workflow::v1::WorkflowExecutionInfo;
+use crate::history::{event_failure, event_references, HistoryRow};
+use crate::temporal::payload::{decode_debug_bytes, format_payloads};
+use crate::temporal::time::{format_timestamp, format_timestamp_short};
+use prost::Message;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect, Alignment},
     style::{Color, Modifier, Style},
@@ -14,20 +22,22 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
 
     // Show error message if there is one
     if let Some(error) = &state.error {
-        let lines = vec![
+        let mut lines = vec![
             Line::from(""),
             Line::from(Span::styled(
                 "⚠ An error occurred:",
                 Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
             )),
             Line::from(""),
-            Line::from(Span::styled(error, Style::default().fg(Color::White))),
+        ];
+        lines.extend(crate::ui::error_message_lines(error, app.show_error_details));
+        lines.extend([
             Line::from(""),
             Line::from(Span::styled(
-                "Press 'ESC' to go back",
+                "Press 'r' to retry or 'ESC' to go back",
                 Style::default().fg(Color::Yellow),
             )),
-        ];
+        ]);
         let paragraph = Paragraph::new(lines)
             .block(
                 Block::default()
@@ -42,7 +52,7 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     // Show loading indicator
     if state.loading {
         let spinner = app.spinner();
-        let loading_text = format!("{} Loading workflow details...", spinner);
+        let loading_text = format!("{} Loading workflow details... (ESC to cancel)", spinner);
         let paragraph = Paragraph::new(loading_text)
             .block(
                 Block::default()
@@ -72,7 +82,7 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(9),  // Metadata section
+            Constraint::Length(metadata_height(app)), // Metadata section
             Constraint::Min(0),      // Event history table
         ])
         .split(area);
@@ -89,20 +99,55 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     }
 
     // Render success message overlay if needed
-    if state.success_message.is_some() {
-        render_success_message(app, frame, area);
+    if let Some(message) = &state.success_message {
+        render_message(frame, area, "Success", message, Color::Green);
+    }
+
+    // Render notice overlay if needed
+    if let Some(notice) = &state.notice {
+        render_message(frame, area, "Notice", notice, Color::Yellow);
+    }
+
+    // Render update outcome overlay if needed
+    if state.update_outcome.is_some() {
+        render_update_outcome(app, frame, area);
     }
 
     // Render event detail modal if needed
     if state.show_event_detail {
         render_event_detail_modal(app, frame, area);
     }
+
+    // Render the run input diff if needed
+    if state.run_diff.is_some() {
+        super::run_diff::render(app, frame, area);
+    }
+
+    // Render the full metadata if expanded
+    if state.show_metadata {
+        super::metadata::render(app, frame, area);
+    }
 }
 
+/// Temporal's default per-workflow history limits; the server terminates runs that reach either
+const HISTORY_EVENT_LIMIT: i64 = 51_200;
+const HISTORY_SIZE_LIMIT: i64 = 50 * 1024 * 1024;
+/// Sizes past the server's default warning thresholds show yellow, and red at 80% of a limit
+const HISTORY_EVENT_WARNING: i64 = 10_240;
+const HISTORY_SIZE_WARNING: i64 = 10 * 1024 * 1024;
+/// A pending workflow task on this attempt or later is failing or timing out repeatedly
+const WORKFLOW_TASK_ATTEMPT_WARNING: i32 = 3;
+
 fn render_workflow_metadata(app: &App, frame: &mut Frame, area: Rect) {
     let state = &app.workflow_detail_state;
     let workflow = state.workflow.as_ref().unwrap();
 
+    // Visibility can lag behind a run, leaving only the default info; don't show it as all "Unknown"
+    if workflow.execution.is_none() {
+        render_metadata_unavailable(app, frame, area);
+        return;
+    }
+
     let workflow_id = workflow
         .execution
         .as_ref()
@@ -133,39 +178,83 @@ fn render_workflow_metadata(app: &App, frame: &mut Frame, area: Rect) {
         WorkflowExecutionStatus::TimedOut => "Timed Out",
         _ => "Unknown",
     };
-    let status_color = match status {
-        WorkflowExecutionStatus::Running => Color::Yellow,
-        WorkflowExecutionStatus::Completed => Color::Green,
-        WorkflowExecutionStatus::Failed => Color::Red,
-        WorkflowExecutionStatus::Canceled => Color::Magenta,
-        WorkflowExecutionStatus::Terminated => Color::Red,
-        WorkflowExecutionStatus::TimedOut => Color::Red,
-        _ => Color::White,
-    };
-
-    let start_time = workflow
-        .start_time
-        .as_ref()
-        .and_then(|t| {
-            let seconds = t.seconds as i64;
-            let nanos = t.nanos as u32;
-            DateTime::from_timestamp(seconds, nanos)
-                .map(|dt: DateTime<Utc>| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
-        })
-        .unwrap_or_else(|| "Unknown".to_string());
+    let status_str = format!("{} {}", crate::ui::status_icon(status), status_str);
+    let status_color = status_color(status);
+
+    let start_time = format_timestamp(workflow.start_time.as_ref(), "Unknown");
+    let close_time = format_timestamp(workflow.close_time.as_ref(), "N/A");
+
+    let (history_size, history_color) = history_size_label(workflow.history_length, workflow.history_size_bytes);
+    // Older servers don't report transitions; leave them out rather than show 0
+    let transitions = (workflow.state_transition_count > 0).then(|| {
+        vec![
+            Span::styled("  State transitions: ", Style::default().fg(Color::Cyan)),
+            Span::raw(workflow.state_transition_count.to_string()),
+        ]
+    });
+    let backlog = backlog_spans(state, workflow);
+    let pending_task = pending_task_spans(state);
+    let continued_as = state.continued_as_run_id().map(|run_id| {
+        vec![
+            Span::styled("→ continued as ", Style::default().fg(Color::Cyan)),
+            Span::raw(run_id.to_string()),
+            Span::styled(" (C to open)", Style::default().fg(Color::DarkGray)),
+        ]
+    });
+
+    // Compact mode packs the metadata into three unbordered lines
+    if app.compact {
+        let mut status_line = vec![
+            Span::styled("Type: ", Style::default().fg(Color::Cyan)),
+            Span::raw(workflow_type),
+            Span::styled("  Status: ", Style::default().fg(Color::Cyan)),
+            Span::styled(status_str, Style::default().fg(status_color).add_modifier(Modifier::BOLD)),
+        ];
+        if let Some(backlog) = backlog {
+            status_line.push(Span::raw("  "));
+            status_line.extend(backlog);
+        }
+        if let Some(pending_task) = pending_task {
+            status_line.push(Span::raw("  "));
+            status_line.extend(pending_task);
+        }
+        if let Some(continued_as) = continued_as {
+            status_line.push(Span::raw("  "));
+            status_line.extend(continued_as);
+        }
+        let mut time_line = vec![
+            Span::styled("Start: ", Style::default().fg(Color::Cyan)),
+            Span::raw(start_time),
+            Span::styled("  Close: ", Style::default().fg(Color::Cyan)),
+            Span::raw(close_time),
+            Span::styled("  History: ", Style::default().fg(Color::Cyan)),
+            Span::styled(history_size, Style::default().fg(history_color)),
+        ];
+        time_line.extend(transitions.unwrap_or_default());
+        let lines = vec![
+            Line::from(vec![
+                Span::styled("ID: ", Style::default().fg(Color::Cyan)),
+                Span::raw(workflow_id),
+                Span::styled("  Run: ", Style::default().fg(Color::Cyan)),
+                Span::raw(run_id),
+            ]),
+            Line::from(status_line),
+            Line::from(time_line),
+        ];
+        frame.render_widget(Paragraph::new(lines), area);
+        return;
+    }
 
-    let close_time = workflow
-        .close_time
-        .as_ref()
-        .and_then(|t| {
-            let seconds = t.seconds as i64;
-            let nanos = t.nanos as u32;
-            DateTime::from_timestamp(seconds, nanos)
-                .map(|dt: DateTime<Utc>| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
-        })
-        .unwrap_or_else(|| "N/A".to_string());
+    let mut status_line = vec![
+        Span::styled("Status: ", Style::default().fg(Color::Cyan)),
+        Span::styled(status_str, Style::default().fg(status_color).add_modifier(Modifier::BOLD)),
+    ];
+    if let Some(backlog) = backlog {
+        status_line.push(Span::raw("  "));
+        status_line.extend(backlog);
+    }
 
-    let lines = vec![
+    let mut lines = vec![
         Line::from(vec![
             Span::styled("Workflow ID: ", Style::default().fg(Color::Cyan)),
             Span::raw(workflow_id),
@@ -178,10 +267,7 @@ fn render_workflow_metadata(app: &App, frame: &mut Frame, area: Rect) {
             Span::styled("Type: ", Style::default().fg(Color::Cyan)),
             Span::raw(workflow_type),
         ]),
-        Line::from(vec![
-            Span::styled("Status: ", Style::default().fg(Color::Cyan)),
-            Span::styled(status_str, Style::default().fg(status_color).add_modifier(Modifier::BOLD)),
-        ]),
+        Line::from(status_line),
         Line::from(vec![
             Span::styled("Start Time: ", Style::default().fg(Color::Cyan)),
             Span::raw(start_time),
@@ -190,7 +276,23 @@ fn render_workflow_metadata(app: &App, frame: &mut Frame, area: Rect) {
             Span::styled("Close Time: ", Style::default().fg(Color::Cyan)),
             Span::raw(close_time),
         ]),
+        Line::from(
+            [
+                vec![
+                    Span::styled("History: ", Style::default().fg(Color::Cyan)),
+                    Span::styled(history_size, Style::default().fg(history_color)),
+                ],
+                transitions.unwrap_or_default(),
+            ]
+            .concat(),
+        ),
     ];
+    if let Some(continued_as) = continued_as {
+        lines.insert(4, Line::from(continued_as));
+    }
+    if let Some(pending_task) = pending_task {
+        lines.insert(4, Line::from(pending_task));
+    }
 
     let paragraph = Paragraph::new(lines)
         .block(
@@ -203,6 +305,141 @@ fn render_workflow_metadata(app: &App, frame: &mut Frame, area: Rect) {
     frame.render_widget(paragraph, area);
 }
 
+pub(super) fn status_color(status: WorkflowExecutionStatus) -> Color {
+    match status {
+        WorkflowExecutionStatus::Running => Color::Yellow,
+        WorkflowExecutionStatus::Completed => Color::Green,
+        WorkflowExecutionStatus::Failed => Color::Red,
+        WorkflowExecutionStatus::Canceled => Color::Magenta,
+        WorkflowExecutionStatus::Terminated => Color::Red,
+        WorkflowExecutionStatus::TimedOut => Color::Red,
+        _ => Color::White,
+    }
+}
+
+/// "Backlog: N tasks (P pollers)" for the run's task queue, red when tasks wait with nobody polling
+pub(super) fn backlog_spans(state: &WorkflowDetailState, workflow: &WorkflowExecutionInfo) -> Option<Vec<Span<'static>>> {
+    let backlog = state
+        .task_queue_backlog
+        .as_ref()
+        .filter(|backlog| backlog.task_queue == workflow.task_queue)?;
+    let color = if backlog.tasks > 0 && backlog.pollers == 0 {
+        Color::Red
+    } else if backlog.tasks > 0 {
+        Color::Yellow
+    } else {
+        Color::White
+    };
+    let tasks = if backlog.tasks == 1 { "task" } else { "tasks" };
+    let pollers = if backlog.pollers == 1 { "poller" } else { "pollers" };
+    Some(vec![
+        Span::styled("Backlog: ", Style::default().fg(Color::Cyan)),
+        Span::styled(format!("{} {}", backlog.tasks, tasks), Style::default().fg(color)),
+        Span::styled(format!(" ({} {})", backlog.pollers, pollers), Style::default().fg(Color::DarkGray)),
+    ])
+}
+
+/// "Workflow Task: Started, attempt N (scheduled ...)", red once the task keeps being retried
+pub(super) fn pending_task_spans(state: &WorkflowDetailState) -> Option<Vec<Span<'static>>> {
+    let task = state.pending_workflow_task.as_ref()?;
+    let task_state = match PendingWorkflowTaskState::try_from(task.state) {
+        Ok(PendingWorkflowTaskState::Scheduled) => "Scheduled",
+        Ok(PendingWorkflowTaskState::Started) => "Started",
+        _ => "Pending",
+    };
+    let style = if task.attempt >= WORKFLOW_TASK_ATTEMPT_WARNING {
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::White)
+    };
+    let mut spans = vec![
+        Span::styled("Workflow Task: ", Style::default().fg(Color::Cyan)),
+        Span::styled(format!("{}, attempt {}", task_state, task.attempt), style),
+    ];
+    // Retries reschedule the task; the original time shows how long it has been failing
+    let scheduled = task.original_scheduled_time.as_ref().or(task.scheduled_time.as_ref());
+    if scheduled.is_some() {
+        spans.push(Span::styled(
+            format!(" (scheduled {})", format_timestamp(scheduled, "Unknown")),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    if task.attempt >= WORKFLOW_TASK_ATTEMPT_WARNING {
+        spans.push(Span::styled(" - failing repeatedly", style));
+    }
+    Some(spans)
+}
+
+/// Lines of the metadata section; a pending workflow task and a continued run's successor add one each
+fn metadata_height(app: &App) -> u16 {
+    if app.compact {
+        return 3;
+    }
+    let state = &app.workflow_detail_state;
+    9 + u16::from(state.pending_workflow_task.is_some()) + u16::from(state.continued_as_run_id().is_some())
+}
+
+/// "N events, X MB", colored by how close the run is to the history limits
+pub(super) fn history_size_label(events: i64, bytes: i64) -> (String, Color) {
+    if events == 0 && bytes == 0 {
+        return ("Unknown".to_string(), Color::White);
+    }
+    let mut label = format!("{} events, {}", events, format_bytes(bytes));
+    let color = if events * 5 >= HISTORY_EVENT_LIMIT * 4 || bytes * 5 >= HISTORY_SIZE_LIMIT * 4 {
+        label.push_str(" - near the history limit");
+        Color::Red
+    } else if events >= HISTORY_EVENT_WARNING || bytes >= HISTORY_SIZE_WARNING {
+        label.push_str(" - consider continue-as-new");
+        Color::Yellow
+    } else {
+        Color::White
+    };
+    (label, color)
+}
+
+fn format_bytes(bytes: i64) -> String {
+    const KB: f64 = 1024.0;
+    let bytes = bytes as f64;
+    if bytes >= KB * KB {
+        format!("{:.1} MB", bytes / (KB * KB))
+    } else if bytes >= KB {
+        format!("{:.1} KB", bytes / KB)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+fn render_metadata_unavailable(app: &App, frame: &mut Frame, area: Rect) {
+    let state = &app.workflow_detail_state;
+    let run = if state.run_id.is_empty() {
+        "the latest run".to_string()
+    } else {
+        format!("RunId {}", state.run_id)
+    };
+    let note = Line::from(Span::styled(
+        format!("Metadata unavailable (visibility lag); history loaded from {}", run),
+        Style::default().fg(Color::Yellow),
+    ));
+    let id = Line::from(vec![
+        Span::styled("Workflow ID: ", Style::default().fg(Color::Cyan)),
+        Span::raw(state.workflow_id.clone().unwrap_or_default()),
+    ]);
+
+    if app.compact {
+        frame.render_widget(Paragraph::new(vec![id, note]), area);
+        return;
+    }
+
+    let paragraph = Paragraph::new(vec![id, Line::from(""), note])
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Workflow Metadata")
+                .style(Style::default().fg(Color::White)),
+        );
+    frame.render_widget(paragraph, area);
+}
+
 fn render_event_history(app: &App, frame: &mut Frame, area: Rect) {
     let state = &app.workflow_detail_state;
 
@@ -231,27 +468,66 @@ fn render_event_history(app: &App, frame: &mut Frame, area: Rect) {
     );
 
     let rows: Vec<Row> = state
-        .history
+        .rows
         .iter()
-        .map(|event| {
-            let event_id = event.event_id.to_string();
-            let event_type = get_event_type_name(event.event_type);
-            let timestamp = event
-                .event_time
-                .as_ref()
-                .and_then(|t| {
-                    let seconds = t.seconds as i64;
-                    let nanos = t.nanos as u32;
-                    DateTime::from_timestamp(seconds, nanos)
-                        .map(|dt: DateTime<Utc>| dt.format("%Y-%m-%d %H:%M:%S").to_string())
-                })
-                .unwrap_or_else(|| "Unknown".to_string());
+        .map(|&row| {
+            let (event_id, event_type, timestamp) = match row {
+                HistoryRow::Event(i) => {
+                    let event = &state.history[i];
+                    (
+                        event.event_id.to_string(),
+                        get_event_type_name(event.event_type),
+                        format_event_time(event),
+                    )
+                }
+                HistoryRow::Member(i) => {
+                    let event = &state.history[i];
+                    (
+                        format!("  {}", event.event_id),
+                        format!("  {}", get_event_type_name(event.event_type)),
+                        format_event_time(event),
+                    )
+                }
+                HistoryRow::Group(index) => {
+                    let group = &state.activity_groups[index];
+                    let first = &state.history[group.events[0]];
+                    let last = &state.history[*group.events.last().unwrap_or(&group.events[0])];
+                    let marker = if state.expanded_groups.contains(&first.event_id) {
+                        "▾"
+                    } else {
+                        "▸"
+                    };
+                    let attempts = if group.attempts == 1 {
+                        "1 attempt".to_string()
+                    } else {
+                        format!("{} attempts", group.attempts)
+                    };
+                    (
+                        format!("{} {}", marker, first.event_id),
+                        format!(
+                            "Activity {}: {}, {}",
+                            group.activity_type,
+                            attempts,
+                            get_event_type_name(group.state as i32)
+                        ),
+                        format_event_time(last),
+                    )
+                }
+            };
+
+            // Highlight search matches
+            let style = if state.row_matches(row) {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default()
+            };
 
             Row::new(vec![
                 Cell::from(event_id),
                 Cell::from(event_type),
                 Cell::from(timestamp),
             ])
+            .style(style)
         })
         .collect();
 
@@ -261,14 +537,56 @@ fn render_event_history(app: &App, frame: &mut Frame, area: Rect) {
         Constraint::Percentage(45),
     ];
 
+    // Show the search prompt or the active search in the title
+    let mut title = format!("Event History ({} events)", state.history.len());
+    let hidden = state.hidden_event_count();
+    if hidden > 0 {
+        title = format!("Event History ({} events, {} workflow task events hidden)", state.history.len(), hidden);
+    }
+    if state.reloading {
+        title.push_str(&format!(" | {} Reloading", app.spinner()));
+    }
+    if state.following.is_some() {
+        // Throughput over the window tells a busy workflow from one that is spinning
+        let recent = state.recent_event_count();
+        title.push_str(&format!(
+            " | {} Following ({} events in {}s, {:.1}/s)",
+            app.spinner(),
+            recent,
+            FOLLOW_RATE_WINDOW.as_secs(),
+            recent as f64 / FOLLOW_RATE_WINDOW.as_secs_f64()
+        ));
+    }
+    if state.search_mode {
+        title.push_str(&format!(" | Search: {}_", state.search_query));
+    } else if !state.search_query.is_empty() {
+        let position = state
+            .selected_event_index()
+            .and_then(|s| state.search_matches.iter().position(|&i| i == s))
+            .map(|p| format!("{}/", p + 1))
+            .unwrap_or_default();
+        title.push_str(&format!(
+            " | Search: '{}' ({}{} matches)",
+            state.search_query,
+            position,
+            state.search_matches.len()
+        ));
+    }
+
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .style(Style::default().fg(Color::White));
+    if state.history_truncated {
+        block = block.title_bottom(Line::styled(
+            format!(" History truncated at {} events (max_history_events) ", state.history.len()),
+            Style::default().fg(Color::Black).bg(Color::Yellow).add_modifier(Modifier::BOLD),
+        ));
+    }
+
     let table = Table::new(rows, widths)
         .header(header)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(format!("Event History ({} events)", state.history.len()))
-                .style(Style::default().fg(Color::White)),
-        )
+        .block(block)
         .row_highlight_style(
             Style::default()
                 .bg(Color::DarkGray)
@@ -278,61 +596,53 @@ fn render_event_history(app: &App, frame: &mut Frame, area: Rect) {
     frame.render_stateful_widget(table, area, &mut state.table_state.clone());
 }
 
+fn format_event_time(event: &HistoryEvent) -> String {
+    format_timestamp_short(event.event_time.as_ref(), "Unknown")
+}
+
 fn get_event_type_name(event_type: i32) -> String {
-    // Map event type enum to human-readable names
-    // This is a simplified version - you can expand this based on the proto definitions
-    match event_type {
-        1 => "WorkflowExecutionStarted".to_string(),
-        2 => "WorkflowExecutionCompleted".to_string(),
-        3 => "WorkflowExecutionFailed".to_string(),
-        4 => "WorkflowExecutionTimedOut".to_string(),
-        5 => "WorkflowTaskScheduled".to_string(),
-        6 => "WorkflowTaskStarted".to_string(),
-        7 => "WorkflowTaskCompleted".to_string(),
-        8 => "WorkflowTaskTimedOut".to_string(),
-        9 => "WorkflowTaskFailed".to_string(),
-        10 => "ActivityTaskScheduled".to_string(),
-        11 => "ActivityTaskStarted".to_string(),
-        12 => "ActivityTaskCompleted".to_string(),
-        13 => "ActivityTaskFailed".to_string(),
-        14 => "ActivityTaskTimedOut".to_string(),
-        15 => "ActivityTaskCancelRequested".to_string(),
-        16 => "ActivityTaskCanceled".to_string(),
-        17 => "TimerStarted".to_string(),
-        18 => "TimerFired".to_string(),
-        19 => "TimerCanceled".to_string(),
-        20 => "WorkflowExecutionCancelRequested".to_string(),
-        21 => "WorkflowExecutionCanceled".to_string(),
-        22 => "RequestCancelExternalWorkflowExecutionInitiated".to_string(),
-        23 => "RequestCancelExternalWorkflowExecutionFailed".to_string(),
-        24 => "ExternalWorkflowExecutionCancelRequested".to_string(),
-        25 => "MarkerRecorded".to_string(),
-        26 => "WorkflowExecutionSignaled".to_string(),
-        27 => "WorkflowExecutionTerminated".to_string(),
-        28 => "WorkflowExecutionContinuedAsNew".to_string(),
-        29 => "StartChildWorkflowExecutionInitiated".to_string(),
-        30 => "StartChildWorkflowExecutionFailed".to_string(),
-        31 => "ChildWorkflowExecutionStarted".to_string(),
-        32 => "ChildWorkflowExecutionCompleted".to_string(),
-        33 => "ChildWorkflowExecutionFailed".to_string(),
-        34 => "ChildWorkflowExecutionCanceled".to_string(),
-        35 => "ChildWorkflowExecutionTimedOut".to_string(),
-        36 => "ChildWorkflowExecutionTerminated".to_string(),
-        37 => "SignalExternalWorkflowExecutionInitiated".to_string(),
-        38 => "SignalExternalWorkflowExecutionFailed".to_string(),
-        39 => "ExternalWorkflowExecutionSignaled".to_string(),
-        40 => "UpsertWorkflowSearchAttributes".to_string(),
-        _ => format!("Unknown({})", event_type),
+    // Map event type enum to human-readable names using the generated enum so
+    // newly added event types render without needing a table update here
+    match EventType::try_from(event_type) {
+        Ok(EventType::Unspecified) | Err(_) => format!("Unknown({})", event_type),
+        Ok(event_type) => event_type
+            .as_str_name()
+            .trim_start_matches("EVENT_TYPE_")
+            .split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars.flat_map(|c| c.to_lowercase())).collect::<String>(),
+                    None => String::new(),
+                }
+            })
+            .collect(),
     }
 }
 
+/// Quick signals beyond this have no digit key and aren't listed
+const MAX_QUICK_SIGNALS: usize = 9;
+
+/// Server reset points listed at once in the reset dialog; the list scrolls with the choice
+const MAX_RESET_POINTS_SHOWN: usize = 6;
+
 fn render_operation_dialog(app: &App, frame: &mut Frame, area: Rect) {
     let state = &app.workflow_detail_state;
     let operation = state.show_dialog.as_ref().unwrap();
 
     // Create a centered dialog area
-    let dialog_width = 60;
-    let dialog_height = 8;
+    let dialog_width = if matches!(operation, WorkflowOperation::Restart | WorkflowOperation::Reset) { 70 } else { 60 };
+    let quick_signals = &app.config.quick_signals[..app.config.quick_signals.len().min(MAX_QUICK_SIGNALS)];
+    let dialog_height = match operation {
+        WorkflowOperation::Update => 10,
+        WorkflowOperation::Restart => 18,
+        WorkflowOperation::Reset => match state.server_reset_points().len().min(MAX_RESET_POINTS_SHOWN) {
+            0 => 12,
+            shown => 14 + shown as u16,
+        },
+        WorkflowOperation::Signal if !quick_signals.is_empty() => 10 + quick_signals.len() as u16,
+        _ => 8,
+    };
     let dialog_x = (area.width.saturating_sub(dialog_width)) / 2;
     let dialog_y = (area.height.saturating_sub(dialog_height)) / 2;
     let dialog_area = Rect::new(dialog_x, dialog_y, dialog_width, dialog_height);
@@ -357,6 +667,21 @@ fn render_operation_dialog(app: &App, frame: &mut Frame, area: Rect) {
             "Enter signal name:",
             true,
         ),
+        WorkflowOperation::Update => (
+            "Update Workflow",
+            "Enter update name and JSON args (Tab to switch):",
+            true,
+        ),
+        WorkflowOperation::Restart => (
+            "Restart Workflow",
+            "Start a new run with the same type, task queue and input?",
+            false,
+        ),
+        WorkflowOperation::Reset => (
+            "Reset Workflow",
+            "Enter reset reason (or leave empty):",
+            true,
+        ),
     };
 
     let mut lines = vec![
@@ -365,7 +690,21 @@ fn render_operation_dialog(app: &App, frame: &mut Frame, area: Rect) {
         Line::from(""),
     ];
 
-    if show_input {
+    if *operation == WorkflowOperation::Update {
+        let cursor = |focused: bool| if focused { "_" } else { "" };
+        lines.push(Line::from(vec![
+            Span::styled("name> ", Style::default().fg(Color::Cyan)),
+            Span::styled(&state.dialog_input, Style::default().fg(Color::White)),
+            Span::styled(cursor(!state.dialog_args_focused), Style::default().fg(Color::Yellow)),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("args> ", Style::default().fg(Color::Cyan)),
+            Span::styled(&state.dialog_args_input, Style::default().fg(Color::White)),
+            Span::styled(cursor(state.dialog_args_focused), Style::default().fg(Color::Yellow)),
+        ]));
+    } else if *operation == WorkflowOperation::Restart {
+        lines.extend(restart_preview_lines(app));
+    } else if show_input {
         lines.push(Line::from(vec![
             Span::styled("> ", Style::default().fg(Color::Cyan)),
             Span::styled(&state.dialog_input, Style::default().fg(Color::White)),
@@ -373,6 +712,26 @@ fn render_operation_dialog(app: &App, frame: &mut Frame, area: Rect) {
         ]));
     }
 
+    if *operation == WorkflowOperation::Reset {
+        lines.push(Line::from(""));
+        lines.extend(reset_option_lines(state));
+    }
+
+    if *operation == WorkflowOperation::Signal && !quick_signals.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Quick signals (press the number to send now):",
+            Style::default().fg(Color::Cyan),
+        )));
+        for (i, signal) in quick_signals.iter().enumerate() {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {} ", i + 1), Style::default().fg(Color::Yellow)),
+                Span::styled(signal.name.clone(), Style::default().fg(Color::White)),
+                Span::styled(format!(" {}", signal.payload_json()), Style::default().fg(Color::DarkGray)),
+            ]));
+        }
+    }
+
     lines.push(Line::from(""));
     lines.push(Line::from(vec![
         Span::styled("Enter", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
@@ -393,13 +752,134 @@ fn render_operation_dialog(app: &App, frame: &mut Frame, area: Rect) {
     frame.render_widget(paragraph, dialog_area);
 }
 
-fn render_success_message(app: &App, frame: &mut Frame, area: Rect) {
+/// Type, task queue and decoded input of the run being restarted
+fn restart_preview_lines(app: &App) -> Vec<Line<'static>> {
+    const MAX_INPUT_LINES: usize = 6;
     let state = &app.workflow_detail_state;
-    let message = state.success_message.as_ref().unwrap();
+    let mut lines = vec![];
+
+    let Some(started) = state.started_attributes() else {
+        lines.push(Line::from(Span::styled(
+            "Start event not loaded; it will be read from history",
+            Style::default().fg(Color::DarkGray),
+        )));
+        return lines;
+    };
+
+    let field = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<12}", label), Style::default().fg(Color::Cyan)),
+            Span::styled(value, Style::default().fg(Color::White)),
+        ])
+    };
+    lines.push(field("Type:", started.workflow_type.as_ref().map(|t| t.name.clone()).unwrap_or_default()));
+    lines.push(field("Task queue:", started.task_queue.as_ref().map(|t| t.name.clone()).unwrap_or_default()));
+    lines.push(Line::from(Span::styled("Input:", Style::default().fg(Color::Cyan))));
+
+    let input = started.input.as_ref().map(format_payloads).unwrap_or_default();
+    if input.is_empty() {
+        lines.push(Line::from(Span::styled("  (none)", Style::default().fg(Color::DarkGray))));
+    } else {
+        for line in input.lines().take(MAX_INPUT_LINES) {
+            lines.push(Line::from(Span::raw(format!("  {}", line))));
+        }
+        if input.lines().count() > MAX_INPUT_LINES {
+            lines.push(Line::from(Span::styled("  ...", Style::default().fg(Color::DarkGray))));
+        }
+    }
+
+    if state.is_running() {
+        let check = if state.restart_terminate { "[x]" } else { "[ ]" };
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(check, Style::default().fg(Color::Yellow)),
+            Span::raw(" Terminate the running execution first "),
+            Span::styled("(Tab)", Style::default().fg(Color::DarkGray)),
+        ]));
+    }
+
+    lines
+}
+
+/// Where the reset goes back to and which later events get reapplied
+fn reset_option_lines(state: &WorkflowDetailState) -> Vec<Line<'static>> {
+    let label = |text: &'static str| Span::styled(format!("{:<12}", text), Style::default().fg(Color::Cyan));
+    let point = match state.reset_point() {
+        Some(event_id) => Span::styled(
+            format!("after WorkflowTaskCompleted (event {})", event_id),
+            Style::default().fg(Color::White),
+        ),
+        None => Span::styled("no completed workflow task at or before the selection", Style::default().fg(Color::Red)),
+    };
+    let source = if state.reset_point_choice.is_some() { " (server reset point)" } else { "" };
+    let reapply = match state.reset_reapply {
+        ResetReapplyType::Unspecified => "Server default",
+        ResetReapplyType::AllEligible => "All eligible events (signals, updates)",
+        ResetReapplyType::Signal => "Signals only",
+        ResetReapplyType::None => "None",
+    };
+    let mut lines = vec![
+        Line::from(vec![label("Reset to:"), point, Span::styled(source, Style::default().fg(Color::DarkGray))]),
+        Line::from(vec![
+            label("Reapply:"),
+            Span::styled(reapply, Style::default().fg(Color::Yellow)),
+            Span::styled(" (Tab)", Style::default().fg(Color::DarkGray)),
+        ]),
+    ];
+    lines.extend(reset_point_list_lines(state));
+    lines
+}
+
+/// The server's reset points for the run, one per line with its event ID, binary checksum
+/// (or build ID) and creation time; the chosen one is highlighted
+fn reset_point_list_lines(state: &WorkflowDetailState) -> Vec<Line<'static>> {
+    let points = state.server_reset_points();
+    if points.is_empty() {
+        return Vec::new();
+    }
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Server reset points", Style::default().fg(Color::Cyan)),
+            Span::styled(" (↑/↓ to pick)", Style::default().fg(Color::DarkGray)),
+        ]),
+    ];
+    let start = state.reset_point_choice.unwrap_or(0).saturating_sub(MAX_RESET_POINTS_SHOWN - 1);
+    for (i, point) in points.iter().enumerate().skip(start).take(MAX_RESET_POINTS_SHOWN) {
+        let chosen = state.reset_point_choice == Some(i);
+        let origin = if !point.binary_checksum.is_empty() {
+            format!("checksum {}", point.binary_checksum)
+        } else if !point.build_id.is_empty() {
+            format!("build {}", point.build_id)
+        } else {
+            "-".to_string()
+        };
+        let style = if chosen {
+            Style::default().fg(Color::Black).bg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(if chosen { "> " } else { "  " }, Style::default().fg(Color::Yellow)),
+            Span::styled(format!("event {:<6} {}", point.first_workflow_task_completed_id, origin), style),
+            Span::styled(
+                format!("  {}", format_timestamp_short(point.create_time.as_ref(), "")),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+    }
+    lines
+}
+
+fn render_message(frame: &mut Frame, area: Rect, title: &str, message: &str, color: Color) {
+    // One line per line of the message, padded above and below
+    let mut lines = vec![Line::from("")];
+    lines.extend(message.lines().map(|line| Line::from(Span::styled(line, Style::default().fg(Color::White)))));
+    lines.push(Line::from(""));
 
     // Create a centered message area
     let msg_width = 60;
-    let msg_height = 5;
+    let msg_height = lines.len() as u16 + 2;
     let msg_x = (area.width.saturating_sub(msg_width)) / 2;
     let msg_y = (area.height.saturating_sub(msg_height)) / 2;
     let msg_area = Rect::new(msg_x, msg_y, msg_width, msg_height);
@@ -407,22 +887,60 @@ fn render_success_message(app: &App, frame: &mut Frame, area: Rect) {
     // Clear the area
     frame.render_widget(Clear, msg_area);
 
-    let lines = vec![
-        Line::from(""),
-        Line::from(Span::styled(message, Style::default().fg(Color::White))),
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .style(Style::default().fg(color)),
+        )
+        .alignment(Alignment::Center);
+
+    frame.render_widget(paragraph, msg_area);
+}
+
+fn render_update_outcome(app: &App, frame: &mut Frame, area: Rect) {
+    let state = &app.workflow_detail_state;
+    let outcome = state.update_outcome.as_ref().unwrap();
+
+    // Create a centered modal area
+    let modal_width = 70.min(area.width);
+    let modal_height = 12.min(area.height);
+    let modal_x = (area.width.saturating_sub(modal_width)) / 2;
+    let modal_y = (area.height.saturating_sub(modal_height)) / 2;
+    let modal_area = Rect::new(modal_x, modal_y, modal_width, modal_height);
+
+    // Clear the area
+    frame.render_widget(Clear, modal_area);
+
+    let (title, color, label) = if outcome.success {
+        ("Update Succeeded", Color::Green, "Result:")
+    } else {
+        ("Update Failed", Color::Red, "Failure:")
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Update: ", Style::default().fg(Color::Cyan)),
+            Span::raw(outcome.update_name.clone()),
+        ]),
         Line::from(""),
+        Line::from(Span::styled(label, Style::default().fg(color).add_modifier(Modifier::BOLD))),
     ];
+    for line in outcome.message.lines() {
+        lines.push(Line::from(Span::styled(line.to_string(), Style::default().fg(Color::White))));
+    }
 
     let paragraph = Paragraph::new(lines)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("Success")
-                .style(Style::default().fg(Color::Green)),
+                .title(title)
+                .style(Style::default().fg(color)),
         )
-        .alignment(Alignment::Center);
+        .wrap(ratatui::widgets::Wrap { trim: false });
 
-    frame.render_widget(paragraph, msg_area);
+    frame.render_widget(paragraph, modal_area);
 }
 
 fn render_event_detail_modal(app: &App, frame: &mut Frame, area: Rect) {
@@ -444,7 +962,130 @@ fn render_event_detail_modal(app: &App, frame: &mut Frame, area: Rect) {
     // Clear the area
     frame.render_widget(Clear, modal_area);
 
-    // Build event details
+    let lines = event_detail_lines(state, event);
+    let total_lines = lines.len() as u16;
+    let scroll_offset = state.event_detail_scroll_offset;
+
+    // Calculate if we can scroll more
+    let visible_lines = modal_area.height.saturating_sub(2); // Subtract borders
+    let max_scroll = total_lines.saturating_sub(visible_lines);
+    let can_scroll_down = scroll_offset < max_scroll;
+    let can_scroll_up = scroll_offset > 0;
+
+    // Add scroll indicators to title
+    let mut title = if state.event_detail_raw {
+        format!("Event Details (raw protobuf, {} bytes)", event.encoded_len())
+    } else {
+        "Event Details".to_string()
+    };
+    if can_scroll_up || can_scroll_down {
+        title.push_str(" | ");
+        if can_scroll_up {
+            title.push_str("↑ ");
+        }
+        title.push_str(&format!("({}/{})", scroll_offset + visible_lines.min(total_lines), total_lines));
+        if can_scroll_down {
+            title.push_str(" ↓");
+        }
+    }
+    if let Some(notice) = &state.modal_notice {
+        title.push_str(&format!(" | {}", notice));
+    }
+    if !event_references(event).is_empty() {
+        title.push_str(" | Tab/Enter follow reference");
+    }
+    if state.event_detail_wrap {
+        title.push_str(" | w no wrap");
+    } else {
+        title.push_str(" | w wrap | ←/→ scroll");
+    }
+    if state.event_detail_raw {
+        title.push_str(" | R decoded");
+    } else {
+        title.push_str(" | R raw");
+    }
+    title.push_str(" | y to copy | ESC/q to close");
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .style(Style::default().fg(Color::Cyan)),
+    );
+    // Unwrapped, long lines run off the right edge and scroll sideways instead
+    let paragraph = if state.event_detail_wrap {
+        paragraph
+            .scroll((scroll_offset, 0))
+            .wrap(ratatui::widgets::Wrap { trim: false })
+    } else {
+        paragraph.scroll((scroll_offset, state.event_detail_hscroll))
+    };
+
+    frame.render_widget(paragraph, modal_area);
+}
+
+/// Plain-text event details with payloads decoded and nothing truncated, for the clipboard
+pub fn event_detail_text(event: &HistoryEvent) -> String {
+    let mut text = format!(
+        "Event ID: {}\nEvent Type: {}\n",
+        event.event_id,
+        get_event_type_name(event.event_type)
+    );
+    let timestamp = format_timestamp(event.event_time.as_ref(), "");
+    if !timestamp.is_empty() {
+        text.push_str(&format!("Timestamp: {}\n", timestamp));
+    }
+    text.push('\n');
+    match &event.attributes {
+        Some(attrs) => text.push_str(&decode_debug_bytes(&format!("{:?}", attrs))),
+        None => text.push_str("No attributes available"),
+    }
+    text.push('\n');
+    text
+}
+
+/// Bytes shown per row of the raw protobuf dump
+const HEX_DUMP_WIDTH: usize = 16;
+
+/// The event as encoded on the wire, one row per 16 bytes: offset, hex bytes and printable ASCII
+pub fn event_hex_dump(event: &HistoryEvent) -> String {
+    let bytes = event.encode_to_vec();
+    let mut dump = String::new();
+    for (row, chunk) in bytes.chunks(HEX_DUMP_WIDTH).enumerate() {
+        let hex: Vec<String> = chunk.iter().map(|byte| format!("{:02x}", byte)).collect();
+        let ascii: String = chunk
+            .iter()
+            .map(|&byte| if byte.is_ascii_graphic() || byte == b' ' { byte as char } else { '.' })
+            .collect();
+        dump.push_str(&format!(
+            "{:08x}  {:<width$}  |{}|\n",
+            row * HEX_DUMP_WIDTH,
+            hex.join(" "),
+            ascii,
+            width = HEX_DUMP_WIDTH * 3 - 1
+        ));
+    }
+    dump
+}
+
+/// Number of lines in the selected event's detail modal, used to clamp scrolling
+pub fn event_detail_line_count(app: &App) -> u16 {
+    app.workflow_detail_state
+        .selected_event()
+        .map(|event| event_detail_lines(&app.workflow_detail_state, event).len() as u16)
+        .unwrap_or(0)
+}
+
+/// Width of the event modal's longest line, for clamping horizontal scroll when unwrapped
+pub fn event_detail_width(app: &App) -> u16 {
+    app.workflow_detail_state
+        .selected_event()
+        .and_then(|event| event_detail_lines(&app.workflow_detail_state, event).iter().map(Line::width).max())
+        .map(|width| u16::try_from(width).unwrap_or(u16::MAX))
+        .unwrap_or(0)
+}
+
+fn event_detail_lines(state: &WorkflowDetailState, event: &HistoryEvent) -> Vec<Line<'static>> {
     let mut lines = vec![];
 
     // Event ID and Type
@@ -459,16 +1100,61 @@ fn render_event_detail_modal(app: &App, frame: &mut Frame, area: Rect) {
     ]));
 
     // Timestamp
-    if let Some(event_time) = &event.event_time {
-        let timestamp = DateTime::from_timestamp(event_time.seconds as i64, event_time.nanos as u32)
-            .map(|dt: DateTime<Utc>| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
-            .unwrap_or_else(|| "Unknown".to_string());
+    if event.event_time.is_some() {
+        let timestamp = format_timestamp(event.event_time.as_ref(), "Unknown");
         lines.push(Line::from(vec![
             Span::styled("Timestamp: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
             Span::raw(timestamp),
         ]));
     }
 
+    // The encoded bytes replace everything decoded below
+    if state.event_detail_raw {
+        lines.push(Line::from(""));
+        lines.extend(
+            event_hex_dump(event)
+                .lines()
+                .map(|row| Line::from(Span::styled(row.to_string(), Style::default().fg(Color::White)))),
+        );
+        return lines;
+    }
+
+    // Events this one points back to; the highlighted one is followed with Enter
+    let references = event_references(event);
+    if !references.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "References:",
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        )));
+        for (i, (name, id)) in references.iter().enumerate() {
+            let target = state
+                .history
+                .iter()
+                .find(|e| e.event_id == *id)
+                .map(|e| get_event_type_name(e.event_type))
+                .unwrap_or_else(|| "not loaded".to_string());
+            let style = if i == state.selected_reference {
+                Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::UNDERLINED)
+            };
+            lines.push(Line::from(vec![
+                Span::raw(format!("  {}: ", name)),
+                Span::styled(format!("→ {} ({})", id, target), style),
+            ]));
+        }
+    }
+
+    if let Some(failure) = event_failure(event) {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Failure:",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )));
+        lines.extend(failure_lines(failure));
+    }
+
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
         "Event Attributes:",
@@ -479,40 +1165,60 @@ fn render_event_detail_modal(app: &App, frame: &mut Frame, area: Rect) {
     // Format event attributes based on type
     lines.extend(format_event_attributes(event));
 
-    let total_lines = lines.len() as u16;
-    let scroll_offset = state.event_detail_scroll_offset;
+    lines
+}
 
-    // Calculate if we can scroll more
-    let visible_lines = modal_area.height.saturating_sub(2); // Subtract borders
-    let max_scroll = total_lines.saturating_sub(visible_lines);
-    let can_scroll_down = scroll_offset < max_scroll;
-    let can_scroll_up = scroll_offset > 0;
+/// Causes followed below a failure before giving up on a runaway chain
+const MAX_FAILURE_CAUSES: usize = 10;
 
-    // Add scroll indicators to title
-    let mut title = "Event Details".to_string();
-    if can_scroll_up || can_scroll_down {
-        title.push_str(" | ");
-        if can_scroll_up {
-            title.push_str("↑ ");
+/// A failure and its causes one line per line of message and stack trace, with frames
+/// indented, since the attribute dump shows each as a single escaped run
+fn failure_lines(failure: &Failure) -> Vec<Line<'static>> {
+    let mut lines = vec![];
+    let mut current = Some(failure);
+    let mut depth = 0;
+    while let Some(failure) = current {
+        if depth == MAX_FAILURE_CAUSES {
+            lines.push(Line::from(Span::styled(
+                "  ... (more causes in the attributes below)",
+                Style::default().fg(Color::DarkGray),
+            )));
+            break;
         }
-        title.push_str(&format!("({}/{})", scroll_offset + visible_lines.min(total_lines), total_lines));
-        if can_scroll_down {
-            title.push_str(" ↓");
+        if depth > 0 {
+            lines.push(Line::from(Span::styled(
+                "Caused by:",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )));
+        }
+        for line in failure.message.lines() {
+            lines.push(Line::from(Span::styled(format!("  {}", line), Style::default().fg(Color::White))));
+        }
+        if !failure.source.is_empty() {
+            lines.push(Line::from(Span::styled(
+                format!("  (source: {})", failure.source),
+                Style::default().fg(Color::DarkGray),
+            )));
         }
+        for line in failure.stack_trace.lines().filter(|line| !line.trim().is_empty()) {
+            let line = if is_stack_frame(line) {
+                Line::from(Span::styled(format!("      {}", line.trim()), Style::default().fg(Color::DarkGray)))
+            } else {
+                Line::from(Span::styled(format!("    {}", line.trim()), Style::default().fg(Color::Gray)))
+            };
+            lines.push(line);
+        }
+        current = failure.cause.as_deref();
+        depth += 1;
     }
-    title.push_str(" | ESC/q to close");
-
-    let paragraph = Paragraph::new(lines)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(title)
-                .style(Style::default().fg(Color::Cyan)),
-        )
-        .scroll((scroll_offset, 0))
-        .wrap(ratatui::widgets::Wrap { trim: false });
+    lines
+}
 
-    frame.render_widget(paragraph, modal_area);
+/// Whether a stack trace line is a frame location (Java/JS `at ...`, Go's tab-indented file
+/// lines, Python's `File "..."`) rather than a function name or exception header
+fn is_stack_frame(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    line.starts_with(char::is_whitespace) || trimmed.starts_with("at ") || trimmed.starts_with("File \"")
 }
 
 fn format_event_attributes(event: &crate::generated::temporal::api::history::v1::HistoryEvent) -> Vec<Line<'static>> {
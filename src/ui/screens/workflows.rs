@@ -1,25 +1,34 @@
-use crate::app::{App, ConnectionStatus, WorkflowFilter};
+use crate::app::{App, ConnectionStatus, WorkflowFilter, WorkflowListState, WorkflowRow};
 use crate::generated::temporal::api::{
     enums::v1::WorkflowExecutionStatus, workflow::v1::WorkflowExecutionInfo,
 };
-use chrono::{DateTime, Utc};
+use crate::temporal::payload::format_search_attribute;
+use crate::temporal::time::format_timestamp;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    widgets::{Bar, BarChart, Block, Borders, Cell, Paragraph, Row, Table},
     Frame,
 };
+use std::collections::HashMap;
 
 pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     let state = &app.workflow_list_state;
 
     // Split area if we need to show search/filter bar
-    let show_search_bar = state.input_mode || state.active_filter.is_some() || !state.query.is_empty();
+    let show_search_bar = state.input_mode
+        || state.active_filter.is_some()
+        || state.time_range.is_some()
+        || state.order.is_some()
+        || !state.query.is_empty();
+    // Workflow type suggestions take a second line in the bar
+    let suggestions = app.workflow_type_suggestions();
+    let search_height = if suggestions.is_empty() { 3 } else { 4 };
     let (search_area, table_area) = if show_search_bar {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .constraints([Constraint::Length(search_height), Constraint::Min(0)])
             .split(area);
         (Some(chunks[0]), chunks[1])
     } else {
@@ -28,25 +37,27 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
 
     // Render search/filter bar if needed
     if let Some(search_rect) = search_area {
-        render_search_bar(app, frame, search_rect);
+        render_search_bar(app, &suggestions, frame, search_rect);
     }
 
     // Show error message if there is one
     if let Some(error) = &state.error {
-        let lines = vec![
+        let mut lines = vec![
             Line::from(""),
             Line::from(Span::styled(
                 "⚠ An error occurred:",
                 Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
             )),
             Line::from(""),
-            Line::from(Span::styled(error, Style::default().fg(Color::White))),
+        ];
+        lines.extend(crate::ui::error_message_lines(error, app.show_error_details));
+        lines.extend([
             Line::from(""),
             Line::from(Span::styled(
                 "Press 'r' to retry or 'ESC' to go back",
                 Style::default().fg(Color::Yellow),
             )),
-        ];
+        ]);
         let paragraph = Paragraph::new(lines)
             .block(
                 Block::default()
@@ -61,12 +72,13 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     // Show loading indicator
     if state.loading {
         let spinner = app.spinner();
-        let loading_text = format!("{} Loading workflows...", spinner);
+        let what = if state.archived { "archived workflows" } else { "workflows" };
+        let loading_text = format!("{} Loading {}... (ESC to cancel)", spinner, what);
         let paragraph = Paragraph::new(loading_text)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title("Workflows")
+                    .title(list_heading(state))
                     .style(Style::default().fg(Color::Yellow)),
             )
             .style(Style::default().fg(Color::Yellow));
@@ -89,7 +101,7 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title("Workflows")
+                    .title(list_heading(state))
                     .style(Style::default().fg(color)),
             )
             .style(Style::default().fg(color));
@@ -100,7 +112,7 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     // Show empty message if no workflows
     if state.items.is_empty() {
         let lines = vec![
-            Line::from("No workflows found"),
+            Line::from(if state.archived { "No archived workflows found" } else { "No workflows found" }),
             Line::from(""),
             Line::from(Span::styled("Press 'r' to refresh", Style::default().fg(Color::Yellow))),
         ];
@@ -108,76 +120,210 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title("Workflows")
+                    .title(list_heading(state))
                     .style(Style::default().fg(Color::White)),
             );
         frame.render_widget(paragraph, table_area);
         return;
     }
 
+    // Status distribution chart above the table
+    let table_area = if state.show_status_chart {
+        let bars = status_chart_bars(app);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(bars.len().max(1) as u16 + 2), Constraint::Min(0)])
+            .split(table_area);
+        render_status_chart(app, bars, frame, chunks[0]);
+        chunks[1]
+    } else {
+        table_area
+    };
+
+    // Custom search attribute columns configured on the active profile
+    let custom_columns: &[String] = app
+        .config
+        .get_active_profile()
+        .map(|p| p.columns.as_slice())
+        .unwrap_or(&[]);
+
     // Build the table
-    let header = Row::new(vec![
-        Cell::from("Workflow ID"),
-        Cell::from("Type"),
-        Cell::from("Status"),
-        Cell::from("Start Time"),
-    ])
-    .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+    let mut header_texts = vec!["Workflow ID", "Type", "Status", "Start Time"];
+    header_texts.extend(custom_columns.iter().map(|key| key.as_str()));
+    let mut measured = vec![0; header_texts.len()];
+    crate::ui::measure_row(&mut measured, &header_texts);
+    let header = Row::new(header_texts.iter().map(|text| Cell::from(*text)))
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+
+    // Visibility lists each run separately, so reruns of one ID need their run to tell apart
+    let mut id_counts: HashMap<String, usize> = HashMap::new();
+    for workflow in &state.items {
+        *id_counts.entry(get_workflow_id(workflow)).or_default() += 1;
+    }
 
     let rows: Vec<Row> = state
-        .items
-        .iter()
-        .map(|workflow| {
-            let workflow_id = get_workflow_id(workflow);
+        .visible_rows()
+        .into_iter()
+        .map(|row| {
+            let (index, grouped) = match row {
+                WorkflowRow::Group { workflow_type, count, running, expanded } => {
+                    let marker = if expanded { "▼" } else { "▶" };
+                    let label = format!("{} {} workflow{}", marker, count, if count == 1 { "" } else { "s" });
+                    let workflow_type = if workflow_type.is_empty() { "Unknown".to_string() } else { workflow_type };
+                    let running_text = format!("{} running", running);
+                    crate::ui::measure_row(&mut measured, &[&label, &workflow_type, &running_text]);
+                    let running_color = if running > 0 { Color::Green } else { Color::Gray };
+                    return Row::new(vec![
+                        Cell::from(label),
+                        Cell::from(workflow_type).style(Style::default().fg(Color::Cyan)),
+                        Cell::from(running_text).style(Style::default().fg(running_color)),
+                    ])
+                    .style(Style::default().add_modifier(Modifier::BOLD));
+                }
+                WorkflowRow::Workflow { index, grouped } => (index, grouped),
+            };
+            let workflow = &state.items[index];
+            let mut workflow_id = get_workflow_id(workflow);
+            let run_suffix = if id_counts.get(&workflow_id).copied().unwrap_or(0) > 1 {
+                format!(" (run {})", short_run_id(workflow))
+            } else {
+                String::new()
+            };
+            if app.short_workflow_ids {
+                workflow_id = short_workflow_id(&workflow_id).to_string();
+            }
+            // Started since the previous refresh
+            let is_new = state.is_new(workflow);
+            if is_new {
+                workflow_id = format!("+ {}", workflow_id);
+            }
+            let is_marked = state.is_marked(workflow);
+            if is_marked {
+                workflow_id = format!("● {}", workflow_id);
+            }
+            if grouped {
+                workflow_id = format!("  {}", workflow_id);
+            }
             let workflow_type = get_workflow_type(workflow);
-            let status = get_workflow_status(workflow);
+            let mut status = get_workflow_status(workflow);
             let start_time = get_workflow_start_time(workflow);
 
-            let status_style = match status.0 {
-                WorkflowExecutionStatus::Running => Style::default().fg(Color::Yellow),
-                WorkflowExecutionStatus::Completed => Style::default().fg(Color::Green),
-                WorkflowExecutionStatus::Failed => Style::default().fg(Color::Red),
-                WorkflowExecutionStatus::Canceled => Style::default().fg(Color::Magenta),
-                WorkflowExecutionStatus::Terminated => Style::default().fg(Color::Red),
-                WorkflowExecutionStatus::TimedOut => Style::default().fg(Color::Red),
-                _ => Style::default().fg(Color::White),
-            };
+            let mut status_style = Style::default().fg(status_color(status.0));
+            // Running but no new events for a while
+            if state.is_stalled(workflow, app.config.stall_minutes) {
+                status.1.push_str(" (stalled)");
+                status_style = Style::default().fg(Color::LightRed);
+            }
 
-            Row::new(vec![
-                Cell::from(workflow_id),
+            let custom_values: Vec<String> = custom_columns
+                .iter()
+                .map(|key| get_search_attribute(workflow, key))
+                .collect();
+            let id_text = format!("{}{}", workflow_id, run_suffix);
+            let mut texts = vec![id_text.as_str(), workflow_type.as_str(), status.1.as_str(), start_time.as_str()];
+            texts.extend(custom_values.iter().map(String::as_str));
+            crate::ui::measure_row(&mut measured, &texts);
+
+            let id_style = if is_marked {
+                Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)
+            } else if is_new {
+                Style::default().fg(Color::LightGreen).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let mut cells = vec![
+                Cell::from(Line::from(vec![
+                    Span::styled(workflow_id, id_style),
+                    Span::styled(run_suffix, Style::default().fg(Color::DarkGray)),
+                ])),
                 Cell::from(workflow_type),
                 Cell::from(status.1).style(status_style),
                 Cell::from(start_time),
-            ])
+            ];
+            cells.extend(custom_values.into_iter().map(Cell::from));
+            Row::new(cells)
         })
         .collect();
 
-    let widths = [
-        Constraint::Percentage(30),
-        Constraint::Percentage(25),
-        Constraint::Percentage(15),
-        Constraint::Percentage(30),
-    ];
+    // Custom columns share whatever space the fixed columns leave over
+    let mut fallback = if custom_columns.is_empty() {
+        vec![
+            Constraint::Percentage(30),
+            Constraint::Percentage(25),
+            Constraint::Percentage(15),
+            Constraint::Percentage(30),
+        ]
+    } else {
+        vec![
+            Constraint::Percentage(25),
+            Constraint::Percentage(20),
+            Constraint::Percentage(12),
+            Constraint::Percentage(18),
+        ]
+    };
+    fallback.extend(custom_columns.iter().map(|_| Constraint::Fill(1)));
+    let widths = crate::ui::fit_widths(table_area, &measured, fallback);
 
     // Build title with pagination info and auto-refresh status
-    let mut title = format!("Workflows ({} items)", state.items.len());
-    if state.current_page > 1 || state.has_next_page() {
+    let mut title = format!("{} ({} items)", list_heading(state), state.items.len());
+    if let Some(total_pages) = state.total_pages().filter(|&pages| pages > 1) {
+        title = format!("{} - Page {} of {}", title, state.current_page, total_pages);
+    } else if state.current_page > 1 || state.has_next_page() {
         title = format!("{} - Page {}", title, state.current_page);
         if state.has_next_page() {
             title = format!("{} [→]", title);
         }
     }
-    if state.auto_refresh_enabled {
+    if !state.new_ids.is_empty() {
+        title = format!("{} [{} new]", title, state.new_ids.len());
+    }
+    if !state.marked.is_empty() {
+        title = format!("{} [{} marked]", title, state.marked.len());
+    }
+    if state.group_by_type {
+        // Grouping only sees the loaded page, not every match of the query
+        title = format!("{} [By type: this page]", title);
+    }
+    if state.auto_refresh_paused {
+        title = format!("{} [Auto: paused]", title);
+    } else if state.auto_refresh_enabled && state.auto_refresh_held {
+        title = format!("{} [Auto: held]", title);
+    } else if state.auto_refresh_enabled {
         title = format!("{} [Auto: {}s]", title, state.auto_refresh_interval_secs);
     }
 
+    // Show how old the data is, turning yellow after 30s and red after 2m
+    let mut title_spans = vec![Span::raw(title)];
+    if let Some(last_refresh) = state.last_refresh {
+        let elapsed = last_refresh.elapsed().as_secs();
+        let color = match elapsed {
+            0..=29 => Color::Green,
+            30..=119 => Color::Yellow,
+            _ => Color::Red,
+        };
+        title_spans.push(Span::styled(
+            format!(" | updated {} ago", format_elapsed(elapsed)),
+            Style::default().fg(color),
+        ));
+    }
+    if let Some(notice) = &state.copy_notice {
+        title_spans.push(Span::styled(format!(" | {}", notice), Style::default().fg(Color::Green)));
+    }
+
+    // Archived results get a distinct border so they aren't mistaken for live data
+    let border_color = if state.archived { Color::Magenta } else { Color::White };
+    let query = Span::styled(
+        query_label(&state.get_query(), table_area.width.saturating_sub(4) as usize),
+        Style::default().fg(Color::DarkGray),
+    );
     let table = Table::new(rows, widths)
         .header(header)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(title)
-                .style(Style::default().fg(Color::White)),
+                .title(Line::from(title_spans))
+                .title_bottom(Line::from(query))
+                .style(Style::default().fg(border_color)),
         )
         .row_highlight_style(
             Style::default()
@@ -188,11 +334,52 @@ pub fn render(app: &App, frame: &mut Frame, area: Rect) {
     frame.render_stateful_widget(table, table_area, &mut state.table_state.clone());
 }
 
-fn render_search_bar(app: &App, frame: &mut Frame, area: Rect) {
+fn list_heading(state: &WorkflowListState) -> &'static str {
+    if state.archived {
+        "Archived Workflows"
+    } else {
+        "Workflows"
+    }
+}
+
+/// The query the list was loaded with, cut to `max_width` characters
+fn query_label(query: &str, max_width: usize) -> String {
+    let label = if query.is_empty() {
+        " Query: all workflows ".to_string()
+    } else {
+        format!(" Query: {} ", query)
+    };
+    if label.chars().count() <= max_width {
+        return label;
+    }
+    let mut truncated: String = label.chars().take(max_width.saturating_sub(2)).collect();
+    truncated.push_str("… ");
+    truncated
+}
+
+/// Compact age such as "12s", "4m" or "2h"
+fn format_elapsed(secs: u64) -> String {
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m", secs / 60),
+        _ => format!("{}h", secs / 3600),
+    }
+}
+
+fn render_search_bar(app: &App, suggestions: &[&str], frame: &mut Frame, area: Rect) {
     let state = &app.workflow_list_state;
 
     let mut spans = vec![];
 
+    // Archived mode queries a different store; make that impossible to miss
+    if state.archived {
+        spans.push(Span::styled(
+            "ARCHIVED",
+            Style::default().fg(Color::Black).bg(Color::Magenta).add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::raw(" | "));
+    }
+
     // Show filter if active
     if let Some(filter) = &state.active_filter {
         let filter_text = match filter {
@@ -210,14 +397,43 @@ fn render_search_bar(app: &App, frame: &mut Frame, area: Rect) {
         spans.push(Span::raw(" | "));
     }
 
+    // Show start time range if active
+    if let Some(range) = &state.time_range {
+        spans.push(Span::styled("Started: ", Style::default().fg(Color::Cyan)));
+        spans.push(Span::styled(
+            range.label(),
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::raw(" | "));
+    }
+
+    // Show server-side ordering if set
+    if let Some(order) = &state.order {
+        spans.push(Span::styled("Order: ", Style::default().fg(Color::Cyan)));
+        spans.push(Span::styled(
+            order.label(),
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::raw(" | "));
+    }
+
     // Show search query
     if state.input_mode {
         spans.push(Span::styled("Search: ", Style::default().fg(Color::Cyan)));
-        spans.push(Span::styled(
-            &state.query,
-            Style::default().fg(Color::White),
-        ));
-        spans.push(Span::styled("_", Style::default().fg(Color::Yellow))); // cursor
+        let (before, after) = state.query_around_cursor();
+        spans.push(Span::styled(before, Style::default().fg(Color::White)));
+        // The cursor sits on the next character, or shows as '_' at the end
+        let mut rest = after.chars();
+        match rest.next() {
+            Some(c) => {
+                spans.push(Span::styled(
+                    c.to_string(),
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::REVERSED),
+                ));
+                spans.push(Span::styled(rest.as_str(), Style::default().fg(Color::White)));
+            }
+            None => spans.push(Span::styled("_", Style::default().fg(Color::Yellow))),
+        }
     } else if !state.query.is_empty() {
         spans.push(Span::styled("Query: ", Style::default().fg(Color::Cyan)));
         spans.push(Span::styled(
@@ -226,8 +442,21 @@ fn render_search_bar(app: &App, frame: &mut Frame, area: Rect) {
         ));
     }
 
-    let line = Line::from(spans);
-    let paragraph = Paragraph::new(line).block(
+    let mut lines = vec![Line::from(spans)];
+    if !suggestions.is_empty() {
+        let mut hint = vec![Span::styled("Tab ", Style::default().fg(Color::Yellow))];
+        for (i, name) in suggestions.iter().enumerate() {
+            let style = if i == 0 {
+                Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            hint.push(Span::styled(name.to_string(), style));
+            hint.push(Span::raw("  "));
+        }
+        lines.push(Line::from(hint));
+    }
+    let paragraph = Paragraph::new(lines).block(
         Block::default()
             .borders(Borders::ALL)
             .style(Style::default().fg(Color::White)),
@@ -236,7 +465,42 @@ fn render_search_bar(app: &App, frame: &mut Frame, area: Rect) {
     frame.render_widget(paragraph, area);
 }
 
-fn get_workflow_id(workflow: &WorkflowExecutionInfo) -> String {
+/// The table's visible workflow rows as tab-separated text with a header line, and how many
+/// rows there are. Group headers are left out; IDs are always written in full.
+pub fn visible_rows_tsv(app: &App) -> (String, usize) {
+    let state = &app.workflow_list_state;
+    let custom_columns: &[String] = app
+        .config
+        .get_active_profile()
+        .map(|p| p.columns.as_slice())
+        .unwrap_or(&[]);
+    // A tab or newline inside a value would shift the columns
+    let clean = |value: &str| value.replace(['\t', '\n', '\r'], " ");
+
+    let mut header = vec!["Workflow ID", "Run ID", "Type", "Status", "Start Time"];
+    header.extend(custom_columns.iter().map(String::as_str));
+    let mut lines = vec![header.join("\t")];
+    for row in state.visible_rows() {
+        let WorkflowRow::Workflow { index, .. } = row else {
+            continue;
+        };
+        let workflow = &state.items[index];
+        let run_id = workflow.execution.as_ref().map(|e| e.run_id.clone()).unwrap_or_default();
+        let mut fields = vec![
+            get_workflow_id(workflow),
+            run_id,
+            get_workflow_type(workflow),
+            status_name(get_workflow_status(workflow).0).to_string(),
+            get_workflow_start_time(workflow),
+        ];
+        fields.extend(custom_columns.iter().map(|key| get_search_attribute(workflow, key)));
+        lines.push(fields.iter().map(|field| clean(field)).collect::<Vec<_>>().join("\t"));
+    }
+    let count = lines.len() - 1;
+    (lines.join("\n") + "\n", count)
+}
+
+pub(super) fn get_workflow_id(workflow: &WorkflowExecutionInfo) -> String {
     workflow
         .execution
         .as_ref()
@@ -244,7 +508,26 @@ fn get_workflow_id(workflow: &WorkflowExecutionInfo) -> String {
         .unwrap_or_else(|| "Unknown".to_string())
 }
 
-fn get_workflow_type(workflow: &WorkflowExecutionInfo) -> String {
+/// First 8 characters of the run ID, enough to tell runs of one workflow apart
+fn short_run_id(workflow: &WorkflowExecutionInfo) -> String {
+    workflow
+        .execution
+        .as_ref()
+        .map(|e| e.run_id.chars().take(8).collect())
+        .unwrap_or_default()
+}
+
+/// Last segment of a hierarchical ID such as `order/12345/payment`
+fn short_workflow_id(workflow_id: &str) -> &str {
+    workflow_id
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .filter(|segment| !segment.is_empty())
+        .unwrap_or(workflow_id)
+}
+
+pub(super) fn get_workflow_type(workflow: &WorkflowExecutionInfo) -> String {
     workflow
         .r#type
         .as_ref()
@@ -252,9 +535,23 @@ fn get_workflow_type(workflow: &WorkflowExecutionInfo) -> String {
         .unwrap_or_else(|| "Unknown".to_string())
 }
 
-fn get_workflow_status(workflow: &WorkflowExecutionInfo) -> (WorkflowExecutionStatus, String) {
+fn get_search_attribute(workflow: &WorkflowExecutionInfo, key: &str) -> String {
+    workflow
+        .search_attributes
+        .as_ref()
+        .and_then(|sa| sa.indexed_fields.get(key))
+        .map(format_search_attribute)
+        .unwrap_or_default()
+}
+
+pub(super) fn get_workflow_status(workflow: &WorkflowExecutionInfo) -> (WorkflowExecutionStatus, String) {
     let status = WorkflowExecutionStatus::try_from(workflow.status).unwrap_or(WorkflowExecutionStatus::Unspecified);
-    let status_str = match status {
+    (status, format!("{} {}", crate::ui::status_icon(status), status_name(status)))
+}
+
+/// The status as plain text, without the icon
+fn status_name(status: WorkflowExecutionStatus) -> &'static str {
+    match status {
         WorkflowExecutionStatus::Running => "Running",
         WorkflowExecutionStatus::Completed => "Completed",
         WorkflowExecutionStatus::Failed => "Failed",
@@ -263,18 +560,78 @@ fn get_workflow_status(workflow: &WorkflowExecutionInfo) -> (WorkflowExecutionSt
         WorkflowExecutionStatus::ContinuedAsNew => "Continued",
         WorkflowExecutionStatus::TimedOut => "Timed Out",
         _ => "Unknown",
-    };
-    (status, status_str.to_string())
+    }
 }
 
-fn get_workflow_start_time(workflow: &WorkflowExecutionInfo) -> String {
-    workflow
-        .start_time
-        .as_ref()
-        .and_then(|t| {
-            let seconds = t.seconds as i64;
-            let nanos = t.nanos as u32;
-            DateTime::from_timestamp(seconds, nanos).map(|dt: DateTime<Utc>| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+/// One bar per status, from the server's counts when available, else tallied from the current page
+fn status_chart_bars(app: &App) -> Vec<Bar<'static>> {
+    const STATUSES: [WorkflowExecutionStatus; 7] = [
+        WorkflowExecutionStatus::Running,
+        WorkflowExecutionStatus::Completed,
+        WorkflowExecutionStatus::Failed,
+        WorkflowExecutionStatus::Canceled,
+        WorkflowExecutionStatus::Terminated,
+        WorkflowExecutionStatus::ContinuedAsNew,
+        WorkflowExecutionStatus::TimedOut,
+    ];
+    let state = &app.workflow_list_state;
+
+    STATUSES
+        .iter()
+        .filter_map(|&status| {
+            let name = visibility_status_name(status);
+            let count = match &state.status_counts {
+                Some(counts) => counts.iter().find(|(s, _)| s == name).map(|(_, c)| *c).unwrap_or(0),
+                None => state.items.iter().filter(|w| w.status == status as i32).count() as i64,
+            };
+            (count > 0).then(|| {
+                Bar::with_label(format!("{} {:<14}", crate::ui::status_icon(status), name), count as u64)
+                    .style(Style::default().fg(status_color(status)))
+                    .text_value(count.to_string())
+            })
         })
-        .unwrap_or_else(|| "Unknown".to_string())
+        .collect()
+}
+
+fn render_status_chart(app: &App, bars: Vec<Bar<'static>>, frame: &mut Frame, area: Rect) {
+    let scope = if app.workflow_list_state.status_counts.is_some() {
+        "Status (all matching)"
+    } else {
+        "Status (this page)"
+    };
+    let chart = BarChart::horizontal(bars)
+        .block(Block::default().borders(Borders::ALL).title(scope))
+        .bar_width(1)
+        .bar_gap(0);
+    frame.render_widget(chart, area);
+}
+
+/// The `ExecutionStatus` value used by visibility queries and grouped counts
+fn visibility_status_name(status: WorkflowExecutionStatus) -> &'static str {
+    match status {
+        WorkflowExecutionStatus::Running => "Running",
+        WorkflowExecutionStatus::Completed => "Completed",
+        WorkflowExecutionStatus::Failed => "Failed",
+        WorkflowExecutionStatus::Canceled => "Canceled",
+        WorkflowExecutionStatus::Terminated => "Terminated",
+        WorkflowExecutionStatus::ContinuedAsNew => "ContinuedAsNew",
+        WorkflowExecutionStatus::TimedOut => "TimedOut",
+        _ => "Unspecified",
+    }
+}
+
+pub(super) fn status_color(status: WorkflowExecutionStatus) -> Color {
+    match status {
+        WorkflowExecutionStatus::Running => Color::Yellow,
+        WorkflowExecutionStatus::Completed => Color::Green,
+        WorkflowExecutionStatus::Failed => Color::Red,
+        WorkflowExecutionStatus::Canceled => Color::Magenta,
+        WorkflowExecutionStatus::Terminated => Color::Red,
+        WorkflowExecutionStatus::TimedOut => Color::Red,
+        _ => Color::White,
+    }
+}
+
+pub(super) fn get_workflow_start_time(workflow: &WorkflowExecutionInfo) -> String {
+    format_timestamp(workflow.start_time.as_ref(), "Unknown")
 }